@@ -0,0 +1,75 @@
+use lc_render::{AreaBaseline, AreaView, BandScale, Chart, Color, LinearScale};
+
+fn main() {
+    let width = 900;
+    let height = 500;
+
+    let margin_top = 90;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    let months: Vec<String> = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ]
+    .iter()
+    .map(|month| month.to_string())
+    .collect();
+
+    let x_scale = BandScale::new(months, 0, width - margin_left - margin_right)
+        .set_inner_padding(0_f32)
+        .set_outer_padding(0_f32);
+    let y_scale = LinearScale::new(-20_f32, 30_f32, height - margin_top - margin_bottom, 0);
+
+    let lows = vec![
+        -15_f32, -13_f32, -7_f32, 0_f32, 6_f32, 11_f32, 14_f32, 12_f32, 6_f32, 0_f32, -6_f32,
+        -12_f32,
+    ];
+    let highs = vec![
+        -6_f32, -4_f32, 3_f32, 11_f32, 19_f32, 23_f32, 26_f32, 24_f32, 17_f32, 9_f32, 1_f32, -4_f32,
+    ];
+    let means: Vec<f32> = lows
+        .iter()
+        .zip(highs.iter())
+        .map(|(low, high)| (low + high) / 2_f32)
+        .collect();
+
+    // Temperature range is filled between lows and highs.
+    let range_view = AreaView::new(x_scale.clone(), y_scale.clone())
+        .set_name("Range")
+        .set_baseline(AreaBaseline::Low(lows))
+        .set_fill_color(Color::new_from_hex("#f9c08b"))
+        .set_stroke_color(Color::new_from_hex("#ff8d00"))
+        .set_point_visible(false)
+        .set_point_label_visible(false)
+        .set_data(&highs)
+        .expect("unable to set range data");
+
+    // Mean temperature is filled down to zero, so the winter months go below the axis.
+    let mean_view = AreaView::new(x_scale.clone(), y_scale.clone())
+        .set_name("Mean")
+        .set_fill_color(Color::new_from_hex("#7ba7e1"))
+        .set_stroke_color(Color::new_from_hex("#1f5aa6"))
+        .set_point_visible(false)
+        .set_point_label_visible(false)
+        .set_data(&means)
+        .expect("unable to set mean data");
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_band(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_axis_left_label("°C")
+        .set_title("Area Band Chart")
+        .add_view(&range_view)
+        .add_view(&mean_view);
+
+    chart
+        .save("./examples/svg/area_band_chart.svg")
+        .expect("unable to save ./examples/svg/area_band_chart.svg");
+}
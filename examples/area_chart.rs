@@ -1,4 +1,6 @@
-use lc_render::{AreaView, BandScale, Chart, Color, LinearScale, PointLabelPosition, PointType};
+use lc_render::{
+    AreaView, BandScale, Chart, Color, GridStyle, LinearScale, PointLabelPosition, PointType,
+};
 
 fn main() {
     let width = 1200;
@@ -49,6 +51,10 @@ fn main() {
         .set_axis_left_linear(y_scale)
         .set_axis_bottom_label("X Values")
         .set_axis_left_label("Y Values")
+        .set_axis_bottom_grid_visible(true)
+        .set_axis_left_grid_visible(true)
+        .set_grid_style(GridStyle::new().set_stroke_dasharray(&[4, 2]))
+        .set_grid_minor_visible(true)
         .set_title("Single Area Chart")
         .add_view(&view);
 
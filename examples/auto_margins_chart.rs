@@ -0,0 +1,55 @@
+use lc_render::{BandScale, BarsValues, Chart, Color, HorizontalBarView, LinearScale, TickFormat};
+
+fn main() {
+    // Configure chart size, margins are computed inside of it from labels and the title.
+    let width = 960;
+    let height = 580;
+
+    // Configure vertical scale with long category names.
+    let y_scale = BandScale::new(
+        vec![
+            "Northern Europe".to_string(),
+            "Southern Europe".to_string(),
+            "North America".to_string(),
+            "Latin America and Caribbean".to_string(),
+            "Asia Pacific".to_string(),
+        ],
+        0,
+        height,
+    );
+
+    // Configure horizontal scale.
+    let x_scale =
+        LinearScale::new(0.0, 1_000_000.0, 0, width).set_tick_format(TickFormat::Thousands(0));
+
+    // Prepare horizontal bars data.
+    let data = vec![BarsValues::new(vec![
+        420_000_f32,
+        310_000_f32,
+        870_000_f32,
+        150_000_f32,
+        640_000_f32,
+    ])
+    .set_fill_color(Color::new_from_hex("#898fd5"))
+    .set_stroke_color(Color::new_from_hex("#2c2663"))];
+    let view = HorizontalBarView::new(x_scale.clone(), y_scale.clone())
+        .set_bar_label_visible(false)
+        .set_data(&data)
+        .expect("unable to set data");
+
+    // Views and axes are fitted into the space that is left by the computed margins.
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_auto_margins(true)
+        .set_axis_bottom_linear(x_scale)
+        .set_axis_left_band(y_scale)
+        .set_axis_bottom_label("Revenue")
+        .set_axis_left_label("Region")
+        .set_title("Auto Margins Chart")
+        .add_view(&view);
+
+    chart
+        .save("./examples/svg/auto_margins_chart.svg")
+        .expect("unable to save ./svg/auto_margins_chart.svg");
+}
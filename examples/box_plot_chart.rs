@@ -0,0 +1,77 @@
+use lc_render::{
+    BandScale, BoxPlotSummary, BoxPlotView, BoxPlotWhiskers, Chart, LinearScale, PointType,
+};
+
+fn main() {
+    let width = 800;
+    let height = 600;
+
+    let margin_top = 90;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    let x_scale = BandScale::new(
+        vec![
+            "api".to_string(),
+            "auth".to_string(),
+            "search".to_string(),
+            "storage".to_string(),
+        ],
+        0,
+        width - margin_left - margin_right,
+    )
+    .set_inner_padding(0.4);
+    let y_scale = LinearScale::new(0_f32, 200_f32, height - margin_top - margin_bottom, 0);
+
+    // Response times in milliseconds.
+    let samples = [
+        vec![
+            21_f32, 25_f32, 28_f32, 30_f32, 31_f32, 33_f32, 35_f32, 38_f32, 41_f32, 47_f32, 112_f32,
+        ],
+        vec![
+            12_f32, 14_f32, 15_f32, 15_f32, 17_f32, 18_f32, 19_f32, 22_f32, 24_f32, 63_f32, 71_f32,
+        ],
+        vec![
+            60_f32, 72_f32, 80_f32, 85_f32, 91_f32, 96_f32, 104_f32, 110_f32, 121_f32, 139_f32,
+        ],
+    ];
+    let view = BoxPlotView::new(x_scale.clone(), y_scale.clone())
+        .set_name("Response time")
+        .set_outlier_point_type(PointType::X);
+
+    // Last category is known only by its precomputed summary.
+    let mut summaries = samples
+        .iter()
+        .map(|category_samples| {
+            BoxPlotSummary::from_samples(category_samples, BoxPlotWhiskers::Iqr(1.5))
+        })
+        .collect::<Result<Vec<BoxPlotSummary>, _>>()
+        .expect("unable to calculate summaries");
+    summaries.push(
+        BoxPlotSummary::new(90_f32, 120_f32, 135_f32, 150_f32, 180_f32)
+            .expect("unable to create summary")
+            .set_outliers(vec![195_f32]),
+    );
+    let view = view
+        .set_summaries(&summaries)
+        .expect("unable to set summaries");
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_band(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_axis_bottom_label("Service")
+        .set_axis_left_label("Milliseconds")
+        .set_title("Box Plot Chart")
+        .add_view(&view);
+
+    chart
+        .save("./examples/svg/box_plot_chart.svg")
+        .expect("unable to save ./examples/svg/box_plot_chart.svg");
+}
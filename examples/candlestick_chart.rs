@@ -0,0 +1,72 @@
+use chrono::{Duration, TimeZone, Utc};
+use lc_render::{CandlestickView, Chart, LinearScale, Ohlc, TimeScale};
+
+fn main() {
+    let width = 1000;
+    let height = 600;
+
+    let margin_top = 90;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    // Volume panel takes the bottom part of the views and the prices are drawn above it.
+    let volume_panel_height = 100;
+    let volume_panel_gap = 10;
+
+    let start = Utc.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap();
+    let days = 30;
+    let end = start + Duration::days(days);
+
+    let x_scale = TimeScale::new(
+        start - Duration::days(1),
+        end,
+        0,
+        width - margin_left - margin_right,
+    );
+    let y_scale = LinearScale::new(
+        90_f32,
+        140_f32,
+        height - margin_top - margin_bottom - volume_panel_height - volume_panel_gap,
+        0,
+    );
+
+    let mut close = 100_f32;
+    let data: Vec<_> = (0..days)
+        .map(|day| {
+            let open = close;
+            let change = ((day * 7 % 11) as f32 - 4_f32) * 1.1_f32;
+            close = open + change;
+            let high = open.max(close) + (day % 3) as f32 + 0.8_f32;
+            let low = open.min(close) - (day % 4) as f32 - 0.6_f32;
+            let volume = 1000_f32 + (day * 37 % 13) as f32 * 150_f32;
+            (
+                start + Duration::days(day),
+                Ohlc::new(open, high, low, close).set_volume(volume),
+            )
+        })
+        .collect();
+
+    let view = CandlestickView::new(x_scale.clone(), y_scale.clone())
+        .set_name("Price")
+        .set_volume_visible(true)
+        .set_volume_panel_height(volume_panel_height)
+        .set_data(&data)
+        .expect("unable to set data");
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_time(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_title("Candlestick Chart")
+        .add_view(&view);
+
+    chart
+        .save("./examples/svg/candlestick_chart.svg")
+        .expect("unable to save ./examples/svg/candlestick_chart.svg");
+}
@@ -0,0 +1,64 @@
+use lc_render::color::{COLOR_HEX_BLUE_1, COLOR_HEX_GREEN_2, COLOR_HEX_RED_3};
+use lc_render::{BandScale, Chart, Color, Curve, LineView, LinearScale};
+
+fn main() {
+    let width = 1000;
+    let height = 600;
+
+    let margin_top = 90;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    let x_scale = BandScale::new(
+        (1..=10).map(|hour| format!("{:02}:00", hour)).collect(),
+        0,
+        width - margin_left - margin_right,
+    )
+    .set_inner_padding(0_f32)
+    .set_outer_padding(0_f32);
+    let y_scale = LinearScale::new(0_f32, 100_f32, height - margin_top - margin_bottom, 0);
+
+    let data = vec![
+        10_f32, 12_f32, 60_f32, 62_f32, 30_f32, 30_f32, 30_f32, 85_f32, 20_f32, 25_f32,
+    ];
+
+    // The same data is drawn with different curves and shifted to keep the lines apart.
+    let curves = [
+        ("Step after", Curve::StepAfter, COLOR_HEX_RED_3, 0_f32),
+        ("Monotone", Curve::Monotone, COLOR_HEX_BLUE_1, 5_f32),
+        ("Basis", Curve::Basis, COLOR_HEX_GREEN_2, 10_f32),
+    ];
+    let views: Vec<LineView> = curves
+        .iter()
+        .map(|(name, curve, color, shift)| {
+            let shifted_data: Vec<f32> = data.iter().map(|value| value + shift).collect();
+            LineView::new(x_scale.clone(), y_scale.clone())
+                .set_name(name)
+                .set_curve(*curve)
+                .set_stroke_color(Color::new_from_hex(color))
+                .set_point_visible(false)
+                .set_point_label_visible(false)
+                .set_data(&shifted_data)
+                .expect("unable to set data")
+        })
+        .collect();
+
+    let mut chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_band(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_title("Curve Line Chart");
+    for view in views.iter() {
+        chart = chart.add_view(view);
+    }
+
+    chart
+        .save("./examples/svg/curve_line_chart.svg")
+        .expect("unable to save ./examples/svg/curve_line_chart.svg");
+}
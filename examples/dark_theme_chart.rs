@@ -0,0 +1,71 @@
+use lc_render::color::{COLOR_HEX_BLUE_4, COLOR_HEX_BLUE_5, COLOR_HEX_GREEN_5};
+use lc_render::{BandScale, Chart, Color, LineView, LinearScale, PointLabelPosition, Theme};
+
+fn main() {
+    let width = 1000;
+    let height = 700;
+
+    let margin_top = 90;
+    let margin_bottom = 70;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    let x_scale = BandScale::new(
+        vec![
+            "Jan".to_string(),
+            "Feb".to_string(),
+            "Mar".to_string(),
+            "Apr".to_string(),
+            "May".to_string(),
+            "Jun".to_string(),
+        ],
+        0,
+        width - margin_left - margin_right,
+    )
+    .set_no_boundaries_offset(true)
+    .set_inner_padding(0.0)
+    .set_outer_padding(0.0);
+    let y_scale = LinearScale::new(0_f32, 200_f32, height - margin_top - margin_bottom, 0);
+
+    let data_1 = vec![42_f32, 80_f32, 125_f32, 150_f32, 110_f32, 170_f32];
+    let view_1 = LineView::new(x_scale.clone(), y_scale.clone())
+        .set_name("Visitors")
+        .set_point_label_position(PointLabelPosition::Top)
+        .set_stroke_color(Color::new_from_hex(COLOR_HEX_BLUE_4))
+        .set_point_fill_color(Color::new_from_hex(COLOR_HEX_BLUE_5))
+        .set_point_stroke_color(Color::new_from_hex(COLOR_HEX_BLUE_4))
+        .set_data(&data_1)
+        .expect("setting data");
+
+    let data_2 = vec![20_f32, 35_f32, 60_f32, 58_f32, 72_f32, 95_f32];
+    let view_2 = LineView::new(x_scale.clone(), y_scale.clone())
+        .set_name("Orders")
+        .set_point_label_position(PointLabelPosition::BottomRight)
+        .set_stroke_color(Color::new_from_hex(COLOR_HEX_GREEN_5))
+        .set_point_fill_color(Color::new_from_hex(COLOR_HEX_GREEN_5))
+        .set_point_stroke_color(Color::new_from_hex(COLOR_HEX_GREEN_5))
+        .set_data(&data_2)
+        .expect("setting data");
+
+    let chart = Chart::new()
+        .set_theme(Theme::dark())
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_band(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_axis_bottom_label("Month")
+        .set_axis_left_label("Count")
+        .set_axis_left_grid_visible(true)
+        .set_grid_minor_visible(true)
+        .set_title("Dark Theme Chart")
+        .add_view(&view_1)
+        .add_view(&view_2);
+
+    chart
+        .save("./examples/svg/dark_theme_chart.svg")
+        .expect("unable to save ./examples/svg/dark_theme_chart.svg");
+}
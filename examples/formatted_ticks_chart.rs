@@ -0,0 +1,60 @@
+use lc_render::{BandScale, BarsValues, Chart, Color, LinearScale, TickFormat, VerticalBarView};
+
+fn main() {
+    // Configure document size.
+    let width = 800;
+    let height = 600;
+
+    // Configure document margins.
+    let margin_top = 90;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    // Configure horizontal scale.
+    let x_scale = BandScale::new(
+        vec![
+            "Q1".to_string(),
+            "Q2".to_string(),
+            "Q3".to_string(),
+            "Q4".to_string(),
+        ],
+        0,
+        width - margin_left - margin_right,
+    );
+
+    // Configure vertical scale with SI prefixes, bar labels use the same format.
+    let y_scale = LinearScale::new(0.0, 3_000_000.0, height - margin_top - margin_bottom, 0)
+        .set_tick_format(TickFormat::Si(1));
+
+    // Prepare vertical bars data.
+    let data = vec![BarsValues::new(vec![
+        1_260_000_f32,
+        2_480_000_f32,
+        1_940_000_f32,
+        2_760_000_f32,
+    ])
+    .set_fill_color(Color::new_from_hex("#36896e"))
+    .set_stroke_color(Color::new_from_hex("#0c513b"))];
+    let view = VerticalBarView::new(x_scale.clone(), y_scale.clone())
+        .set_data(&data)
+        .expect("unable to set data");
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_band(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_axis_bottom_label("Quarters")
+        .set_axis_left_label("Revenue")
+        .set_title("Formatted Ticks Chart")
+        .add_view(&view);
+
+    chart
+        .save("./examples/svg/formatted_ticks_chart.svg")
+        .expect("unable to save ./svg/formatted_ticks_chart.svg");
+}
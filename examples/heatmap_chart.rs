@@ -0,0 +1,68 @@
+use lc_render::color::{COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_3, COLOR_HEX_BLUE_5};
+use lc_render::{BandScale, Chart, Color, ColorScale, HeatmapView};
+
+fn main() {
+    let width = 900;
+    let height = 500;
+
+    let margin_top = 90;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 20;
+
+    let hours: Vec<String> = (8..20).map(|hour| format!("{}:00", hour)).collect();
+    let weekdays: Vec<String> = ["Mon", "Tue", "Wed", "Thu", "Fri"]
+        .iter()
+        .map(|day| day.to_string())
+        .collect();
+
+    let x_scale = BandScale::new(hours.clone(), 0, width - margin_left - margin_right)
+        .set_inner_padding(0.05)
+        .set_outer_padding(0.0);
+    let y_scale = BandScale::new(weekdays.clone(), 0, height - margin_top - margin_bottom)
+        .set_inner_padding(0.05)
+        .set_outer_padding(0.0);
+    let color_scale = ColorScale::new(
+        0_f32,
+        100_f32,
+        &[
+            Color::new_from_hex(COLOR_HEX_BLUE_5),
+            Color::new_from_hex(COLOR_HEX_BLUE_3),
+            Color::new_from_hex(COLOR_HEX_BLUE_1),
+        ],
+    )
+    .expect("unable to create color scale");
+
+    // Activity peaks around noon and decreases by the end of the week.
+    let data: Vec<Vec<f32>> = (0..weekdays.len())
+        .map(|day| {
+            (0..hours.len())
+                .map(|hour| {
+                    let distance = (hour as f32 - 4_f32).abs();
+                    ((95_f32 - distance * 9_f32 - day as f32 * 7_f32).max(0_f32)).round()
+                })
+                .collect()
+        })
+        .collect();
+
+    let view = HeatmapView::new(x_scale.clone(), y_scale.clone(), color_scale)
+        .set_label_visible(true)
+        .set_data(&data)
+        .expect("unable to set data");
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_band(x_scale)
+        .set_axis_left_band(y_scale)
+        .set_title("Heatmap Chart")
+        .add_view(&view);
+
+    chart
+        .save("./examples/svg/heatmap_chart.svg")
+        .expect("unable to save ./examples/svg/heatmap_chart.svg");
+}
@@ -0,0 +1,54 @@
+use lc_render::{Chart, HistogramBins, HistogramView, LinearScale};
+
+// Generate normally distributed samples with a fixed seed so the example output is stable.
+fn samples(count: usize) -> Vec<f32> {
+    let mut state = 42_u32;
+    let mut uniform = move || {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (state >> 8) as f32 / (1 << 24) as f32
+    };
+
+    // Sum of 12 uniform values approximates the standard normal distribution.
+    (0..count)
+        .map(|_| 50_f32 + 10_f32 * ((0..12).map(|_| uniform()).sum::<f32>() - 6_f32))
+        .collect()
+}
+
+fn main() {
+    let width = 800;
+    let height = 600;
+
+    let margin_top = 90;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    let x_scale = LinearScale::new(0_f32, 100_f32, 0, width - margin_left - margin_right);
+    let y_scale = LinearScale::new(0_f32, 0.05_f32, height - margin_top - margin_bottom, 0);
+
+    let view = HistogramView::new(x_scale.clone(), y_scale.clone())
+        .set_name("Samples density")
+        .set_bins(HistogramBins::FreedmanDiaconis)
+        .expect("unable to set bins")
+        .set_density(true)
+        .set_data(&samples(1000))
+        .expect("unable to set data");
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_linear(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_axis_bottom_label("Value")
+        .set_axis_left_label("Density")
+        .set_title("Histogram Chart")
+        .add_view(&view);
+
+    chart
+        .save("./examples/svg/histogram_chart.svg")
+        .expect("unable to save ./examples/svg/histogram_chart.svg");
+}
@@ -0,0 +1,68 @@
+use chrono::{Duration, TimeZone, Utc};
+use lc_render::color::{COLOR_HEX_BLUE_1, COLOR_HEX_GREEN_1, COLOR_HEX_GREEN_5};
+use lc_render::{AreaView, Chart, Color, LineView, LinearScale, MissingValues, TimeScale};
+
+fn main() {
+    let width = 1000;
+    let height = 500;
+
+    let margin_top = 90;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    let start = Utc.with_ymd_and_hms(2021, 6, 1, 0, 0, 0).unwrap();
+    let end = start + Duration::hours(23);
+
+    let x_scale = TimeScale::new(start, end, 0, width - margin_left - margin_right);
+    let y_scale = LinearScale::new(0_f32, 100_f32, height - margin_top - margin_bottom, 0);
+
+    // Collector was down between 06:00 and 09:00 and missed a sample at 17:00.
+    let samples: Vec<_> = (0..24)
+        .map(|hour| {
+            let value = match hour {
+                6..=9 | 17 => None,
+                _ => Some(50_f32 + 30_f32 * ((hour as f32) / 24_f32 * std::f32::consts::TAU).sin()),
+            };
+            (start + Duration::hours(hour), value)
+        })
+        .collect();
+    let shifted_samples: Vec<_> = samples
+        .iter()
+        .map(|(time, value)| (*time, value.map(|value| value - 20_f32)))
+        .collect();
+
+    let cpu_view = LineView::new(x_scale.clone(), y_scale.clone())
+        .set_name("CPU, gaps")
+        .set_stroke_color(Color::new_from_hex(COLOR_HEX_BLUE_1))
+        .set_point_label_visible(false)
+        .set_data(&samples)
+        .expect("unable to set cpu data");
+
+    let io_view = AreaView::new(x_scale.clone(), y_scale.clone())
+        .set_name("IO, bridged")
+        .set_missing_values(MissingValues::Bridge)
+        .set_fill_color(Color::new_from_hex(COLOR_HEX_GREEN_5))
+        .set_stroke_color(Color::new_from_hex(COLOR_HEX_GREEN_1))
+        .set_point_visible(false)
+        .set_point_label_visible(false)
+        .set_data(&shifted_samples)
+        .expect("unable to set io data");
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_time(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_title("Missing Values Line Chart")
+        .add_view(&io_view)
+        .add_view(&cpu_view);
+
+    chart
+        .save("./examples/svg/missing_values_line_chart.svg")
+        .expect("unable to save ./examples/svg/missing_values_line_chart.svg");
+}
@@ -0,0 +1,68 @@
+use lc_render::{BandScale, Chart, LegendPosition, LineView, LinearScale, Palette};
+
+fn main() {
+    let width = 1000;
+    let height = 800;
+
+    let margin_top = 60;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    let x_scale = BandScale::new(
+        vec![
+            "A".to_string(),
+            "B".to_string(),
+            "C".to_string(),
+            "D".to_string(),
+            "E".to_string(),
+            "F".to_string(),
+            "G".to_string(),
+            "H".to_string(),
+        ],
+        0,
+        width - margin_left - margin_right,
+    )
+    .set_no_boundaries_offset(true)
+    .set_inner_padding(0.0)
+    .set_outer_padding(0.0);
+
+    let y_scale = LinearScale::new(0.0, 200.0_f32, height - margin_top - margin_bottom, 0);
+
+    // Lines don't set their colors so the chart assigns the next palette color to each of them.
+    let views: Vec<LineView> = (0..10)
+        .map(|idx| {
+            let data: Vec<f32> = (0..8)
+                .map(|category| {
+                    let wave = ((category + idx) as f32 * 0.8_f32).sin() * 15_f32;
+                    20_f32 + idx as f32 * 18_f32 + wave
+                })
+                .collect();
+            LineView::new(x_scale.clone(), y_scale.clone())
+                .set_name(&format!("Series {}", idx + 1))
+                .set_point_label_visible(false)
+                .set_data(&data)
+                .expect("setting data")
+        })
+        .collect();
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_band(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_axis_bottom_label("X Values")
+        .set_axis_left_label("Y Values")
+        .set_title("Palette Chart")
+        .set_legend_position(LegendPosition::Right)
+        .set_palette(Palette::tableau10())
+        .set_views(views.iter().map(|view| view as _).collect());
+
+    chart
+        .save("./examples/svg/palette_chart.svg")
+        .expect("unable to save ./examples/svg/palette_chart.svg");
+}
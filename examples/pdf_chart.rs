@@ -0,0 +1,61 @@
+use lc_render::{
+    BandScale, BarsValues, Chart, LineView, LinearScale, PdfDocument, VerticalBarView,
+};
+
+fn main() {
+    let width = 800;
+    let height = 600;
+
+    let margin_top = 90;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    let x_scale = BandScale::new(
+        vec![
+            "A".to_string(),
+            "B".to_string(),
+            "C".to_string(),
+            "D".to_string(),
+        ],
+        0,
+        width - margin_left - margin_right,
+    );
+    let y_scale = LinearScale::new(0_f32, 100_f32, height - margin_top - margin_bottom, 0);
+
+    let line_view = LineView::new(x_scale.clone(), y_scale.clone())
+        .set_name("Line values")
+        .set_data(&[22_f32, 67_f32, 45_f32, 88_f32])
+        .expect("unable to set data");
+    let line_chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_axis_bottom_band(x_scale.clone())
+        .set_axis_left_linear(y_scale.clone())
+        .set_axis_bottom_label("Categories")
+        .set_axis_left_label("Values")
+        .set_title("Line Chart")
+        .add_view(&line_view);
+
+    let bar_view = VerticalBarView::new(x_scale.clone(), y_scale.clone())
+        .set_data(&[BarsValues::new(vec![35_f32, 50_f32, 72_f32, 14_f32]).set_name("Bar values")])
+        .expect("unable to set data");
+    let bar_chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_axis_bottom_band(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_axis_bottom_label("Categories")
+        .set_axis_left_label("Values")
+        .set_title("Vertical Bar Chart")
+        .add_view(&bar_view);
+
+    // Each chart is placed on a separate page.
+    PdfDocument::new()
+        .add_chart(&line_chart)
+        .expect("unable to add line chart")
+        .add_chart(&bar_chart)
+        .expect("unable to add bar chart")
+        .save("./examples/pdf/pdf_chart.pdf")
+        .expect("unable to save ./examples/pdf/pdf_chart.pdf");
+}
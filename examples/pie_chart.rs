@@ -0,0 +1,58 @@
+use lc_render::color::{
+    COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_3, COLOR_HEX_BLUE_5, COLOR_HEX_GREEN_3, COLOR_HEX_GREEN_5,
+};
+use lc_render::{Chart, Color, PieLabelFormat, PieLabelPosition, PieSlice, PieSortOrder, PieView};
+
+const STROKE_COLOR: &str = "#ffffff";
+
+fn main() {
+    let width = 800;
+    let height = 600;
+
+    let margin_top = 60;
+    let margin_bottom = 40;
+    let margin_left = 40;
+    let margin_right = 40;
+
+    let data = vec![
+        PieSlice::new("Rust", 42_f32)
+            .set_fill_color(Color::new_from_hex(COLOR_HEX_BLUE_1))
+            .set_stroke_color(Color::new_from_hex(STROKE_COLOR)),
+        PieSlice::new("Go", 27_f32)
+            .set_fill_color(Color::new_from_hex(COLOR_HEX_BLUE_3))
+            .set_stroke_color(Color::new_from_hex(STROKE_COLOR)),
+        PieSlice::new("C++", 15_f32)
+            .set_fill_color(Color::new_from_hex(COLOR_HEX_BLUE_5))
+            .set_stroke_color(Color::new_from_hex(STROKE_COLOR)),
+        PieSlice::new("Zig", 10_f32)
+            .set_fill_color(Color::new_from_hex(COLOR_HEX_GREEN_3))
+            .set_stroke_color(Color::new_from_hex(STROKE_COLOR)),
+        PieSlice::new("Other", 6_f32)
+            .set_fill_color(Color::new_from_hex(COLOR_HEX_GREEN_5))
+            .set_stroke_color(Color::new_from_hex(STROKE_COLOR)),
+    ];
+
+    let view = PieView::new()
+        .set_outer_radius(180_f32)
+        .set_inner_radius(90_f32)
+        .set_pad_angle(1_f32)
+        .set_sort_order(PieSortOrder::Descending)
+        .set_label_position(PieLabelPosition::Outside)
+        .set_label_format(PieLabelFormat::LabelAndPercentage)
+        .set_data(&data)
+        .expect("unable to set data");
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_title("Pie Chart")
+        .add_view(&view);
+
+    chart
+        .save("./examples/svg/pie_chart.svg")
+        .expect("unable to save ./examples/svg/pie_chart.svg");
+}
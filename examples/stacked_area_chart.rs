@@ -0,0 +1,67 @@
+use lc_render::color::{
+    COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_4, COLOR_HEX_GREEN_1, COLOR_HEX_GREEN_4, COLOR_HEX_RED_2,
+    COLOR_HEX_RED_5,
+};
+use lc_render::{AreaValues, BandScale, Chart, Color, LinearScale, StackedAreaView};
+
+fn main() {
+    let width = 900;
+    let height = 500;
+
+    let margin_top = 90;
+    let margin_bottom = 50;
+    let margin_left = 60;
+    let margin_right = 40;
+
+    let months: Vec<String> = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug"]
+        .iter()
+        .map(|month| month.to_string())
+        .collect();
+
+    let x_scale = BandScale::new(months, 0, width - margin_left - margin_right)
+        .set_inner_padding(0_f32)
+        .set_outer_padding(0_f32);
+    let y_scale = LinearScale::new(0_f32, 100_f32, height - margin_top - margin_bottom, 0);
+
+    let data = vec![
+        AreaValues::new(vec![
+            20_f32, 24_f32, 30_f32, 28_f32, 35_f32, 40_f32, 38_f32, 45_f32,
+        ])
+        .set_name("Compute")
+        .set_fill_color(Color::new_from_hex(COLOR_HEX_BLUE_4))
+        .set_stroke_color(Color::new_from_hex(COLOR_HEX_BLUE_1)),
+        AreaValues::new(vec![
+            15_f32, 18_f32, 16_f32, 20_f32, 22_f32, 21_f32, 25_f32, 24_f32,
+        ])
+        .set_name("Storage")
+        .set_fill_color(Color::new_from_hex(COLOR_HEX_GREEN_4))
+        .set_stroke_color(Color::new_from_hex(COLOR_HEX_GREEN_1)),
+        AreaValues::new(vec![
+            10_f32, 8_f32, 12_f32, 15_f32, 11_f32, 14_f32, 18_f32, 16_f32,
+        ])
+        .set_name("Network")
+        .set_fill_color(Color::new_from_hex(COLOR_HEX_RED_5))
+        .set_stroke_color(Color::new_from_hex(COLOR_HEX_RED_2)),
+    ];
+
+    let view = StackedAreaView::new(x_scale.clone(), y_scale.clone())
+        .set_data(&data)
+        .expect("unable to set data");
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_band(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_axis_left_label("Cost")
+        .set_title("Stacked Area Chart")
+        .add_view(&view);
+
+    chart
+        .save("./examples/svg/stacked_area_chart.svg")
+        .expect("unable to save ./examples/svg/stacked_area_chart.svg");
+}
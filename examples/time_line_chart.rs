@@ -0,0 +1,52 @@
+use chrono::{Duration, TimeZone, Utc};
+use lc_render::{Chart, LineView, LinearScale, TimeScale};
+
+fn main() {
+    let width = 1200;
+    let height = 700;
+
+    let margin_top = 20;
+    let margin_bottom = 70;
+    let margin_left = 40;
+    let margin_right = 30;
+
+    let start = Utc.with_ymd_and_hms(2021, 5, 1, 0, 0, 0).unwrap();
+    let end = start + Duration::hours(36);
+
+    let x_scale = TimeScale::new(start, end, 0, width - margin_left - margin_right);
+    let y_scale = LinearScale::new(0_f32, 200_f32, height - margin_top - margin_bottom, 0);
+
+    // Irregular sampling is kept as is.
+    let data = vec![
+        (start, 12_f32),
+        (start + Duration::minutes(90), 100_f32),
+        (start + Duration::hours(5), 120_f32),
+        (start + Duration::hours(14), 180_f32),
+        (start + Duration::hours(15), 40_f32),
+        (start + Duration::hours(30), 75_f32),
+        (end, 8_f32),
+    ];
+
+    let view = LineView::new(x_scale.clone(), y_scale.clone())
+        .set_point_label_visible(false)
+        .set_data(&data)
+        .expect("setting data");
+
+    let chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margin_top(margin_top)
+        .set_margin_bottom(margin_bottom)
+        .set_margin_left(margin_left)
+        .set_margin_right(margin_right)
+        .set_axis_bottom_time(x_scale)
+        .set_axis_left_linear(y_scale)
+        .set_axis_bottom_label("Time")
+        .set_axis_left_label("Y Values")
+        .set_title("Time Line Chart")
+        .add_view(&view);
+
+    chart
+        .save("./examples/svg/time_line_chart.svg")
+        .expect("unable to save ./examples/svg/time_line_chart.svg");
+}
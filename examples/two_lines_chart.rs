@@ -1,5 +1,7 @@
 use lc_render::color::{COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_4};
-use lc_render::{BandScale, Chart, Color, LineView, LinearScale, PointLabelPosition};
+use lc_render::{
+    BandScale, Chart, Color, LegendPosition, LineView, LinearScale, PointLabelPosition,
+};
 
 fn main() {
     let width = 1000;
@@ -34,6 +36,7 @@ fn main() {
         20_f32, 70_f32, 130_f32, 180_f32, 20_f32, 77_f32, 140_f32, 190_f32,
     ];
     let view_1 = LineView::new(x_scale.clone(), y_scale.clone())
+        .set_name("First")
         .set_point_label_position(PointLabelPosition::BottomLeft)
         .set_stroke_color(Color::new_from_hex(COLOR_HEX_BLUE_1))
         .set_point_visible(false)
@@ -44,6 +47,7 @@ fn main() {
         54_f32, 40_f32, 50_f32, 77_f32, 91_f32, 53_f32, 11_f32, 3_f32,
     ];
     let view_2 = LineView::new(x_scale.clone(), y_scale.clone())
+        .set_name("Second")
         .set_point_label_position(PointLabelPosition::TopRight)
         .set_stroke_color(Color::new_from_hex(COLOR_HEX_BLUE_4))
         .set_point_visible(false)
@@ -62,6 +66,7 @@ fn main() {
         .set_axis_bottom_label("X Values")
         .set_axis_left_label("Y Values")
         .set_title("Two Lines Chart")
+        .set_legend_position(LegendPosition::InsideTopLeft)
         .add_view(&view_1)
         .add_view(&view_2);
 
@@ -1,45 +1,174 @@
 use crate::render::svg::*;
+use crate::scale::fit_scale;
 use crate::shape::axis::{Axis, AxisPosition};
+use crate::shape::color_bar::ColorBar;
+use crate::shape::grid::Grid;
+use crate::shape::legend::Legend;
 use crate::view::View;
-use crate::{BandScale, Error, LinearScale};
+use crate::{
+    BandScale, Error, GridStyle, LegendPosition, LinearScale, LogScale, Orientation, Palette,
+    Scale, Theme, TimeScale,
+};
+use chrono::TimeZone;
+use std::fmt::Display;
+use std::io::Write;
+use std::ops::Deref;
 use std::path::Path;
+use std::sync::Arc;
 use svg::Node;
 
-const DEFAULT_MARGIN_TOP: i32 = 90;
-const DEFAULT_MARGIN_BOTTOM: i32 = 50;
-const DEFAULT_MARGIN_LEFT: i32 = 60;
-const DEFAULT_MARGIN_RIGHT: i32 = 40;
+const DEFAULT_AUTO_MARGINS: bool = false;
 const DEFAULT_WIDTH: i32 = 800;
 const DEFAULT_HEIGHT: i32 = 600;
 
-const DEFAULT_TITLE_FONT_SIZE: &str = "24px";
 const DEFAULT_TITLE_Y_TRANSFORM: i32 = 25;
 
-/// Chart represents a single document with one or more views, axes and a title.
-/// It will also contain grid and legend in the future.
-pub struct Chart<'a> {
-    margin_top: i32,
-    margin_bottom: i32,
-    margin_left: i32,
-    margin_right: i32,
+const DEFAULT_AXIS_GRID_VISIBLE: bool = false;
+
+const DEFAULT_LEGEND_VISIBLE: bool = true;
+const DEFAULT_LEGEND_POSITION: LegendPosition = LegendPosition::Right;
+
+// ChartView is a view that is either borrowed or owned by a chart.
+enum ChartView<'a, V: ?Sized> {
+    Borrowed(&'a V),
+    Owned(Arc<V>),
+}
+
+impl<'a, V: ?Sized> Clone for ChartView<'a, V> {
+    fn clone(&self) -> Self {
+        match self {
+            ChartView::Borrowed(view) => ChartView::Borrowed(*view),
+            ChartView::Owned(view) => ChartView::Owned(Arc::clone(view)),
+        }
+    }
+}
+
+impl<'a, V: ?Sized> Deref for ChartView<'a, V> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            ChartView::Borrowed(view) => view,
+            ChartView::Owned(view) => view.as_ref(),
+        }
+    }
+}
+
+// ChartAxis is an axis of a chart that keeps its scale,
+// the axis is laid out for the chart view size when the chart is rendered.
+#[derive(Clone)]
+struct ChartAxis {
+    layout: Arc<dyn Fn(i32, i32) -> Axis + Send + Sync>,
+    label: String,
+    grid_visible: bool,
+}
+
+impl ChartAxis {
+    // Create a new ChartAxis for the provided scale.
+    fn new<T: ToString, S: Scale<T> + Clone + Send + Sync + 'static>(
+        scale: S,
+        position: AxisPosition,
+    ) -> Self {
+        let layout = move |view_width: i32, view_height: i32| {
+            let size = match position {
+                AxisPosition::Top | AxisPosition::Bottom => view_width,
+                AxisPosition::Left | AxisPosition::Right => view_height,
+            };
+            Axis::new(&fit_scale(&scale, size), position, view_width, view_height)
+        };
+
+        Self {
+            layout: Arc::new(layout),
+            label: String::new(),
+            grid_visible: DEFAULT_AXIS_GRID_VISIBLE,
+        }
+    }
+
+    // Configure axis label.
+    fn set_label(&mut self, label: &str) {
+        self.label = label.to_string();
+    }
+
+    // Configure grid lines visibility for axis ticks.
+    fn set_grid_visible(&mut self, grid_visible: bool) {
+        self.grid_visible = grid_visible;
+    }
+
+    // Get axis laid out for the provided view size.
+    fn axis(&self, view_width: i32, view_height: i32) -> Axis {
+        let mut axis = (self.layout)(view_width, view_height);
+        axis.set_label(&self.label);
+        axis.set_grid_visible(self.grid_visible);
+        axis
+    }
+}
+
+/// SyncChart is a chart that only accepts views that are `Send` and `Sync`.
+/// SyncChart that owns all of its views is `'static`, it can be sent to other threads.
+pub type SyncChart<'a> = Chart<'a, dyn View + Send + Sync + 'a>;
+
+/// Chart represents a single document with one or more views, axes, a grid, a title and a legend.
+/// Chart that owns all of its views is `'static` and can be cloned,
+/// use [`SyncChart`] to send it to other threads.
+pub struct Chart<'a, V: ?Sized + View + 'a = dyn View + 'a> {
+    margin_top: Option<i32>,
+    margin_bottom: Option<i32>,
+    margin_left: Option<i32>,
+    margin_right: Option<i32>,
+    auto_margins: bool,
     width: i32,
     height: i32,
-    x_axis_top: Option<Axis>,
-    x_axis_bottom: Option<Axis>,
-    y_axis_left: Option<Axis>,
-    y_axis_right: Option<Axis>,
-    views: Vec<&'a dyn View>,
+    x_axis_top: Option<ChartAxis>,
+    x_axis_bottom: Option<ChartAxis>,
+    y_axis_left: Option<ChartAxis>,
+    y_axis_right: Option<ChartAxis>,
+    views: Vec<ChartView<'a, V>>,
     title: String,
+    legend_visible: bool,
+    legend_position: LegendPosition,
+    legend_orientation: Option<Orientation>,
+    grid_style: Option<GridStyle>,
+    grid_minor_style: Option<GridStyle>,
+    grid_minor_visible: bool,
+    palette: Option<Palette>,
+    theme: Theme,
 }
 
 impl<'a> Chart<'a> {
     /// Create a new chart.
     pub fn new() -> Self {
+        Self::empty()
+    }
+
+    /// Add a view that is owned by chart.
+    pub fn add_owned_view<T: View + 'a>(mut self, view: T) -> Self {
+        self.views.push(ChartView::Owned(Arc::new(view)));
+        self
+    }
+}
+
+impl<'a> SyncChart<'a> {
+    /// Create a new chart that only accepts views that are `Send` and `Sync`.
+    pub fn new_sync() -> Self {
+        Self::empty()
+    }
+
+    /// Add a view that is owned by chart.
+    pub fn add_owned_view<T: View + Send + Sync + 'a>(mut self, view: T) -> Self {
+        self.views.push(ChartView::Owned(Arc::new(view)));
+        self
+    }
+}
+
+impl<'a, V: ?Sized + View + 'a> Chart<'a, V> {
+    // Create a chart without views, axes and title.
+    fn empty() -> Self {
         Chart {
-            margin_top: DEFAULT_MARGIN_TOP,
-            margin_bottom: DEFAULT_MARGIN_BOTTOM,
-            margin_left: DEFAULT_MARGIN_LEFT,
-            margin_right: DEFAULT_MARGIN_RIGHT,
+            margin_top: None,
+            margin_bottom: None,
+            margin_left: None,
+            margin_right: None,
+            auto_margins: DEFAULT_AUTO_MARGINS,
             width: DEFAULT_WIDTH,
             height: DEFAULT_HEIGHT,
             x_axis_top: None,
@@ -48,40 +177,60 @@ impl<'a> Chart<'a> {
             y_axis_right: None,
             views: Vec::new(),
             title: String::new(),
+            legend_visible: DEFAULT_LEGEND_VISIBLE,
+            legend_position: DEFAULT_LEGEND_POSITION,
+            legend_orientation: None,
+            grid_style: None,
+            grid_minor_style: None,
+            grid_minor_visible: false,
+            palette: None,
+            theme: Theme::default(),
         }
     }
 
     /// Get chart width that can be used for views.
     pub fn view_width(&self) -> i32 {
-        self.width - self.margin_left - self.margin_right
+        let (_, _, margin_left, margin_right) = self.margins();
+        let (_, _, legend_right) = self.legend_space(self.legend().as_ref());
+        self.width - margin_left - margin_right - legend_right
     }
 
     /// Get chart height that can be used for views.
     pub fn view_height(&self) -> i32 {
-        self.height - self.margin_top - self.margin_bottom
+        let (margin_top, margin_bottom, _, _) = self.margins();
+        let (legend_top, legend_bottom, _) = self.legend_space(self.legend().as_ref());
+        self.height - margin_top - margin_bottom - legend_top - legend_bottom
     }
 
     /// Set chart top margin.
     pub fn set_margin_top(mut self, margin_top: i32) -> Self {
-        self.margin_top = margin_top;
+        self.margin_top = Some(margin_top);
         self
     }
 
     /// Set chart bottom margin.
     pub fn set_margin_bottom(mut self, margin_bottom: i32) -> Self {
-        self.margin_bottom = margin_bottom;
+        self.margin_bottom = Some(margin_bottom);
         self
     }
 
     /// Set chart left margin.
     pub fn set_margin_left(mut self, margin_left: i32) -> Self {
-        self.margin_left = margin_left;
+        self.margin_left = Some(margin_left);
         self
     }
 
     /// Set chart right margin.
     pub fn set_margin_right(mut self, margin_right: i32) -> Self {
-        self.margin_right = margin_right;
+        self.margin_right = Some(margin_right);
+        self
+    }
+
+    /// Configure automatic margins that fit tick labels, axis labels and the title.
+    /// Margins are computed inside of the chart width and height so the view area shrinks to fit them.
+    /// Manual margins are kept as they are.
+    pub fn set_auto_margins(mut self, auto_margins: bool) -> Self {
+        self.auto_margins = auto_margins;
         self
     }
 
@@ -99,89 +248,109 @@ impl<'a> Chart<'a> {
 
     /// Set BandScale for top axis.
     pub fn set_axis_top_band(mut self, scale: BandScale) -> Self {
-        self.x_axis_top = Some(Axis::new(
-            &scale,
-            AxisPosition::Top,
-            self.view_width(),
-            self.view_height(),
-        ));
+        self.x_axis_top = Some(ChartAxis::new(scale, AxisPosition::Top));
         self
     }
 
     /// Set LinearScale for top axis.
     pub fn set_axis_top_linear(mut self, scale: LinearScale) -> Self {
-        self.x_axis_top = Some(Axis::new(
-            &scale,
-            AxisPosition::Top,
-            self.view_width(),
-            self.view_height(),
-        ));
+        self.x_axis_top = Some(ChartAxis::new(scale, AxisPosition::Top));
+        self
+    }
+
+    /// Set LogScale for top axis.
+    pub fn set_axis_top_log(mut self, scale: LogScale) -> Self {
+        self.x_axis_top = Some(ChartAxis::new(scale, AxisPosition::Top));
+        self
+    }
+
+    /// Set TimeScale for top axis.
+    pub fn set_axis_top_time<Tz: TimeZone + 'static>(mut self, scale: TimeScale<Tz>) -> Self
+    where
+        Tz::Offset: Display + Send + Sync,
+    {
+        self.x_axis_top = Some(ChartAxis::new(scale, AxisPosition::Top));
         self
     }
 
     /// Set BandScale for bottom axis.
     pub fn set_axis_bottom_band(mut self, scale: BandScale) -> Self {
-        self.x_axis_bottom = Some(Axis::new(
-            &scale,
-            AxisPosition::Bottom,
-            self.view_width(),
-            self.view_height(),
-        ));
+        self.x_axis_bottom = Some(ChartAxis::new(scale, AxisPosition::Bottom));
         self
     }
 
     /// Set LinearScale for bottom axis.
     pub fn set_axis_bottom_linear(mut self, scale: LinearScale) -> Self {
-        self.x_axis_bottom = Some(Axis::new(
-            &scale,
-            AxisPosition::Bottom,
-            self.view_width(),
-            self.view_height(),
-        ));
+        self.x_axis_bottom = Some(ChartAxis::new(scale, AxisPosition::Bottom));
+        self
+    }
+
+    /// Set LogScale for bottom axis.
+    pub fn set_axis_bottom_log(mut self, scale: LogScale) -> Self {
+        self.x_axis_bottom = Some(ChartAxis::new(scale, AxisPosition::Bottom));
+        self
+    }
+
+    /// Set TimeScale for bottom axis.
+    pub fn set_axis_bottom_time<Tz: TimeZone + 'static>(mut self, scale: TimeScale<Tz>) -> Self
+    where
+        Tz::Offset: Display + Send + Sync,
+    {
+        self.x_axis_bottom = Some(ChartAxis::new(scale, AxisPosition::Bottom));
         self
     }
 
     /// Set BandScale for left axis.
     pub fn set_axis_left_band(mut self, scale: BandScale) -> Self {
-        self.y_axis_left = Some(Axis::new(
-            &scale,
-            AxisPosition::Left,
-            self.view_width(),
-            self.view_height(),
-        ));
+        self.y_axis_left = Some(ChartAxis::new(scale, AxisPosition::Left));
         self
     }
 
     /// Set LinearScale for left axis.
     pub fn set_axis_left_linear(mut self, scale: LinearScale) -> Self {
-        self.y_axis_left = Some(Axis::new(
-            &scale,
-            AxisPosition::Left,
-            self.view_width(),
-            self.view_height(),
-        ));
+        self.y_axis_left = Some(ChartAxis::new(scale, AxisPosition::Left));
+        self
+    }
+
+    /// Set LogScale for left axis.
+    pub fn set_axis_left_log(mut self, scale: LogScale) -> Self {
+        self.y_axis_left = Some(ChartAxis::new(scale, AxisPosition::Left));
+        self
+    }
+
+    /// Set TimeScale for left axis.
+    pub fn set_axis_left_time<Tz: TimeZone + 'static>(mut self, scale: TimeScale<Tz>) -> Self
+    where
+        Tz::Offset: Display + Send + Sync,
+    {
+        self.y_axis_left = Some(ChartAxis::new(scale, AxisPosition::Left));
         self
     }
 
     /// Set BandScale for right axis.
     pub fn set_axis_right_band(mut self, scale: BandScale) -> Self {
-        self.y_axis_right = Some(Axis::new(
-            &scale,
-            AxisPosition::Right,
-            self.view_width(),
-            self.view_height(),
-        ));
+        self.y_axis_right = Some(ChartAxis::new(scale, AxisPosition::Right));
         self
     }
 
     /// Set LinearScale for right axis.
     pub fn set_axis_right_linear(mut self, scale: LinearScale) -> Self {
-        self.y_axis_right = Some(Axis::new(
-            &scale,
-            AxisPosition::Right,
-            self.view_width(),
-            self.view_height(),
-        ));
+        self.y_axis_right = Some(ChartAxis::new(scale, AxisPosition::Right));
+        self
+    }
+
+    /// Set LogScale for right axis.
+    pub fn set_axis_right_log(mut self, scale: LogScale) -> Self {
+        self.y_axis_right = Some(ChartAxis::new(scale, AxisPosition::Right));
+        self
+    }
+
+    /// Set TimeScale for right axis.
+    pub fn set_axis_right_time<Tz: TimeZone + 'static>(mut self, scale: TimeScale<Tz>) -> Self
+    where
+        Tz::Offset: Display + Send + Sync,
+    {
+        self.y_axis_right = Some(ChartAxis::new(scale, AxisPosition::Right));
         self
     }
 
@@ -217,73 +386,429 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Set grid lines visibility for top axis ticks.
+    pub fn set_axis_top_grid_visible(mut self, grid_visible: bool) -> Self {
+        if let Some(ref mut axis) = self.x_axis_top {
+            axis.set_grid_visible(grid_visible);
+        }
+        self
+    }
+
+    /// Set grid lines visibility for bottom axis ticks.
+    pub fn set_axis_bottom_grid_visible(mut self, grid_visible: bool) -> Self {
+        if let Some(ref mut axis) = self.x_axis_bottom {
+            axis.set_grid_visible(grid_visible);
+        }
+        self
+    }
+
+    /// Set grid lines visibility for left axis ticks.
+    pub fn set_axis_left_grid_visible(mut self, grid_visible: bool) -> Self {
+        if let Some(ref mut axis) = self.y_axis_left {
+            axis.set_grid_visible(grid_visible);
+        }
+        self
+    }
+
+    /// Set grid lines visibility for right axis ticks.
+    pub fn set_axis_right_grid_visible(mut self, grid_visible: bool) -> Self {
+        if let Some(ref mut axis) = self.y_axis_right {
+            axis.set_grid_visible(grid_visible);
+        }
+        self
+    }
+
+    /// Set style of major grid lines, the theme style is used by default.
+    pub fn set_grid_style(mut self, grid_style: GridStyle) -> Self {
+        self.grid_style = Some(grid_style);
+        self
+    }
+
+    /// Set style of minor grid lines and make them visible, the theme style is used by default.
+    pub fn set_grid_minor_style(mut self, grid_minor_style: GridStyle) -> Self {
+        self.grid_minor_style = Some(grid_minor_style);
+        self.grid_minor_visible = true;
+        self
+    }
+
+    /// Set minor grid lines visibility.
+    /// Minor lines are placed in the middle between major lines.
+    pub fn set_grid_minor_visible(mut self, grid_minor_visible: bool) -> Self {
+        self.grid_minor_visible = grid_minor_visible;
+        self
+    }
+
+    /// Set palette that assigns the next color to each view or series without an explicitly set color.
+    /// The theme palette is used by default.
+    pub fn set_palette(mut self, palette: Palette) -> Self {
+        self.palette = Some(palette);
+        self
+    }
+
+    /// Set chart theme that is applied to all rendered shapes.
+    /// Margins, grid styles and palette that are set on the chart take precedence over the theme.
+    pub fn set_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
     /// Set chart title.
     pub fn set_title(mut self, title: &str) -> Self {
         self.title = title.to_string();
         self
     }
 
-    /// Add a view to chart.
-    pub fn add_view(mut self, view: &'a dyn View) -> Self {
-        self.views.push(view);
+    /// Set legend visibility.
+    /// Legend is rendered only if views contain named series.
+    pub fn set_legend_visible(mut self, legend_visible: bool) -> Self {
+        self.legend_visible = legend_visible;
         self
     }
 
-    /// Set chart views.
-    pub fn set_views(mut self, views: Vec<&'a dyn View>) -> Self {
-        self.views = views;
+    /// Set legend position.
+    pub fn set_legend_position(mut self, legend_position: LegendPosition) -> Self {
+        self.legend_position = legend_position;
         self
     }
 
+    /// Set legend orientation.
+    /// By default legend entries flow horizontally for top and bottom positions and vertically otherwise.
+    pub fn set_legend_orientation(mut self, legend_orientation: Orientation) -> Self {
+        self.legend_orientation = Some(legend_orientation);
+        self
+    }
+
+    /// Add a borrowed view to chart.
+    pub fn add_view(mut self, view: &'a V) -> Self {
+        self.views.push(ChartView::Borrowed(view));
+        self
+    }
+
+    /// Set borrowed chart views.
+    pub fn set_views(mut self, views: Vec<&'a V>) -> Self {
+        self.views = views.into_iter().map(ChartView::Borrowed).collect();
+        self
+    }
+
+    /// Set chart views that are owned by chart.
+    pub fn set_owned_views(mut self, views: Vec<Box<V>>) -> Self {
+        self.views = views
+            .into_iter()
+            .map(|view| ChartView::Owned(Arc::from(view)))
+            .collect();
+        self
+    }
+
+    // Get top, bottom, left and right axes laid out for the provided view size.
+    fn axes(&self, view_width: i32, view_height: i32) -> [Option<Axis>; 4] {
+        // Y axes end above the panels that views draw below their y scale ranges.
+        let bottom_panel_height = self
+            .views
+            .iter()
+            .map(|view| view.bottom_panel_height())
+            .max()
+            .unwrap_or(0);
+        let axis = |axis: &Option<ChartAxis>, view_height: i32| {
+            axis.as_ref().map(|axis| axis.axis(view_width, view_height))
+        };
+
+        [
+            axis(&self.x_axis_top, view_height),
+            axis(&self.x_axis_bottom, view_height),
+            axis(&self.y_axis_left, view_height - bottom_panel_height),
+            axis(&self.y_axis_right, view_height - bottom_panel_height),
+        ]
+    }
+
+    // Get top, bottom, left and right margins that are used to render the chart.
+    fn margins(&self) -> (i32, i32, i32, i32) {
+        if !self.auto_margins {
+            let (margin_top, margin_bottom, margin_left, margin_right) = self.theme.margins();
+            return (
+                self.margin_top.unwrap_or(margin_top),
+                self.margin_bottom.unwrap_or(margin_bottom),
+                self.margin_left.unwrap_or(margin_left),
+                self.margin_right.unwrap_or(margin_right),
+            );
+        }
+
+        // Tick labels that overflow axis ends depend on the view size so margins are fitted twice,
+        // the second time for the view size that is left by the first estimate.
+        let manual_margins = (
+            self.margin_top.unwrap_or(0),
+            self.margin_bottom.unwrap_or(0),
+            self.margin_left.unwrap_or(0),
+            self.margin_right.unwrap_or(0),
+        );
+        self.fitted_margins(self.fitted_margins(manual_margins))
+    }
+
+    // Get automatic margins that fit the axes laid out for the view size that is left by the provided margins.
+    fn fitted_margins(&self, margins: (i32, i32, i32, i32)) -> (i32, i32, i32, i32) {
+        let (margin_top, margin_bottom, margin_left, margin_right) = margins;
+        let axes = self.axes(
+            self.width - margin_left - margin_right,
+            self.height - margin_top - margin_bottom,
+        );
+
+        // Each side fits its axis and the labels of perpendicular axes that overflow their ends.
+        let size = |axis: &Option<Axis>| axis.as_ref().map_or(0, |axis| axis.size(&self.theme));
+        let overflow = |axes: [&Option<Axis>; 2]| {
+            axes.iter()
+                .filter_map(|axis| axis.as_ref())
+                .map(|axis| axis.overflow(&self.theme))
+                .fold((0, 0), |acc, overflow| {
+                    (acc.0.max(overflow.0), acc.1.max(overflow.1))
+                })
+        };
+        let [x_axis_top, x_axis_bottom, y_axis_left, y_axis_right] = &axes;
+        let (top_overflow, bottom_overflow) = overflow([y_axis_left, y_axis_right]);
+        let (left_overflow, right_overflow) = overflow([x_axis_top, x_axis_bottom]);
+        let padding = self.theme.padding();
+        let title_height = self.title_height();
+
+        (
+            self.margin_top
+                .unwrap_or(title_height + size(x_axis_top).max(top_overflow) + padding),
+            self.margin_bottom
+                .unwrap_or(size(x_axis_bottom).max(bottom_overflow) + padding),
+            self.margin_left
+                .unwrap_or(size(y_axis_left).max(left_overflow) + padding),
+            self.margin_right
+                .unwrap_or(size(y_axis_right).max(right_overflow) + padding),
+        )
+    }
+
+    // Get chart theme with the chart palette that assigns colors starting from the provided palette offset.
+    fn palette_theme(&self, palette_offset: usize) -> Theme {
+        let theme = match self.palette {
+            Some(ref palette) => self.theme.clone().set_palette(palette.clone()),
+            None => self.theme.clone(),
+        };
+        theme.set_palette_offset(palette_offset)
+    }
+
+    // Get palette offsets of views so every view series without explicitly set color
+    // gets the next palette color.
+    fn palette_offsets(&self) -> Vec<usize> {
+        self.views
+            .iter()
+            .scan(0, |offset, view| {
+                let view_offset = *offset;
+                *offset += view.palette_series_count();
+                Some(view_offset)
+            })
+            .collect()
+    }
+
+    // Get approximate height of the title with the padding below it.
+    fn title_height(&self) -> i32 {
+        if self.title.is_empty() {
+            return 0;
+        }
+
+        DEFAULT_TITLE_Y_TRANSFORM + self.theme.title_font_size() / 2 + self.theme.padding()
+    }
+
+    // Get top, bottom and right space that is taken from the view area for the legend
+    // so the legend never overlaps the views and the document keeps the chart size.
+    fn legend_space(&self, legend: Option<&Legend>) -> (i32, i32, i32) {
+        let legend = match legend {
+            Some(legend) => legend,
+            None => return (0, 0, 0),
+        };
+
+        let padding = self.theme.padding();
+        match self.legend_position {
+            LegendPosition::Top => {
+                // Automatic top margin already fits the title that is placed above the legend.
+                let title_height = if self.auto_margins && self.margin_top.is_none() {
+                    self.title_height()
+                } else {
+                    0
+                };
+                (self.legend_top() + legend.height() - title_height, 0, 0)
+            }
+            LegendPosition::Bottom => (0, legend.height() + padding, 0),
+            LegendPosition::Right => (0, 0, legend.width() + padding),
+            _ => (0, 0, 0),
+        }
+    }
+
+    // Get vertical position of the top legend that is placed below the title.
+    fn legend_top(&self) -> i32 {
+        if self.title.is_empty() {
+            return self.theme.padding();
+        }
+
+        self.title_height()
+    }
+
+    // Create legend from the entries of all views.
+    fn legend(&self) -> Option<Legend> {
+        if !self.legend_visible {
+            return None;
+        }
+
+        let entries: Vec<_> = self
+            .views
+            .iter()
+            .zip(self.palette_offsets())
+            .flat_map(|(view, palette_offset)| {
+                view.legend_entries()
+                    .into_iter()
+                    .map(move |entry| entry.offset_palette_series(palette_offset))
+            })
+            .collect();
+
+        let orientation = self
+            .legend_orientation
+            .unwrap_or(match self.legend_position {
+                LegendPosition::Top | LegendPosition::Bottom => Orientation::Horizontal,
+                _ => Orientation::Vertical,
+            });
+        let (_, _, margin_left, margin_right) = self.margins();
+        let max_width = match self.legend_position {
+            LegendPosition::Top | LegendPosition::Bottom => self.width - margin_left - margin_right,
+            _ => self.width - margin_left - margin_right - 2 * self.theme.padding(),
+        };
+
+        let legend = self
+            .views
+            .iter()
+            .filter_map(|view| view.color_scale())
+            .fold(
+                Legend::new(entries, orientation, max_width),
+                |legend, color_scale| legend.add_color_bar(ColorBar::new(color_scale, orientation)),
+            );
+        if legend.is_empty() {
+            return None;
+        }
+
+        Some(legend)
+    }
+
     /// Get chart SVG representation.
     pub fn to_svg(&self) -> svg::Document {
         let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_CHART);
+        let (margin_top, margin_bottom, margin_left, margin_right) = self.margins();
+        let (width, height) = (self.width, self.height);
 
-        // Add axes.
-        if let Some(ref axis) = self.x_axis_top {
-            let mut axis_group = axis.to_svg();
-            axis_group.assign(
-                TRANSFORM_ATTR,
-                translate_x_y(self.margin_left, self.margin_top),
+        // Take space for the legend from the view area so the legend never overlaps the views.
+        let legend = self.legend();
+        let (legend_top, legend_bottom, legend_right) = self.legend_space(legend.as_ref());
+        let view_width = width - margin_left - margin_right - legend_right;
+        let view_height = height - margin_top - margin_bottom - legend_top - legend_bottom;
+        let axes = self.axes(view_width, view_height);
+        let top = legend_top + margin_top;
+        let bottom = top + view_height;
+        let right = margin_left + view_width;
+
+        let mut legend_translate = (0, 0);
+        if let Some(ref legend) = legend {
+            let legend_margin = self.theme.padding();
+            let centered_x = margin_left + (view_width - legend.width()) / 2;
+            let inside_left = margin_left + legend_margin;
+            let inside_right = right - legend.width() - legend_margin;
+            let inside_top = top + legend_margin;
+            let inside_bottom = bottom - legend.height() - legend_margin;
+
+            legend_translate = match self.legend_position {
+                LegendPosition::Top => (centered_x, self.legend_top()),
+                LegendPosition::Right => (width - legend_right, top),
+                LegendPosition::Bottom => (centered_x, height - legend_bottom),
+                LegendPosition::InsideTopLeft => (inside_left, inside_top),
+                LegendPosition::InsideTopRight => (inside_right, inside_top),
+                LegendPosition::InsideBottomLeft => (inside_left, inside_bottom),
+                LegendPosition::InsideBottomRight => (inside_right, inside_bottom),
+            };
+        }
+
+        // Add background behind everything else.
+        if let Some(background_color) = self.theme.background_color() {
+            res.append(
+                svg::node::element::Rectangle::new()
+                    .set(CLASS_ATTR, CLASS_BACKGROUND)
+                    .set(X_ATTR, START)
+                    .set(Y_ATTR, START)
+                    .set(WIDTH_ATTR, width)
+                    .set(HEIGHT_ATTR, height)
+                    .set(FILL_ATTR, background_color),
+            );
+        }
+
+        // Add grid behind axes and views.
+        let grid_axes: Vec<_> = axes
+            .iter()
+            .filter_map(|axis| axis.as_ref())
+            .filter(|axis| axis.grid_visible())
+            .collect();
+        if !grid_axes.is_empty() {
+            let mut grid = Grid::new(
+                view_width,
+                view_height,
+                self.grid_style
+                    .clone()
+                    .unwrap_or_else(|| self.theme.grid_style().clone()),
+                self.grid_minor_visible.then(|| {
+                    self.grid_minor_style
+                        .clone()
+                        .unwrap_or_else(|| self.theme.grid_minor_style().clone())
+                }),
             );
+            for axis in grid_axes {
+                grid.add_axis(axis);
+            }
+            let mut grid_group = grid.to_svg();
+            grid_group.assign(TRANSFORM_ATTR, translate_x_y(margin_left, top));
+            res.append(grid_group);
+        }
+
+        // Add axes.
+        let [x_axis_top, x_axis_bottom, y_axis_left, y_axis_right] = &axes;
+        if let Some(axis) = x_axis_top {
+            let mut axis_group = axis.to_svg(&self.theme);
+            axis_group.assign(TRANSFORM_ATTR, translate_x_y(margin_left, top));
             res.append(axis_group);
         };
-        if let Some(ref axis) = self.x_axis_bottom {
-            let mut axis_group = axis.to_svg();
-            axis_group.assign(
-                TRANSFORM_ATTR,
-                translate_x_y(self.margin_left, self.height - self.margin_bottom),
-            );
+        if let Some(axis) = x_axis_bottom {
+            let mut axis_group = axis.to_svg(&self.theme);
+            axis_group.assign(TRANSFORM_ATTR, translate_x_y(margin_left, bottom));
             res.append(axis_group);
         };
-        if let Some(ref axis) = self.y_axis_left {
-            let mut axis_group = axis.to_svg();
-            axis_group.assign(
-                TRANSFORM_ATTR,
-                translate_x_y(self.margin_left, self.margin_top),
-            );
+        if let Some(axis) = y_axis_left {
+            let mut axis_group = axis.to_svg(&self.theme);
+            axis_group.assign(TRANSFORM_ATTR, translate_x_y(margin_left, top));
             res.append(axis_group);
         };
-        if let Some(ref axis) = self.y_axis_right {
-            let mut axis_group = axis.to_svg();
-            axis_group.assign(
-                TRANSFORM_ATTR,
-                translate_x_y(self.width - self.margin_right, self.margin_top),
-            );
+        if let Some(axis) = y_axis_right {
+            let mut axis_group = axis.to_svg(&self.theme);
+            axis_group.assign(TRANSFORM_ATTR, translate_x_y(right, top));
             res.append(axis_group);
         };
 
         // Add views.
         let mut views_group = svg::node::element::Group::new()
             .set(CLASS_ATTR, CLASS_VIEWS)
-            .set(
+            .set(TRANSFORM_ATTR, translate_x_y(margin_left, top));
+        for (view, palette_offset) in self.views.iter().zip(self.palette_offsets()) {
+            views_group.append(view.to_svg_with_theme(
+                view_width,
+                view_height,
+                &self.palette_theme(palette_offset),
+            ));
+        }
+        res.append(views_group);
+
+        // Add legend.
+        if let Some(ref legend) = legend {
+            let mut legend_group = legend.to_svg(&self.palette_theme(0));
+            legend_group.assign(
                 TRANSFORM_ATTR,
-                translate_x_y(self.margin_left, self.margin_top),
+                translate_x_y(legend_translate.0, legend_translate.1),
             );
-        for view in self.views.iter() {
-            views_group.append(view.to_svg());
+            res.append(legend_group);
         }
-        res.append(views_group);
 
         // Add title.
         if !self.title.is_empty() {
@@ -291,35 +816,119 @@ impl<'a> Chart<'a> {
                 .set(CLASS_ATTR, CLASS_TITLE)
                 .set(
                     TRANSFORM_ATTR,
-                    translate_x_y(self.width / 2, DEFAULT_TITLE_Y_TRANSFORM),
+                    translate_x_y(width / 2, DEFAULT_TITLE_Y_TRANSFORM),
                 )
                 .add(
                     svg::node::element::Text::new()
                         .set(X_ATTR, START)
                         .set(Y_ATTR, START)
                         .set(DY_ATTR, DEFAULT_DY)
-                        .set(FILL_ATTR, DEFAULT_FONT_COLOR)
+                        .set(FILL_ATTR, self.theme.font_color())
                         .set(TEXT_ANCHOR_ATTR, TEXT_ANCHOR_MIDDLE)
-                        .set(FONT_SIZE_ATTR, DEFAULT_TITLE_FONT_SIZE)
-                        .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
+                        .set(FONT_SIZE_ATTR, px(self.theme.title_font_size()))
+                        .set(FONT_FAMILY_ATTR, self.theme.font_family())
                         .add(svg::node::Text::new(&self.title)),
                 );
             res.append(title_group);
         }
 
         svg::Document::new()
-            .set(WIDTH_ATTR, self.width)
-            .set(HEIGHT_ATTR, self.height)
-            .set(VIEW_BOX_ATTR, (START, START, self.width, self.height))
+            .set(WIDTH_ATTR, width)
+            .set(HEIGHT_ATTR, height)
+            .set(VIEW_BOX_ATTR, (START, START, width, height))
             .add(res)
     }
 
+    /// Get chart SVG representation as a string.
+    pub fn to_svg_string(&self) -> String {
+        self.to_svg().to_string()
+    }
+
     /// Save chart to SVG file at the specified path.
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
         svg::save(path, &self.to_svg())?;
 
         Ok(())
     }
+
+    /// Write chart SVG representation to the provided writer.
+    pub fn write_to<W: Write>(&self, writer: W) -> Result<(), Error> {
+        svg::write(writer, &self.to_svg()).map_err(Error::WriteError)?;
+
+        Ok(())
+    }
+
+    /// Get chart PDF representation with a single page of the chart size.
+    #[cfg(feature = "pdf")]
+    pub fn to_pdf(&self) -> Result<Vec<u8>, Error> {
+        crate::PdfDocument::new().add_chart(self)?.to_bytes()
+    }
+
+    /// Save chart to PDF file at the specified path.
+    #[cfg(feature = "pdf")]
+    pub fn save_pdf<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        std::fs::write(path, self.to_pdf()?)?;
+
+        Ok(())
+    }
+
+    /// Get chart PNG representation.
+    /// Chart size is multiplied by the scale factor, use `dpi / 96.0` to render the chart for a specific DPI.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self, scale: f32) -> Result<Vec<u8>, Error> {
+        crate::render::png::svg_to_png(&self.to_svg_string(), scale)
+    }
+
+    /// Save chart to PNG file at the specified path.
+    /// Chart size is multiplied by the scale factor, use `dpi / 96.0` to render the chart for a specific DPI.
+    #[cfg(feature = "png")]
+    pub fn save_png<P: AsRef<Path>>(&self, path: P, scale: f32) -> Result<(), Error> {
+        std::fs::write(path, self.to_png(scale)?)?;
+
+        Ok(())
+    }
+
+    /// Get chart PNG representation for the provided DPI.
+    /// Chart size is defined in CSS pixels at 96 DPI, so 300 DPI renders a 800px wide chart 2500px wide.
+    #[cfg(feature = "png")]
+    pub fn to_png_with_dpi(&self, dpi: f32) -> Result<Vec<u8>, Error> {
+        self.to_png(crate::render::png::dpi_scale(dpi))
+    }
+
+    /// Save chart to PNG file at the specified path for the provided DPI.
+    /// Chart size is defined in CSS pixels at 96 DPI, so 300 DPI renders a 800px wide chart 2500px wide.
+    #[cfg(feature = "png")]
+    pub fn save_png_with_dpi<P: AsRef<Path>>(&self, path: P, dpi: f32) -> Result<(), Error> {
+        self.save_png(path, crate::render::png::dpi_scale(dpi))
+    }
+}
+
+impl<'a, V: ?Sized + View + 'a> Clone for Chart<'a, V> {
+    fn clone(&self) -> Self {
+        Chart {
+            margin_top: self.margin_top,
+            margin_bottom: self.margin_bottom,
+            margin_left: self.margin_left,
+            margin_right: self.margin_right,
+            auto_margins: self.auto_margins,
+            width: self.width,
+            height: self.height,
+            x_axis_top: self.x_axis_top.clone(),
+            x_axis_bottom: self.x_axis_bottom.clone(),
+            y_axis_left: self.y_axis_left.clone(),
+            y_axis_right: self.y_axis_right.clone(),
+            views: self.views.clone(),
+            title: self.title.clone(),
+            legend_visible: self.legend_visible,
+            legend_position: self.legend_position,
+            legend_orientation: self.legend_orientation,
+            grid_style: self.grid_style.clone(),
+            grid_minor_style: self.grid_minor_style.clone(),
+            grid_minor_visible: self.grid_minor_visible,
+            palette: self.palette.clone(),
+            theme: self.theme.clone(),
+        }
+    }
 }
 
 impl<'a> Default for Chart<'a> {
@@ -327,3 +936,207 @@ impl<'a> Default for Chart<'a> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, LineView, PointType, ScatterView};
+
+    #[test]
+    fn chart_write_to() {
+        let chart = Chart::new().set_width(200).set_height(100);
+        let expected_svg = r#"<svg height="100" viewBox="0 0 200 100" width="200" xmlns="http://www.w3.org/2000/svg">
+<g class="chart">
+<g class="views" transform="translate(60,90)"/>
+</g>
+</svg>"#;
+        assert_eq!(chart.to_svg_string(), expected_svg);
+
+        let mut buf = Vec::new();
+        chart.write_to(&mut buf).expect("unable to write chart");
+        assert_eq!(String::from_utf8(buf).expect("invalid utf-8"), expected_svg);
+
+        struct FailingWriter;
+
+        impl Write for FailingWriter {
+            fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::from(std::io::ErrorKind::BrokenPipe))
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        assert!(matches!(
+            chart.write_to(FailingWriter),
+            Err(Error::WriteError(_))
+        ));
+    }
+
+    #[test]
+    fn chart_legend() {
+        let x_scale = BandScale::new(vec!["A".to_string(), "B".to_string()], 0, 100);
+        let y_scale = LinearScale::new(0_f32, 100_f32, 100, 0);
+        let named_view = LineView::new(x_scale.clone(), y_scale.clone())
+            .set_name("Named")
+            .set_point_type(PointType::Square)
+            .set_data(&[10_f32, 20_f32])
+            .expect("unable to set data");
+        let unnamed_view = LineView::new(x_scale, y_scale)
+            .set_data(&[30_f32, 40_f32])
+            .expect("unable to set data");
+
+        // Only named views are represented in the legend.
+        let chart = Chart::new()
+            .set_width(200)
+            .set_height(200)
+            .add_view(&named_view)
+            .add_view(&unnamed_view);
+        let legend = chart.legend().expect("legend is expected");
+        assert_eq!(legend.width(), 66);
+        assert_eq!(legend.height(), 22);
+
+        // Right legend takes its space from the view area so the document keeps the chart size.
+        let chart_svg = chart.to_svg_string();
+        assert!(chart_svg.contains(r#"viewBox="0 0 200 200""#));
+        assert!(chart_svg.contains(r#"<g class="legend" transform="translate(124,90)">"#));
+        assert_eq!(chart.view_width(), 200 - 60 - 40 - 76);
+
+        // Top legend shifts the views down.
+        let chart = chart.set_legend_position(LegendPosition::Top);
+        let chart_svg = chart.to_svg_string();
+        assert!(chart_svg.contains(r#"viewBox="0 0 200 200""#));
+        assert!(chart_svg.contains(r#"<g class="views" transform="translate(60,122)">"#));
+        assert!(chart_svg.contains(r#"<g class="legend" transform="translate(77,10)">"#));
+        assert_eq!(chart.view_height(), 200 - 90 - 50 - 32);
+    }
+
+    #[test]
+    fn chart_auto_margins() {
+        let x_scale = LinearScale::new(0_f32, 100_f32, 0, 400);
+        let y_scale = BandScale::new(vec!["Long category name".to_string()], 0, 280);
+
+        // Left margin fits long categories, right margin fits the last bottom tick label,
+        // the view area shrinks so the document keeps the chart size.
+        let chart = Chart::new()
+            .set_width(400)
+            .set_height(300)
+            .set_auto_margins(true)
+            .set_axis_bottom_linear(x_scale)
+            .set_axis_left_band(y_scale)
+            .set_margin_top(20);
+        let chart_svg = chart.to_svg_string();
+        assert!(chart_svg.contains(r#"viewBox="0 0 400 300""#));
+        assert!(chart_svg.contains(r#"<g class="views" transform="translate(152,20)""#));
+        assert_eq!(chart.view_width(), 227);
+        assert_eq!(chart.view_height(), 248);
+    }
+
+    #[test]
+    fn chart_deferred_layout() {
+        let data = vec![(10_f32, 20_f32), (50_f32, 75_f32)];
+        let chart_svg = |scales_size: i32| {
+            let x_scale = LinearScale::new(0_f32, 100_f32, 0, scales_size);
+            let y_scale = LinearScale::new(0_f32, 100_f32, scales_size, 0);
+            let view = ScatterView::new(x_scale.clone(), y_scale.clone())
+                .set_data(&data)
+                .expect("unable to set data");
+
+            // Chart size is changed after axes and views are set.
+            Chart::new()
+                .set_margin_top(0)
+                .set_margin_bottom(0)
+                .set_margin_left(0)
+                .set_margin_right(0)
+                .set_axis_bottom_linear(x_scale)
+                .set_axis_left_linear(y_scale)
+                .set_views(vec![&view])
+                .set_width(400)
+                .set_height(400)
+                .to_svg_string()
+        };
+
+        assert_eq!(chart_svg(100), chart_svg(400));
+    }
+
+    #[test]
+    fn chart_theme() {
+        let chart = Chart::new()
+            .set_width(200)
+            .set_height(100)
+            .set_theme(Theme::dark().set_margins(10, 10, 10, 10))
+            .set_title("Title");
+        let chart_svg = chart.to_svg_string();
+        assert!(chart_svg.contains(
+            r##"<rect class="background" fill="#1e1e1e" height="100" width="200" x="0" y="0"/>"##
+        ));
+        assert!(chart_svg.contains(r##"fill="#e6e6e6" font-family="sans-serif" font-size="24px""##));
+        assert_eq!(chart.view_width(), 180);
+    }
+
+    #[test]
+    fn chart_owned_views() {
+        let x_scale = BandScale::new(vec!["A".to_string(), "B".to_string()], 0, 100);
+        let y_scale = LinearScale::new(0_f32, 100_f32, 100, 0);
+        let view = LineView::new(x_scale, y_scale)
+            .set_data(&[10_f32, 20_f32])
+            .expect("unable to set data");
+
+        let borrowed_svg = Chart::new().add_view(&view).to_svg_string();
+
+        // Owned chart outlives its views and is rendered on another thread.
+        let chart: SyncChart<'static> = Chart::new_sync().add_owned_view(view.clone());
+        let cloned_chart = chart.clone();
+        let owned_svg = std::thread::spawn(move || cloned_chart.to_svg_string())
+            .join()
+            .expect("unable to render chart");
+        assert_eq!(owned_svg, borrowed_svg);
+        assert_eq!(chart.to_svg_string(), borrowed_svg);
+
+        // Views that aren't Sync can still be borrowed and owned by a chart.
+        struct RcView(std::rc::Rc<LineView>);
+        impl View for RcView {
+            fn to_svg(&self) -> svg::node::element::Group {
+                self.0.to_svg()
+            }
+        }
+        let rc_view = RcView(std::rc::Rc::new(view));
+        assert!(Chart::new()
+            .add_view(&rc_view)
+            .to_svg_string()
+            .contains(r#"class="line""#));
+        assert!(Chart::new()
+            .add_owned_view(rc_view)
+            .to_svg_string()
+            .contains(r#"class="line""#));
+    }
+
+    #[test]
+    fn chart_palette() {
+        let x_scale = BandScale::new(vec!["A".to_string(), "B".to_string()], 0, 100);
+        let y_scale = LinearScale::new(0_f32, 100_f32, 100, 0);
+        let line = |name: &str| {
+            LineView::new(x_scale.clone(), y_scale.clone())
+                .set_name(name)
+                .set_data(&[10_f32, 20_f32])
+                .expect("unable to set data")
+        };
+        let first = line("First");
+        let custom = line("Custom").set_stroke_color(Color::new_from_hex("#ff006c"));
+        let second = line("Second");
+
+        let chart_svg = Chart::new()
+            .set_views(vec![&first, &custom, &second])
+            .set_palette(Palette::tableau10())
+            .to_svg_string();
+        let strokes: Vec<&str> = chart_svg
+            .lines()
+            .filter(|line| line.starts_with(r#"<path class="line""#))
+            .filter_map(|line| line.split("stroke=\"").nth(1))
+            .map(|stroke| &stroke[..7])
+            .collect();
+        assert_eq!(strokes, vec!["#4e79a7", "#ff006c", "#f28e2b"]);
+        assert!(chart_svg.contains(r##"stroke="#f28e2b" stroke-width="2" x1="0""##));
+    }
+}
@@ -10,6 +10,12 @@ pub const COLOR_HEX_GREEN_3: &str = "#005813";
 pub const COLOR_HEX_GREEN_4: &str = "#117401";
 pub const COLOR_HEX_GREEN_5: &str = "#038d05";
 
+pub const COLOR_HEX_RED_1: &str = "#5c0a0a";
+pub const COLOR_HEX_RED_2: &str = "#8c1414";
+pub const COLOR_HEX_RED_3: &str = "#b71c1c";
+pub const COLOR_HEX_RED_4: &str = "#d93a3a";
+pub const COLOR_HEX_RED_5: &str = "#f28b8b";
+
 /// Color can be used to configure colors of different elements on charts.
 pub struct Color {
     value: String,
@@ -28,6 +34,36 @@ impl Color {
         let value = format!("rgb({},{},{})", r, g, b);
         Color { value }
     }
+
+    /// Get (r, g, b) values of the color.
+    /// Returns None if the color is not a hex string or an rgb() function.
+    pub fn rgb(&self) -> Option<(u8, u8, u8)> {
+        if let Some(hex) = self.value.strip_prefix('#') {
+            let channel = |idx: usize, len: usize| {
+                let value = u8::from_str_radix(hex.get(idx * len..(idx + 1) * len)?, 16).ok()?;
+                Some(if len == 1 { value * 17 } else { value })
+            };
+            return match hex.len() {
+                3 | 6 => {
+                    let len = hex.len() / 3;
+                    Some((channel(0, len)?, channel(1, len)?, channel(2, len)?))
+                }
+                _ => None,
+            };
+        }
+
+        let channels: Vec<u8> = self
+            .value
+            .strip_prefix("rgb(")?
+            .strip_suffix(')')?
+            .split(',')
+            .map(|channel| channel.trim().parse().ok())
+            .collect::<Option<Vec<u8>>>()?;
+        match channels.as_slice() {
+            [r, g, b] => Some((*r, *g, *b)),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for Color {
@@ -42,6 +78,96 @@ impl Default for Color {
     }
 }
 
+const PALETTE_TABLEAU_10: [&str; 10] = [
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#bab0ac",
+];
+const PALETTE_SET_1: [&str; 9] = [
+    "#e41a1c", "#377eb8", "#4daf4a", "#984ea3", "#ff7f00", "#ffff33", "#a65628", "#f781bf",
+    "#999999",
+];
+const PALETTE_DARK_2: [&str; 8] = [
+    "#1b9e77", "#d95f02", "#7570b3", "#e7298a", "#66a61e", "#e6ab02", "#a6761d", "#666666",
+];
+const PALETTE_OKABE_ITO: [&str; 8] = [
+    "#e69f00", "#56b4e9", "#009e73", "#f0e442", "#0072b2", "#d55e00", "#cc79a7", "#000000",
+];
+const PALETTE_GRAYSCALE: [&str; 4] = ["#000000", "#525252", "#969696", "#cccccc"];
+
+/// Palette represents an ordered list of categorical colors that a chart assigns to views
+/// and series without explicitly set colors.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    colors: Vec<String>,
+}
+
+impl Palette {
+    /// Create a new Palette from the provided colors.
+    pub fn new(colors: Vec<Color>) -> Self {
+        Self {
+            colors: colors.iter().map(|color| color.to_string()).collect(),
+        }
+    }
+
+    /// Create Tableau 10 palette.
+    pub fn tableau10() -> Self {
+        Self::new_from_hex(&PALETTE_TABLEAU_10)
+    }
+
+    /// Create ColorBrewer Set1 palette.
+    pub fn set1() -> Self {
+        Self::new_from_hex(&PALETTE_SET_1)
+    }
+
+    /// Create ColorBrewer Dark2 palette.
+    pub fn dark2() -> Self {
+        Self::new_from_hex(&PALETTE_DARK_2)
+    }
+
+    /// Create Okabe-Ito palette that stays distinguishable with color vision deficiencies.
+    pub fn okabe_ito() -> Self {
+        Self::new_from_hex(&PALETTE_OKABE_ITO)
+    }
+
+    /// Create palette of gray shades for grayscale printing.
+    pub fn grayscale() -> Self {
+        Self::new_from_hex(&PALETTE_GRAYSCALE)
+    }
+
+    // Create a Palette from hex color values.
+    fn new_from_hex(colors: &[&str]) -> Self {
+        Self {
+            colors: colors.iter().map(|color| color.to_string()).collect(),
+        }
+    }
+
+    /// Get count of palette colors.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Check if palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Get palette color for the provided index.
+    /// Colors are repeated when the index exceeds the palette size.
+    /// Returns None if the palette is empty.
+    pub fn color(&self, index: usize) -> Option<Color> {
+        self.hex(index).map(Color::new_from_hex)
+    }
+
+    // Get palette color value for the provided index.
+    pub(crate) fn hex(&self, index: usize) -> Option<&str> {
+        if self.colors.is_empty() {
+            return None;
+        }
+
+        Some(&self.colors[index % self.colors.len()])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,4 +183,27 @@ mod tests {
         let color = Color::new_from_rgb(253, 185, 200);
         assert_eq!(color.value, "rgb(253,185,200)".to_string());
     }
+
+    #[test]
+    fn rgb() {
+        assert_eq!(Color::new_from_hex("#0e3569").rgb(), Some((14, 53, 105)));
+        assert_eq!(Color::new_from_hex("#fff").rgb(), Some((255, 255, 255)));
+        assert_eq!(
+            Color::new_from_rgb(253, 185, 200).rgb(),
+            Some((253, 185, 200))
+        );
+        assert_eq!(Color::new_from_hex("red").rgb(), None);
+    }
+
+    #[test]
+    fn palette_color() {
+        let palette = Palette::tableau10();
+        assert_eq!(palette.len(), 10);
+        assert_eq!(palette.color(1).unwrap().to_string(), "#f28e2b");
+        assert_eq!(palette.color(11).unwrap().to_string(), "#f28e2b");
+        assert_eq!(
+            Palette::new(Vec::new()).color(0).map(|c| c.to_string()),
+            None
+        );
+    }
 }
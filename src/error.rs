@@ -12,17 +12,85 @@ pub enum Error {
     /// Provided dataset is empty.
     DataIsEmpty,
 
+    /// Domain of a log scale contains non-positive or non-finite values.
+    LogDomainIsNotPositive,
+
+    /// Base of a log scale is not finite or not greater than 1.
+    LogBaseIsInvalid,
+
+    /// Provided value can't be represented on the view scale.
+    ValueIsNotInScaleDomain,
+
+    /// Pie values contain negative or non-finite values or their sum is zero.
+    PieValuesAreInvalid,
+
+    /// Histogram bins count is zero or too large or bins width is not positive.
+    HistogramBinsAreInvalid,
+
+    /// Box plot summary values are not finite or not ordered from minimum to maximum.
+    BoxPlotSummaryIsInvalid,
+
+    /// Color scale has less than two color stops, unsupported colors or non-finite domain.
+    ColorScaleIsInvalid,
+
+    /// OHLC prices are not finite or high and low prices don't bound open and close prices.
+    OhlcValuesAreInvalid,
+
+    /// Stacked area values are not finite or negative.
+    StackedAreaValuesAreInvalid,
+
+    /// Area baseline series doesn't contain a value for each data element.
+    AreaBaselineIsInvalid,
+
     /// Could not save file.
     SaveFileError(std::io::Error),
+
+    /// Could not write to the provided writer.
+    WriteError(std::io::Error),
+
+    /// Could not render PDF document.
+    #[cfg(feature = "pdf")]
+    RenderPdfError(String),
+
+    /// Scale factor of a PNG image is not positive or too large.
+    #[cfg(feature = "png")]
+    PngScaleIsInvalid,
+
+    /// Could not render PNG image.
+    #[cfg(feature = "png")]
+    RenderPngError(String),
+
+    /// Chart contains text but no fonts were found to render it.
+    #[cfg(any(feature = "pdf", feature = "png"))]
+    FontsAreMissing,
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+        match self {
             Error::CategoriesCountDoesntEqual => "categories count doesn't equal to data elements count and it's not supported for the selected view".to_string().fmt(f),
             Error::CategoriesCountIsLess => "categories count is less than data elements count and it's not supported for the selected view".to_string().fmt(f),
             Error::DataIsEmpty => "provided data vector is empty".to_string().fmt(f),
+            Error::LogDomainIsNotPositive => "log scale domain must contain only positive finite values".to_string().fmt(f),
+            Error::LogBaseIsInvalid => "log scale base must be finite and greater than 1".to_string().fmt(f),
+            Error::ValueIsNotInScaleDomain => "provided value can't be represented on the view scale".to_string().fmt(f),
+            Error::PieValuesAreInvalid => "pie values must be non-negative and have a positive sum".to_string().fmt(f),
+            Error::HistogramBinsAreInvalid => "histogram bins count and width must be positive and bins count must not exceed 10000".to_string().fmt(f),
+            Error::BoxPlotSummaryIsInvalid => "box plot summary values must be finite and ordered from minimum to maximum".to_string().fmt(f),
+            Error::ColorScaleIsInvalid => "color scale must have finite domain and at least two hex or rgb colors".to_string().fmt(f),
+            Error::OhlcValuesAreInvalid => "ohlc prices must be finite, bounded by high and low prices and volume must be non-negative".to_string().fmt(f),
+            Error::StackedAreaValuesAreInvalid => "stacked area values must be finite and non-negative".to_string().fmt(f),
+            Error::AreaBaselineIsInvalid => "area baseline series must contain a value for each data element".to_string().fmt(f),
             Error::SaveFileError(err) => format!("failed to save file, error: {}", err).fmt(f),
+            Error::WriteError(err) => format!("failed to write, error: {}", err).fmt(f),
+            #[cfg(feature = "pdf")]
+            Error::RenderPdfError(err) => format!("failed to render pdf, error: {}", err).fmt(f),
+            #[cfg(feature = "png")]
+            Error::PngScaleIsInvalid => "png scale factor must be positive and produce an image of a valid size".to_string().fmt(f),
+            #[cfg(feature = "png")]
+            Error::RenderPngError(err) => format!("failed to render png, error: {}", err).fmt(f),
+            #[cfg(any(feature = "pdf", feature = "png"))]
+            Error::FontsAreMissing => "no system fonts were found to render chart text".to_string().fmt(f),
         }
     }
 }
@@ -42,6 +110,7 @@ mod tests {
     #[test]
     fn impl_error() {
         #[derive(Debug)]
+        #[allow(dead_code)]
         struct B(Option<Box<dyn std::error::Error + 'static>>);
 
         impl std::fmt::Display for B {
@@ -54,6 +123,6 @@ mod tests {
 
         let err = B(Some(Box::new(Error::DataIsEmpty)));
 
-        let _err = &err as &(dyn std::error::Error);
+        let _err = &err as &dyn std::error::Error;
     }
 }
@@ -1,24 +1,48 @@
-pub use crate::chart::Chart;
-pub use crate::color::Color;
+pub use crate::chart::{Chart, SyncChart};
+pub use crate::color::{Color, Palette};
 pub use crate::error::Error;
+#[cfg(feature = "pdf")]
+pub use crate::render::pdf::PdfDocument;
 pub use crate::scale::band::BandScale;
+pub use crate::scale::color::ColorScale;
+pub use crate::scale::format::TickFormat;
 pub use crate::scale::linear::LinearScale;
-pub use crate::scale::{Scale, ScaleKind};
+pub use crate::scale::log::LogScale;
+pub use crate::scale::time::TimeScale;
+pub use crate::scale::{ContinuousScale, Scale, ScaleKind};
 pub use crate::shape::bar::BarLabelPosition;
+pub use crate::shape::candle::CandlestickStyle;
+pub use crate::shape::curve::Curve;
+pub use crate::shape::grid::GridStyle;
+pub use crate::shape::legend::{LegendEntry, LegendMarker, LegendPosition};
 pub use crate::shape::point::{PointLabelPosition, PointType};
+pub use crate::shape::sector::{PieLabelFormat, PieLabelPosition};
+pub use crate::theme::Theme;
+pub use crate::value::area::AreaValues;
 pub use crate::value::bar::BarsValues;
-pub use crate::view::area::AreaView;
+pub use crate::value::box_plot::BoxPlotSummary;
+pub use crate::value::ohlc::Ohlc;
+pub use crate::value::optional::OptionalValue;
+pub use crate::value::pie::PieSlice;
+pub use crate::view::area::{AreaBaseline, AreaView};
+pub use crate::view::box_plot::{BoxPlotView, BoxPlotWhiskers};
+pub use crate::view::candlestick::CandlestickView;
+pub use crate::view::heatmap::HeatmapView;
+pub use crate::view::histogram::{HistogramBins, HistogramView};
 pub use crate::view::horizontal_bar::HorizontalBarView;
 pub use crate::view::line::LineView;
+pub use crate::view::pie::{PieSortOrder, PieView};
 pub use crate::view::scatter::ScatterView;
+pub use crate::view::stacked_area::{StackedAreaOffset, StackedAreaView};
 pub use crate::view::vertical_bar::VerticalBarView;
-pub use crate::view::Orientation;
 pub use crate::view::View;
+pub use crate::view::{BarsLayout, MissingValues, Orientation};
 
 pub mod chart;
 pub mod color;
 pub mod error;
 pub mod scale;
+pub mod theme;
 pub mod value;
 pub mod view;
 
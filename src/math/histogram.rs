@@ -0,0 +1,62 @@
+use crate::math::statistics::quantile;
+
+/// Calculate bins count for the provided samples count with the Sturges' rule.
+pub fn sturges_bins_count(samples_count: usize) -> usize {
+    if samples_count == 0 {
+        return 1;
+    }
+
+    (samples_count as f32).log2().ceil() as usize + 1
+}
+
+/// Calculate bin width for the provided sorted samples with the Freedman–Diaconis rule.
+pub fn freedman_diaconis_bin_width(sorted_samples: &[f32]) -> f32 {
+    if sorted_samples.is_empty() {
+        return 0_f32;
+    }
+
+    let iqr = quantile(sorted_samples, 0.75_f32) - quantile(sorted_samples, 0.25_f32);
+
+    2_f32 * iqr / (sorted_samples.len() as f32).cbrt()
+}
+
+/// Count samples in bins that are set by their sorted edges.
+/// Bins include their left edges and the last bin includes its right edge as well.
+pub fn bin_counts(samples: &[f32], edges: &[f32]) -> Vec<usize> {
+    let bins_count = edges.len().saturating_sub(1);
+    let mut counts = vec![0; bins_count];
+    if bins_count == 0 {
+        return counts;
+    }
+
+    let last_edge = edges[bins_count];
+    for sample in samples.iter() {
+        if *sample < edges[0] || *sample > last_edge {
+            continue;
+        }
+        let bin_idx = edges.partition_point(|edge| edge <= sample);
+        counts[bin_idx.min(bins_count) - 1] += 1;
+    }
+
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bins_rules_basic() {
+        assert_eq!(sturges_bins_count(100), 8);
+
+        let samples = vec![1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32];
+        assert!((freedman_diaconis_bin_width(&samples) - 3.5_f32).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn bin_counts_basic() {
+        let samples = vec![0_f32, 0.5_f32, 1_f32, 1.5_f32, 2_f32, 3_f32];
+        let edges = vec![0_f32, 1_f32, 2_f32];
+        assert_eq!(bin_counts(&samples, &edges), vec![2, 3]);
+    }
+}
@@ -1 +1,3 @@
+pub(crate) mod histogram;
 pub(crate) mod linear;
+pub(crate) mod statistics;
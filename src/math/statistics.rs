@@ -0,0 +1,25 @@
+/// Calculate a quantile q in [0; 1] of the provided sorted samples with linear interpolation.
+pub fn quantile(sorted_samples: &[f32], q: f32) -> f32 {
+    if sorted_samples.is_empty() {
+        return 0_f32;
+    }
+
+    let position = q * (sorted_samples.len() - 1) as f32;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+
+    sorted_samples[lower]
+        + (sorted_samples[upper] - sorted_samples[lower]) * (position - lower as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantile_basic() {
+        let samples = vec![1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32];
+        assert!((quantile(&samples, 0.25_f32) - 2.75_f32).abs() < f32::EPSILON);
+        assert!((quantile(&samples, 0.5_f32) - 4.5_f32).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::Error;
+use resvg::usvg::fontdb;
+use std::sync::{Arc, OnceLock};
+
+static FONT_DATABASE: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
+
+/// Get database of system fonts that is used to render text.
+/// System fonts are loaded only once since it requires scanning of font directories.
+pub fn font_database() -> Arc<fontdb::Database> {
+    FONT_DATABASE
+        .get_or_init(|| {
+            let mut db = fontdb::Database::new();
+            db.load_system_fonts();
+
+            // Use any available sans font for generic sans-serif family in case the default one is missing.
+            let sans_serif_query = fontdb::Query {
+                families: &[fontdb::Family::SansSerif],
+                ..fontdb::Query::default()
+            };
+            if db.query(&sans_serif_query).is_none() {
+                let families: Vec<String> = db
+                    .faces()
+                    .filter_map(|face| face.families.first().map(|family| family.0.clone()))
+                    .collect();
+                let fallback_family = families
+                    .iter()
+                    .find(|family| family.contains("Sans") && !family.contains("Serif"))
+                    .or_else(|| families.first());
+                if let Some(family) = fallback_family {
+                    db.set_sans_serif_family(family.as_str());
+                }
+            }
+
+            Arc::new(db)
+        })
+        .clone()
+}
+
+/// Get database of system fonts that can render the provided SVG document.
+/// Text of a document is silently dropped without fonts so documents with text require at least one font.
+pub fn document_font_database(svg: &str) -> Result<Arc<fontdb::Database>, Error> {
+    let db = font_database();
+    check_document_fonts(&db, svg)?;
+
+    Ok(db)
+}
+
+// Check that the font database can render text of the provided SVG document.
+fn check_document_fonts(db: &fontdb::Database, svg: &str) -> Result<(), Error> {
+    if db.is_empty() && svg.contains("<text") {
+        return Err(Error::FontsAreMissing);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_document_fonts_empty_database() {
+        let db = fontdb::Database::new();
+        assert!(check_document_fonts(&db, r#"<svg><rect width="1" height="1"/></svg>"#).is_ok());
+        assert!(matches!(
+            check_document_fonts(&db, "<svg><text>label</text></svg>"),
+            Err(Error::FontsAreMissing)
+        ));
+    }
+}
@@ -1 +1,7 @@
+#[cfg(any(feature = "pdf", feature = "png"))]
+pub(crate) mod fonts;
+#[cfg(feature = "pdf")]
+pub(crate) mod pdf;
+#[cfg(feature = "png")]
+pub(crate) mod png;
 pub(crate) mod svg;
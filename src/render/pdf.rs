@@ -0,0 +1,288 @@
+use crate::render::fonts::document_font_database;
+use crate::{Chart, Error, View};
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str};
+use resvg::tiny_skia::{PathSegment, Point, Transform};
+use resvg::usvg;
+use std::path::Path;
+
+// Chart sizes are measured in CSS pixels with 96 DPI while PDF uses points with 72 DPI.
+const PX_TO_PT: f32 = 0.75;
+
+const FONT_NAME: Name = Name(b"F1");
+const FONT_BASE_NAME: Name = Name(b"Helvetica");
+const FONT_ENCODING: Name = Name(b"WinAnsiEncoding");
+
+const CATALOG_ID: Ref = Ref::new(1);
+const PAGE_TREE_ID: Ref = Ref::new(2);
+const FONT_ID: Ref = Ref::new(3);
+
+/// PdfDocument contains charts that are placed on separate pages of a single vector PDF document.
+/// Each page has the size of its chart and text is kept selectable.
+pub struct PdfDocument {
+    pages: Vec<usvg::Tree>,
+}
+
+impl PdfDocument {
+    /// Create a new PdfDocument.
+    pub fn new() -> Self {
+        Self { pages: Vec::new() }
+    }
+
+    /// Add a chart to a new page of the document.
+    pub fn add_chart<V: ?Sized + View>(mut self, chart: &Chart<V>) -> Result<Self, Error> {
+        let svg = chart.to_svg_string();
+        let options = usvg::Options {
+            fontdb: document_font_database(&svg)?,
+            ..usvg::Options::default()
+        };
+        let tree = usvg::Tree::from_str(&svg, &options)
+            .map_err(|err| Error::RenderPdfError(err.to_string()))?;
+        self.pages.push(tree);
+
+        Ok(self)
+    }
+
+    /// Get document PDF representation.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        if self.pages.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+
+        let mut pdf = Pdf::new();
+        pdf.catalog(CATALOG_ID).pages(PAGE_TREE_ID);
+
+        // Each page takes two references: the page itself and its content stream.
+        let page_ids: Vec<Ref> = (0..self.pages.len())
+            .map(|page_idx| Ref::new(FONT_ID.get() + 1 + 2 * page_idx as i32))
+            .collect();
+        pdf.pages(PAGE_TREE_ID)
+            .kids(page_ids.iter().copied())
+            .count(page_ids.len() as i32);
+
+        for (tree, page_id) in self.pages.iter().zip(page_ids.iter()) {
+            let content_id = Ref::new(page_id.get() + 1);
+            let width = tree.size().width() * PX_TO_PT;
+            let height = tree.size().height() * PX_TO_PT;
+
+            let mut page = pdf.page(*page_id);
+            page.media_box(Rect::new(0_f32, 0_f32, width, height));
+            page.parent(PAGE_TREE_ID);
+            page.contents(content_id);
+            page.resources().fonts().pair(FONT_NAME, FONT_ID);
+            page.finish();
+
+            // Flip y axis since SVG origin is at the top left corner and PDF origin is at the bottom left.
+            let mut content = Content::new();
+            content.transform([PX_TO_PT, 0_f32, 0_f32, -PX_TO_PT, 0_f32, height]);
+            write_group(&mut content, tree.root());
+            pdf.stream(content_id, &content.finish());
+        }
+
+        pdf.type1_font(FONT_ID)
+            .base_font(FONT_BASE_NAME)
+            .encoding_predefined(FONT_ENCODING);
+
+        Ok(pdf.finish())
+    }
+
+    /// Save document to PDF file at the specified path.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        std::fs::write(path, self.to_bytes()?)?;
+
+        Ok(())
+    }
+}
+
+impl Default for PdfDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_group(content: &mut Content, group: &usvg::Group) {
+    for node in group.children() {
+        match node {
+            usvg::Node::Group(group) => write_group(content, group),
+            usvg::Node::Path(path) => write_path(content, path),
+            usvg::Node::Text(text) => write_text(content, text),
+            usvg::Node::Image(_) => {}
+        }
+    }
+}
+
+fn rgb(paint: &usvg::Paint) -> Option<(f32, f32, f32)> {
+    match paint {
+        usvg::Paint::Color(color) => Some((
+            color.red as f32 / 255_f32,
+            color.green as f32 / 255_f32,
+            color.blue as f32 / 255_f32,
+        )),
+        _ => None,
+    }
+}
+
+fn matrix(ts: Transform) -> [f32; 6] {
+    [ts.sx, ts.ky, ts.kx, ts.sy, ts.tx, ts.ty]
+}
+
+fn write_path(content: &mut Content, path: &usvg::Path) {
+    if !path.is_visible() {
+        return;
+    }
+
+    let fill = path.fill().and_then(|fill| rgb(fill.paint()));
+    let stroke = path.stroke().and_then(|stroke| rgb(stroke.paint()));
+    if fill.is_none() && stroke.is_none() {
+        return;
+    }
+
+    content.save_state();
+    content.transform(matrix(path.abs_transform()));
+
+    // Colors have to be set before the path construction.
+    if let Some((r, g, b)) = fill {
+        content.set_fill_rgb(r, g, b);
+    }
+    if let (Some((r, g, b)), Some(path_stroke)) = (stroke, path.stroke()) {
+        content.set_stroke_rgb(r, g, b);
+        content.set_line_width(path_stroke.width().get());
+        if let Some(dasharray) = path_stroke.dasharray() {
+            content.set_dash_pattern(dasharray.iter().copied(), path_stroke.dashoffset());
+        }
+    }
+    let mut last = Point::zero();
+    for segment in path.data().segments() {
+        match segment {
+            PathSegment::MoveTo(p) => {
+                content.move_to(p.x, p.y);
+                last = p;
+            }
+            PathSegment::LineTo(p) => {
+                content.line_to(p.x, p.y);
+                last = p;
+            }
+            PathSegment::QuadTo(p1, p) => {
+                // PDF supports only cubic curves so quadratic ones are converted.
+                let c1 = Point::from_xy(
+                    last.x + 2_f32 / 3_f32 * (p1.x - last.x),
+                    last.y + 2_f32 / 3_f32 * (p1.y - last.y),
+                );
+                let c2 = Point::from_xy(
+                    p.x + 2_f32 / 3_f32 * (p1.x - p.x),
+                    p.y + 2_f32 / 3_f32 * (p1.y - p.y),
+                );
+                content.cubic_to(c1.x, c1.y, c2.x, c2.y, p.x, p.y);
+                last = p;
+            }
+            PathSegment::CubicTo(p1, p2, p) => {
+                content.cubic_to(p1.x, p1.y, p2.x, p2.y, p.x, p.y);
+                last = p;
+            }
+            PathSegment::Close => {
+                content.close_path();
+            }
+        }
+    }
+
+    match (fill.is_some(), stroke.is_some()) {
+        (true, true) => content.fill_nonzero_and_stroke(),
+        (true, false) => content.fill_nonzero(),
+        _ => content.stroke(),
+    };
+
+    content.restore_state();
+}
+
+fn write_text(content: &mut Content, text: &usvg::Text) {
+    let abs_transform = text.abs_transform();
+    let abs_scale =
+        (abs_transform.sx * abs_transform.sx + abs_transform.ky * abs_transform.ky).sqrt();
+
+    // Text is written with a standard font at positions of laid out glyphs so it stays selectable.
+    for span in text.layouted() {
+        let first_glyph = match span.positioned_glyphs.first() {
+            Some(glyph) if span.visible => glyph,
+            _ => continue,
+        };
+
+        let glyph_transform = abs_transform.pre_concat(first_glyph.transform());
+        let direction_length = (glyph_transform.sx * glyph_transform.sx
+            + glyph_transform.ky * glyph_transform.ky)
+            .sqrt();
+        if direction_length == 0_f32 {
+            continue;
+        }
+        let cos = glyph_transform.sx / direction_length;
+        let sin = glyph_transform.ky / direction_length;
+        let font_size = span.font_size.get() * abs_scale;
+
+        let (r, g, b) = span
+            .fill
+            .as_ref()
+            .and_then(|fill| rgb(fill.paint()))
+            .unwrap_or((0_f32, 0_f32, 0_f32));
+        let encoded: Vec<u8> = span
+            .positioned_glyphs
+            .iter()
+            .flat_map(|glyph| glyph.text.chars())
+            .map(win_ansi_byte)
+            .collect();
+
+        content.save_state();
+        content.set_fill_rgb(r, g, b);
+        content.begin_text();
+        content.set_font(FONT_NAME, 1_f32);
+
+        // Text space is mirrored back since the page content has flipped y axis.
+        content.set_text_matrix([
+            cos * font_size,
+            sin * font_size,
+            sin * font_size,
+            -cos * font_size,
+            glyph_transform.tx,
+            glyph_transform.ty,
+        ]);
+        content.show(Str(&encoded));
+        content.end_text();
+        content.restore_state();
+    }
+}
+
+// Encode a character for the standard font, characters that can't be encoded are replaced.
+fn win_ansi_byte(c: char) -> u8 {
+    match c as u32 {
+        code @ 0x20..=0x7e | code @ 0xa0..=0xff => code as u8,
+        _ => b'?',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pdf_document_basic() {
+        let chart = Chart::new()
+            .set_width(200)
+            .set_height(100)
+            .set_title("Selectable title");
+        let pdf = PdfDocument::new()
+            .add_chart(&chart)
+            .expect("unable to add chart")
+            .add_chart(&chart)
+            .expect("unable to add chart")
+            .to_bytes()
+            .expect("unable to render pdf");
+        let pdf = String::from_utf8_lossy(&pdf);
+
+        assert!(pdf.starts_with("%PDF-"));
+        assert!(pdf.contains("/Count 2"));
+        assert!(pdf.contains("/MediaBox [0 0 150 75]"));
+        assert!(pdf.contains("(Selectable title) Tj"));
+
+        assert!(matches!(
+            PdfDocument::new().to_bytes(),
+            Err(Error::DataIsEmpty)
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+use crate::render::fonts::document_font_database;
+use crate::Error;
+use resvg::{tiny_skia, usvg};
+
+// SVG user units are CSS pixels that are defined for 96 DPI.
+const SVG_DPI: f32 = 96_f32;
+
+// Maximum count of pixels in a PNG image, larger images would need too much memory to render.
+const MAX_PNG_PIXELS: u64 = 100_000_000;
+
+/// Rasterize the provided SVG document to PNG bytes.
+/// Document size is multiplied by the scale factor, images with more than 100 million pixels
+/// are not rendered.
+pub fn svg_to_png(svg: &str, scale: f32) -> Result<Vec<u8>, Error> {
+    if !scale.is_finite() || scale <= 0_f32 {
+        return Err(Error::PngScaleIsInvalid);
+    }
+
+    let options = usvg::Options {
+        fontdb: document_font_database(svg)?,
+        ..usvg::Options::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &options)
+        .map_err(|err| Error::RenderPngError(err.to_string()))?;
+
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(scale)
+        .ok_or(Error::PngScaleIsInvalid)?;
+    if size.width() as u64 * size.height() as u64 > MAX_PNG_PIXELS {
+        return Err(Error::PngScaleIsInvalid);
+    }
+    let mut pixmap =
+        tiny_skia::Pixmap::new(size.width(), size.height()).ok_or(Error::PngScaleIsInvalid)?;
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap
+        .encode_png()
+        .map_err(|err| Error::RenderPngError(err.to_string()))
+}
+
+/// Get scale factor that renders SVG document for the provided DPI.
+pub fn dpi_scale(dpi: f32) -> f32 {
+    dpi / SVG_DPI
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svg_to_png_basic() {
+        let svg = r##"<svg height="20" viewBox="0 0 10 20" width="10" xmlns="http://www.w3.org/2000/svg">
+<rect fill="#5095e5" height="20" width="10" x="0" y="0"/>
+</svg>"##;
+
+        let png = svg_to_png(svg, 2_f32).expect("unable to render png");
+        assert_eq!(&png[1..4], b"PNG");
+
+        // PNG header contains image width and height as big-endian integers.
+        assert_eq!(&png[16..24], &[0, 0, 0, 20, 0, 0, 0, 40]);
+
+        assert!(matches!(
+            svg_to_png(svg, 0_f32),
+            Err(Error::PngScaleIsInvalid)
+        ));
+        assert!(matches!(
+            svg_to_png(svg, 10_000_f32),
+            Err(Error::PngScaleIsInvalid)
+        ));
+
+        let png = svg_to_png(svg, dpi_scale(192_f32)).expect("unable to render png");
+        assert_eq!(&png[16..24], &[0, 0, 0, 20, 0, 0, 0, 40]);
+    }
+}
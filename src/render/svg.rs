@@ -25,8 +25,10 @@ pub const HEIGHT_ATTR: &str = "height";
 
 pub const STROKE_ATTR: &str = "stroke";
 pub const STROKE_WIDTH_ATTR: &str = "stroke-width";
+pub const STROKE_DASHARRAY_ATTR: &str = "stroke-dasharray";
 
 pub const FILL_ATTR: &str = "fill";
+pub const FILL_OPACITY_ATTR: &str = "fill-opacity";
 pub const TRANSFORM_ATTR: &str = "transform";
 
 pub const TEXT_ANCHOR_ATTR: &str = "text-anchor";
@@ -42,8 +44,15 @@ pub const SHAPE_RENDERING_CRISP_EDGES: &str = "crispEdges";
 
 pub const CLASS_ATTR: &str = "class";
 pub const CLASS_AREA: &str = "area";
+pub const CLASS_BACKGROUND: &str = "background";
 pub const CLASS_BAR: &str = "bar";
+pub const CLASS_BOX_PLOT: &str = "box-plot";
+pub const CLASS_BOX_PLOTS: &str = "box-plots";
+pub const CLASS_CANDLE: &str = "candle";
+pub const CLASS_CANDLESTICK: &str = "candlestick";
+pub const CLASS_CELL: &str = "cell";
 pub const CLASS_CHART: &str = "chart";
+pub const CLASS_COLOR_BAR: &str = "color-bar";
 pub const CLASS_VIEWS: &str = "views";
 pub const CLASS_X_AXIS: &str = "x-axis";
 pub const CLASS_Y_AXIS: &str = "y-axis";
@@ -51,6 +60,14 @@ pub const CLASS_TICK: &str = "tick";
 pub const CLASS_TITLE: &str = "title";
 pub const CLASS_POINT: &str = "point";
 pub const CLASS_LINE: &str = "line";
+pub const CLASS_LEGEND: &str = "legend";
+pub const CLASS_LEGEND_ENTRY: &str = "legend-entry";
+pub const CLASS_GRID: &str = "grid";
+pub const CLASS_HEATMAP: &str = "heatmap";
+pub const CLASS_PIE: &str = "pie";
+pub const CLASS_SECTOR: &str = "sector";
+pub const CLASS_STACKED_AREA: &str = "stacked-area";
+pub const CLASS_VOLUME: &str = "volume";
 
 pub const VIEW_BOX_ATTR: &str = "viewBox";
 
@@ -70,6 +87,10 @@ pub fn rotate_a<T: std::fmt::Display>(a: T) -> String {
     format!("rotate({})", a)
 }
 
+pub fn px<T: std::fmt::Display>(size: T) -> String {
+    format!("{}px", size)
+}
+
 pub fn pair_x_y<T: std::fmt::Display>(x: T, y: T) -> String {
     format!("({},{})", x, y)
 }
@@ -2,12 +2,16 @@ use crate::math::linear::range;
 use crate::{Scale, ScaleKind};
 use itertools::Itertools;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 const DEFAULT_PADDING: f32 = 0.1_f32;
 const DEFAULT_ALIGN: f32 = 0.5_f32;
 const DEFAULT_STEP: f32 = 1_f32;
 const DEFAULT_BANDWIDTH: f32 = 1_f32;
 
+// Formatter of category tick labels.
+type TickFormatter = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
 /// BandScale represents axis scale with categories.
 #[derive(Clone)]
 pub struct BandScale {
@@ -44,6 +48,9 @@ pub struct BandScale {
     /// Does this scale needs an offset from the start and end of an axis.
     /// This is usually need for an area or line views.
     no_boundaries_offset: bool,
+
+    /// Formatter of tick labels, categories are used as labels without it.
+    tick_format: Option<TickFormatter>,
 }
 
 impl BandScale {
@@ -61,6 +68,7 @@ impl BandScale {
             index: HashMap::new(),
             offsets: Vec::new(),
             no_boundaries_offset: false,
+            tick_format: None,
         };
         band.rescale();
         band
@@ -76,6 +84,15 @@ impl BandScale {
         self.range_end
     }
 
+    /// Set formatter of tick labels that gets a category and returns its label.
+    pub fn set_tick_format<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.tick_format = Some(Arc::new(formatter));
+        self
+    }
+
     /// Set scale inner padding.
     pub fn set_inner_padding(mut self, padding: f32) -> Self {
         self.padding_inner = padding;
@@ -153,16 +170,42 @@ impl BandScale {
 
 impl Scale<String> for BandScale {
     fn scale(&self, domain: &String) -> f32 {
-        return match self.index.get(domain) {
+        match self.index.get(domain) {
             Some(offset_idx) => self.offsets[*offset_idx],
             None => 0_f32,
-        };
+        }
+    }
+
+    fn accepts(&self, domain: &String) -> bool {
+        self.index.contains_key(domain)
     }
 
     fn ticks(&self) -> Vec<String> {
         self.domain.clone()
     }
 
+    fn tick_label(&self, tick: &String) -> String {
+        match &self.tick_format {
+            Some(formatter) => formatter(tick),
+            None => tick.clone(),
+        }
+    }
+
+    fn range_start(&self) -> i32 {
+        self.range_start
+    }
+
+    fn range_end(&self) -> i32 {
+        self.range_end
+    }
+
+    fn set_range(mut self, range_start: i32, range_end: i32) -> Self {
+        self.range_start = range_start;
+        self.range_end = range_end;
+        self.rescale();
+        self
+    }
+
     fn kind(&self) -> ScaleKind {
         ScaleKind::Band
     }
@@ -256,5 +299,8 @@ mod tests {
         assert!((band_scale.bandwidth() - 33.333332_f32).abs() < f32::EPSILON);
         assert!(!band_scale.is_range_reversed());
         assert!((band_scale.tick_offset() - 0_f32).abs() < f32::EPSILON);
+
+        let band_scale = band_scale.set_tick_format(|category| category.to_uppercase());
+        assert_eq!(band_scale.tick_label(&"a2".to_string()), "A2");
     }
 }
@@ -0,0 +1,134 @@
+use crate::color::{COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_5};
+use crate::math::linear::{interpolate, normalize};
+use crate::{Color, Error, TickFormat};
+
+/// ColorScale represents a continuous scale that maps numerical values to colors.
+/// Colors are interpolated between evenly spaced color stops.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorScale {
+    /// Start of the scale domain.
+    domain_start: f32,
+
+    /// End of the scale domain.
+    domain_end: f32,
+
+    /// Color stops as (r, g, b) values.
+    stops: Vec<(u8, u8, u8)>,
+
+    /// Format of color bar labels and of value labels in views.
+    tick_format: TickFormat,
+}
+
+impl ColorScale {
+    /// Create a new ColorScale with the provided color stops.
+    /// At least two stops are required and they must be hex strings or rgb() functions.
+    pub fn new(domain_start: f32, domain_end: f32, colors: &[Color]) -> Result<Self, Error> {
+        let stops = colors
+            .iter()
+            .map(|color| color.rgb())
+            .collect::<Option<Vec<(u8, u8, u8)>>>()
+            .ok_or(Error::ColorScaleIsInvalid)?;
+        if stops.len() < 2 || !domain_start.is_finite() || !domain_end.is_finite() {
+            return Err(Error::ColorScaleIsInvalid);
+        }
+
+        Ok(Self {
+            domain_start,
+            domain_end,
+            stops,
+            tick_format: TickFormat::Default,
+        })
+    }
+
+    /// Create a new sequential ColorScale from light to dark blue.
+    pub fn new_sequential(domain_start: f32, domain_end: f32) -> Self {
+        Self {
+            domain_start,
+            domain_end,
+            stops: [COLOR_HEX_BLUE_5, COLOR_HEX_BLUE_1]
+                .iter()
+                .filter_map(|hex| Color::new_from_hex(hex).rgb())
+                .collect(),
+            tick_format: TickFormat::Default,
+        }
+    }
+
+    /// Set format of color bar labels, views use it for value labels too.
+    pub fn set_tick_format(mut self, tick_format: TickFormat) -> Self {
+        self.tick_format = tick_format;
+        self
+    }
+
+    /// Get the label for the provided value.
+    pub fn tick_label(&self, value: f32) -> String {
+        self.tick_format.format(value)
+    }
+
+    /// Get start of the scale domain.
+    pub fn domain_start(&self) -> f32 {
+        self.domain_start
+    }
+
+    /// Get end of the scale domain.
+    pub fn domain_end(&self) -> f32 {
+        self.domain_end
+    }
+
+    /// Get (r, g, b) values for the provided value, values outside of the domain are clamped.
+    pub fn rgb(&self, value: f32) -> (u8, u8, u8) {
+        let position = normalize(self.domain_start, self.domain_end, value).clamp(0_f32, 1_f32);
+        let segments = (self.stops.len() - 1) as f32;
+        let segment = ((position * segments).floor() as usize).min(self.stops.len() - 2);
+        let t = position * segments - segment as f32;
+
+        let (start, end) = (self.stops[segment], self.stops[segment + 1]);
+        let channel = |a: u8, b: u8| interpolate(a as f32, b as f32, t).round() as u8;
+
+        (
+            channel(start.0, end.0),
+            channel(start.1, end.1),
+            channel(start.2, end.2),
+        )
+    }
+
+    /// Get the color for the provided value, values outside of the domain are clamped.
+    pub fn color(&self, value: f32) -> Color {
+        let (r, g, b) = self.rgb(value);
+        Color::new_from_rgb(r, g, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_scale_basic() {
+        let scale = ColorScale::new(
+            0_f32,
+            100_f32,
+            &[
+                Color::new_from_hex("#000000"),
+                Color::new_from_hex("#ff0000"),
+                Color::new_from_hex("#ffffff"),
+            ],
+        )
+        .expect("unable to create color scale");
+        assert_eq!(scale.rgb(-10_f32), (0, 0, 0));
+        assert_eq!(scale.rgb(25_f32), (128, 0, 0));
+        assert_eq!(scale.rgb(75_f32), (255, 128, 128));
+        assert_eq!(scale.color(100_f32).to_string(), "rgb(255,255,255)");
+        assert_eq!(
+            scale
+                .clone()
+                .set_tick_format(TickFormat::Fixed(1))
+                .tick_label(0.1_f32 + 0.2_f32),
+            "0.3"
+        );
+
+        assert!(matches!(
+            ColorScale::new(0_f32, 1_f32, &[Color::new_from_hex("#000000")]),
+            Err(Error::ColorScaleIsInvalid)
+        ));
+    }
+}
@@ -0,0 +1,179 @@
+use std::sync::Arc;
+
+const THOUSANDS_SEPARATOR: char = ',';
+
+// SI prefixes with their exponents, ordered from the largest one.
+const SI_PREFIXES: [(i32, &str); 4] = [(12, "T"), (9, "G"), (6, "M"), (3, "k")];
+
+/// TickFormat represents how numeric scale values are turned into tick and value labels.
+/// Custom formats are equal only if they share the same formatter.
+#[derive(Clone, Default)]
+pub enum TickFormat {
+    /// Plain value representation, `1000000` or `0.5`.
+    #[default]
+    Default,
+
+    /// Fixed number of decimals, `Fixed(2)` formats `0.30000001` as `0.30`.
+    Fixed(usize),
+
+    /// Fixed number of decimals with thousands separators, `Thousands(0)` formats `1234567` as `1,234,567`.
+    Thousands(usize),
+
+    /// SI prefixes with at most the provided number of decimals, `Si(1)` formats `1260000` as `1.3M`.
+    Si(usize),
+
+    /// Percentage of a fraction with a fixed number of decimals, `Percent(0)` formats `0.25` as `25%`.
+    Percent(usize),
+
+    /// Currency symbol and thousands separators with two decimals, `Currency("$")` formats `-1234.5` as `-$1,234.50`.
+    Currency(String),
+
+    /// Scientific notation with a fixed number of decimals, `Scientific(2)` formats `12345` as `1.23e4`.
+    Scientific(usize),
+
+    /// User provided formatter.
+    Custom(Arc<dyn Fn(f32) -> String + Send + Sync>),
+}
+
+impl TickFormat {
+    /// Create a TickFormat from the provided formatter function.
+    pub fn custom<F>(formatter: F) -> Self
+    where
+        F: Fn(f32) -> String + Send + Sync + 'static,
+    {
+        TickFormat::Custom(Arc::new(formatter))
+    }
+
+    /// Format the provided value.
+    pub fn format(&self, value: f32) -> String {
+        match self {
+            TickFormat::Default => value.to_string(),
+            TickFormat::Fixed(decimals) => format!("{:.*}", decimals, value),
+            TickFormat::Thousands(decimals) => group_thousands(&format!("{:.*}", decimals, value)),
+            TickFormat::Si(decimals) => si(value, *decimals),
+            TickFormat::Percent(decimals) => format!("{:.*}%", decimals, value * 100_f32),
+            TickFormat::Currency(symbol) => {
+                let amount = group_thousands(&format!("{:.2}", value.abs()));
+                if value < 0_f32 {
+                    format!("-{}{}", symbol, amount)
+                } else {
+                    format!("{}{}", symbol, amount)
+                }
+            }
+            TickFormat::Scientific(decimals) => format!("{:.*e}", decimals, value),
+            TickFormat::Custom(formatter) => formatter(value),
+        }
+    }
+}
+
+impl std::fmt::Debug for TickFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TickFormat::Default => write!(f, "Default"),
+            TickFormat::Fixed(decimals) => write!(f, "Fixed({})", decimals),
+            TickFormat::Thousands(decimals) => write!(f, "Thousands({})", decimals),
+            TickFormat::Si(decimals) => write!(f, "Si({})", decimals),
+            TickFormat::Percent(decimals) => write!(f, "Percent({})", decimals),
+            TickFormat::Currency(symbol) => write!(f, "Currency({:?})", symbol),
+            TickFormat::Scientific(decimals) => write!(f, "Scientific({})", decimals),
+            TickFormat::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl PartialEq for TickFormat {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TickFormat::Default, TickFormat::Default) => true,
+            (TickFormat::Fixed(a), TickFormat::Fixed(b))
+            | (TickFormat::Thousands(a), TickFormat::Thousands(b))
+            | (TickFormat::Si(a), TickFormat::Si(b))
+            | (TickFormat::Percent(a), TickFormat::Percent(b))
+            | (TickFormat::Scientific(a), TickFormat::Scientific(b)) => a == b,
+            (TickFormat::Currency(a), TickFormat::Currency(b)) => a == b,
+            (TickFormat::Custom(a), TickFormat::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+// Insert thousands separators into the integer part of a formatted number.
+fn group_thousands(formatted: &str) -> String {
+    let (sign, unsigned) = match formatted.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", formatted),
+    };
+    let (integer, fraction) = match unsigned.find('.') {
+        Some(idx) => unsigned.split_at(idx),
+        None => (unsigned, ""),
+    };
+
+    let mut grouped = String::new();
+    for (idx, digit) in integer.chars().enumerate() {
+        if idx > 0 && (integer.len() - idx) % 3 == 0 {
+            grouped.push(THOUSANDS_SEPARATOR);
+        }
+        grouped.push(digit);
+    }
+
+    format!("{}{}{}", sign, grouped, fraction)
+}
+
+// Format value with the largest SI prefix that keeps it at least 1, trailing zeros are removed.
+// Prefix is chosen after rounding so 999950 is formatted as 1M instead of 1000k.
+fn si(value: f32, decimals: usize) -> String {
+    let rounds_to_one = |exponent: i32| {
+        format!("{:.*}", decimals, value.abs() / 10_f32.powi(exponent))
+            .parse::<f32>()
+            .is_ok_and(|rounded| rounded >= 1_f32)
+    };
+    let (exponent, prefix) = SI_PREFIXES
+        .iter()
+        .find(|(exponent, _)| rounds_to_one(*exponent))
+        .cloned()
+        .unwrap_or((0, ""));
+
+    let mut formatted = format!("{:.*}", decimals, value / 10_f32.powi(exponent));
+    if formatted.contains('.') {
+        formatted = formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string();
+    }
+    if formatted == "-0" {
+        formatted = "0".to_string();
+    }
+
+    format!("{}{}", formatted, prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tick_format_builtin() {
+        assert_eq!(TickFormat::Default.format(1000000_f32), "1000000");
+        assert_eq!(TickFormat::Fixed(1).format(0.1_f32 + 0.2_f32), "0.3");
+        assert_eq!(TickFormat::Thousands(0).format(-1234567_f32), "-1,234,567");
+        assert_eq!(TickFormat::Thousands(1).format(1234.5_f32), "1,234.5");
+        assert_eq!(TickFormat::Si(1).format(1260000_f32), "1.3M");
+        assert_eq!(TickFormat::Si(2).format(2000_f32), "2k");
+        assert_eq!(TickFormat::Si(2).format(3e9_f32), "3G");
+        assert_eq!(TickFormat::Si(1).format(12.5_f32), "12.5");
+        assert_eq!(TickFormat::Si(1).format(999950_f32), "1M");
+        assert_eq!(TickFormat::Si(0).format(-999.6_f32), "-1k");
+        assert_eq!(TickFormat::Percent(0).format(0.25_f32), "25%");
+        assert_eq!(
+            TickFormat::Currency("$".to_string()).format(-1234.5_f32),
+            "-$1,234.50"
+        );
+        assert_eq!(TickFormat::Scientific(2).format(12345_f32), "1.23e4");
+    }
+
+    #[test]
+    fn tick_format_custom() {
+        let format = TickFormat::custom(|value| format!("{} pcs", value));
+        assert_eq!(format.format(3_f32), "3 pcs");
+    }
+}
@@ -1,8 +1,11 @@
 use crate::math::linear::{interpolate, normalize, range};
-use crate::{Scale, ScaleKind};
+use crate::{ContinuousScale, Scale, ScaleKind, TickFormat};
 
 const DEFAULT_TICK_COUNT: usize = 11;
 
+// Limit of domain extensions in nice domains.
+const MAX_NICE_ITERATIONS: usize = 10;
+
 /// LinearScale represents axis scale with numerical values.
 #[derive(Clone)]
 pub struct LinearScale {
@@ -20,6 +23,12 @@ pub struct LinearScale {
 
     /// Count of ticks on scale axis.
     tick_count: usize,
+
+    /// Explicit ticks that are used instead of computed ones.
+    tick_values: Option<Vec<f32>>,
+
+    /// Format of tick labels and of value labels in views.
+    tick_format: TickFormat,
 }
 
 impl LinearScale {
@@ -31,6 +40,8 @@ impl LinearScale {
             range_start,
             range_end,
             tick_count: DEFAULT_TICK_COUNT,
+            tick_values: None,
+            tick_format: TickFormat::Default,
         }
     }
 
@@ -44,7 +55,68 @@ impl LinearScale {
         self.range_end
     }
 
+    /// Set approximate count of ticks, the actual count depends on the rounded tick step.
+    pub fn set_tick_count(mut self, tick_count: usize) -> Self {
+        self.tick_count = tick_count;
+        self
+    }
+
+    /// Set explicit ticks that are used instead of computed ones.
+    /// Ticks outside of the scale domain are skipped.
+    pub fn set_tick_values(mut self, tick_values: Vec<f32>) -> Self {
+        self.tick_values = Some(tick_values);
+        self
+    }
+
+    /// Set format of tick labels, views use it for value labels too.
+    pub fn set_tick_format(mut self, tick_format: TickFormat) -> Self {
+        self.tick_format = tick_format;
+        self
+    }
+
+    /// Extend the scale domain to the closest ticks so both domain bounds have a tick.
+    pub fn nice(mut self) -> Self {
+        if self.tick_count == 0 || (self.domain_end - self.domain_start).abs() < f32::EPSILON {
+            return self;
+        }
+
+        // Step can change after the domain is extended so it's recomputed a few times.
+        let (mut min, mut max) = self.domain_min_max();
+        for _ in 0..MAX_NICE_ITERATIONS {
+            let step = self.compute_tick_step(min, max);
+            let (nice_min, nice_max) = if step > 0_f32 {
+                ((min / step).floor() * step, (max / step).ceil() * step)
+            } else {
+                ((min * -step).floor() / -step, (max * -step).ceil() / -step)
+            };
+            if nice_min == min && nice_max == max {
+                break;
+            }
+            min = nice_min;
+            max = nice_max;
+        }
+
+        if self.domain_start > self.domain_end {
+            self.domain_start = max;
+            self.domain_end = min;
+        } else {
+            self.domain_start = min;
+            self.domain_end = max;
+        }
+        self
+    }
+
+    // Get domain bounds in ascending order.
+    fn domain_min_max(&self) -> (f32, f32) {
+        if self.domain_start > self.domain_end {
+            return (self.domain_end, self.domain_start);
+        }
+
+        (self.domain_start, self.domain_end)
+    }
+
     // Compute the step for each tick.
+    // Negative step represents an inverted step below 1 to avoid accumulating float errors.
     fn compute_tick_step(&self, start: f32, end: f32) -> f32 {
         let mut step_denominator = 0_f32;
         if self.tick_count as f32 > step_denominator {
@@ -52,7 +124,7 @@ impl LinearScale {
         }
 
         let step = range(start, end) / step_denominator;
-        let power = (step.ln() / 10_f32.ln()).trunc() as i32;
+        let power = (step.ln() / 10_f32.ln()).floor() as i32;
         let error = step / 10_f32.powi(power);
 
         let mut dynamic = 1;
@@ -75,8 +147,9 @@ impl LinearScale {
     fn ticks_positive_step(&self, step: f32) -> Vec<f32> {
         let mut res = Vec::new();
 
-        let start = (self.domain_start / step).ceil();
-        let end = (self.domain_end / step).floor();
+        let (min, max) = self.domain_min_max();
+        let start = (min / step).ceil();
+        let end = (max / step).floor();
         let ticks_count = (range(start, end) + 1_f32).ceil() as i32;
         for i in 0..ticks_count {
             res.push((start + i as f32) * step);
@@ -89,8 +162,9 @@ impl LinearScale {
     fn ticks_negative_step(&self, step: f32) -> Vec<f32> {
         let mut res = Vec::new();
 
-        let start = (self.domain_start as f32 * step).floor();
-        let end = (self.domain_end as f32 * step).ceil();
+        let (min, max) = self.domain_min_max();
+        let start = (min * step).floor();
+        let end = (max * step).ceil();
         let ticks_count = (range(end, start) + 1_f32).ceil() as i32;
         for i in 0..ticks_count {
             res.push((start - i as f32) / step);
@@ -106,12 +180,28 @@ impl Scale<f32> for LinearScale {
         interpolate(self.range_start as f32, self.range_end as f32, normalized)
     }
 
+    fn accepts(&self, domain: &f32) -> bool {
+        domain.is_finite()
+    }
+
     fn ticks(&self) -> Vec<f32> {
-        if (self.domain_end - self.domain_start).abs() < f32::EPSILON && self.tick_count > 0 {
-            return vec![self.domain_start as f32];
+        if let Some(tick_values) = &self.tick_values {
+            let (min, max) = self.domain_min_max();
+            return tick_values
+                .iter()
+                .filter(|tick| **tick >= min && **tick <= max)
+                .cloned()
+                .collect();
+        }
+        if self.tick_count == 0 {
+            return Vec::new();
+        }
+        if (self.domain_end - self.domain_start).abs() < f32::EPSILON {
+            return vec![self.domain_start];
         }
 
-        let step = self.compute_tick_step(self.domain_start, self.domain_end);
+        let (min, max) = self.domain_min_max();
+        let step = self.compute_tick_step(min, max);
         if step > 0_f32 {
             return self.ticks_positive_step(step);
         }
@@ -119,6 +209,24 @@ impl Scale<f32> for LinearScale {
         self.ticks_negative_step(step)
     }
 
+    fn tick_label(&self, tick: &f32) -> String {
+        self.tick_format.format(*tick)
+    }
+
+    fn range_start(&self) -> i32 {
+        self.range_start
+    }
+
+    fn range_end(&self) -> i32 {
+        self.range_end
+    }
+
+    fn set_range(mut self, range_start: i32, range_end: i32) -> Self {
+        self.range_start = range_start;
+        self.range_end = range_end;
+        self
+    }
+
     fn kind(&self) -> ScaleKind {
         ScaleKind::Linear
     }
@@ -136,6 +244,10 @@ impl Scale<f32> for LinearScale {
     }
 }
 
+impl ContinuousScale for LinearScale {
+    type Domain = f32;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,4 +271,67 @@ mod tests {
         assert!(linear_scale.is_range_reversed());
         assert!((linear_scale.tick_offset() - 0_f32).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn linear_scale_ticks() {
+        // Fractional domains use the inverted step.
+        assert_eq!(
+            LinearScale::new(0.15_f32, 0.95_f32, 0, 100)
+                .set_tick_count(4)
+                .ticks(),
+            vec![0.2_f32, 0.4_f32, 0.6_f32, 0.8_f32]
+        );
+        assert_eq!(
+            LinearScale::new(0_f32, 5.5_f32, 0, 100).ticks(),
+            vec![
+                0_f32, 0.5_f32, 1_f32, 1.5_f32, 2_f32, 2.5_f32, 3_f32, 3.5_f32, 4_f32, 4.5_f32,
+                5_f32, 5.5_f32
+            ]
+        );
+        assert_eq!(
+            LinearScale::new(50_f32, -50_f32, 0, 100)
+                .set_tick_count(2)
+                .ticks(),
+            vec![-50_f32, 0_f32, 50_f32]
+        );
+        assert!(LinearScale::new(0_f32, 1_f32, 0, 100)
+            .set_tick_count(0)
+            .ticks()
+            .is_empty());
+        assert_eq!(
+            LinearScale::new(0_f32, 10_f32, 0, 100)
+                .set_tick_values(vec![0_f32, 2.5_f32, 7_f32, 12_f32])
+                .ticks(),
+            vec![0_f32, 2.5_f32, 7_f32]
+        );
+    }
+
+    #[test]
+    fn linear_scale_nice() {
+        let linear_scale = LinearScale::new(0_f32, 187.3_f32, 100, 0).nice();
+        assert_eq!(linear_scale.ticks().last(), Some(&200_f32));
+        assert!((linear_scale.scale(&200_f32) - 0_f32).abs() < f32::EPSILON);
+
+        let linear_scale = LinearScale::new(0.93_f32, 0.12_f32, 0, 100)
+            .set_tick_count(5)
+            .nice();
+        assert_eq!(
+            linear_scale.ticks(),
+            vec![0_f32, 0.2_f32, 0.4_f32, 0.6_f32, 0.8_f32, 1_f32]
+        );
+        assert!((linear_scale.scale(&1_f32) - 0_f32).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn linear_scale_tick_format() {
+        let linear_scale = LinearScale::new(0_f32, 1_f32, 100, 0)
+            .set_tick_count(5)
+            .set_tick_format(TickFormat::Percent(0));
+        let labels: Vec<String> = linear_scale
+            .ticks()
+            .iter()
+            .map(|tick| linear_scale.tick_label(tick))
+            .collect();
+        assert_eq!(labels, vec!["0%", "20%", "40%", "60%", "80%", "100%"]);
+    }
 }
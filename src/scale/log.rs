@@ -0,0 +1,293 @@
+use crate::math::linear::{interpolate, normalize};
+use crate::{ContinuousScale, Error, LinearScale, Scale, ScaleKind, TickFormat};
+
+const DEFAULT_BASE: f32 = 10_f32;
+const DEFAULT_INTERMEDIATE_TICKS: bool = false;
+
+// Multipliers that are used to create ticks inside each power of the base.
+const INTERMEDIATE_TICK_MULTIPLIERS: [f32; 3] = [1_f32, 2_f32, 5_f32];
+
+// Relative tolerance that is used to keep ticks that are equal to the domain bounds.
+const TICK_TOLERANCE: f32 = 1e-6_f32;
+
+// Maximum count of powers of the base that get ticks, wider domains skip some of the powers.
+const MAX_TICK_POWERS: i64 = 20;
+
+/// LogScale represents axis scale with logarithmic numerical values.
+#[derive(Clone)]
+pub struct LogScale {
+    /// Start of the scale domain.
+    domain_start: f32,
+
+    /// End of the scale domain.
+    domain_end: f32,
+
+    /// Start of the scale range.
+    range_start: i32,
+
+    /// End of the scale range.
+    range_end: i32,
+
+    /// Base of the logarithm.
+    base: f32,
+
+    /// Do we need to add 2x and 5x ticks between powers of the base.
+    intermediate_ticks: bool,
+
+    /// Format of tick labels and of value labels in views.
+    tick_format: TickFormat,
+}
+
+impl LogScale {
+    /// Create a new LogScale.
+    /// Domain values must be positive and finite.
+    pub fn new(
+        domain_start: f32,
+        domain_end: f32,
+        range_start: i32,
+        range_end: i32,
+    ) -> Result<Self, Error> {
+        if !(domain_start.is_finite()
+            && domain_end.is_finite()
+            && domain_start > 0_f32
+            && domain_end > 0_f32)
+        {
+            return Err(Error::LogDomainIsNotPositive);
+        }
+
+        Ok(Self {
+            domain_start,
+            domain_end,
+            range_start,
+            range_end,
+            base: DEFAULT_BASE,
+            intermediate_ticks: DEFAULT_INTERMEDIATE_TICKS,
+            tick_format: TickFormat::Default,
+        })
+    }
+
+    /// Set base of the logarithm.
+    /// Base must be finite and greater than 1.
+    pub fn set_base(mut self, base: f32) -> Result<Self, Error> {
+        if !base.is_finite() || base <= 1_f32 {
+            return Err(Error::LogBaseIsInvalid);
+        }
+
+        self.base = base;
+        Ok(self)
+    }
+
+    /// Configure 2x and 5x ticks between powers of the base.
+    pub fn set_intermediate_ticks(mut self, intermediate_ticks: bool) -> Self {
+        self.intermediate_ticks = intermediate_ticks;
+        self
+    }
+
+    /// Set format of tick labels, views use it for value labels too.
+    pub fn set_tick_format(mut self, tick_format: TickFormat) -> Self {
+        self.tick_format = tick_format;
+        self
+    }
+
+    // Get domain bounds in ascending order.
+    fn domain_min_max(&self) -> (f32, f32) {
+        if self.domain_start > self.domain_end {
+            return (self.domain_end, self.domain_start);
+        }
+
+        (self.domain_start, self.domain_end)
+    }
+
+    // Get multipliers for ticks inside each power of the base.
+    fn tick_multipliers(&self) -> Vec<f32> {
+        if !self.intermediate_ticks {
+            return vec![1_f32];
+        }
+
+        INTERMEDIATE_TICK_MULTIPLIERS
+            .iter()
+            .copied()
+            .filter(|multiplier| *multiplier < self.base)
+            .collect()
+    }
+}
+
+impl Scale<f32> for LogScale {
+    fn scale(&self, domain: &f32) -> f32 {
+        // Non-positive values can't be represented on the log scale so they are clamped to the domain minimum.
+        let value = if *domain > 0_f32 {
+            *domain
+        } else {
+            self.domain_min_max().0
+        };
+
+        let normalized = normalize(self.domain_start.ln(), self.domain_end.ln(), value.ln());
+        interpolate(self.range_start as f32, self.range_end as f32, normalized)
+    }
+
+    fn accepts(&self, domain: &f32) -> bool {
+        domain.is_finite() && *domain > 0_f32
+    }
+
+    fn ticks(&self) -> Vec<f32> {
+        let (min, max) = self.domain_min_max();
+        let start_power = (min.ln() / self.base.ln()).floor() as i64;
+        let end_power = (max.ln() / self.base.ln()).ceil() as i64;
+
+        // Wide domains or bases close to 1 get ticks only at every n-th power of the base.
+        let power_step = ((end_power - start_power).abs() + MAX_TICK_POWERS) / MAX_TICK_POWERS;
+        let start_power = start_power.div_euclid(power_step) * power_step;
+        let multipliers = if power_step > 1 {
+            vec![1_f32]
+        } else {
+            self.tick_multipliers()
+        };
+
+        let mut res = Vec::new();
+        for power in (start_power..=end_power).step_by(power_step as usize) {
+            let power_value = self.base.powf(power as f32);
+            for multiplier in multipliers.iter() {
+                let tick = power_value * multiplier;
+                if tick >= min * (1_f32 - TICK_TOLERANCE) && tick <= max * (1_f32 + TICK_TOLERANCE)
+                {
+                    res.push(tick);
+                }
+            }
+        }
+
+        // Domain is too narrow to contain several powers of the base so linear ticks are used.
+        if res.len() < 2 {
+            return LinearScale::new(min, max, self.range_start, self.range_end).ticks();
+        }
+
+        res
+    }
+
+    fn tick_label(&self, tick: &f32) -> String {
+        self.tick_format.format(*tick)
+    }
+
+    fn range_start(&self) -> i32 {
+        self.range_start
+    }
+
+    fn range_end(&self) -> i32 {
+        self.range_end
+    }
+
+    fn set_range(mut self, range_start: i32, range_end: i32) -> Self {
+        self.range_start = range_start;
+        self.range_end = range_end;
+        self
+    }
+
+    fn kind(&self) -> ScaleKind {
+        ScaleKind::Log
+    }
+
+    fn bandwidth(&self) -> f32 {
+        0_f32
+    }
+
+    fn is_range_reversed(&self) -> bool {
+        self.range_start > self.range_end
+    }
+
+    fn tick_offset(&self) -> f32 {
+        0_f32
+    }
+}
+
+impl ContinuousScale for LogScale {
+    type Domain = f32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_scale_basic() {
+        let log_scale = LogScale::new(1_f32, 100_000_f32, 500, 0).expect("unable to create scale");
+
+        assert_eq!(log_scale.range_start(), 500);
+        assert_eq!(log_scale.range_end(), 0);
+        assert_eq!(
+            log_scale.ticks(),
+            vec![1_f32, 10_f32, 100_f32, 1_000_f32, 10_000_f32, 100_000_f32]
+        );
+        assert!((log_scale.scale(&1_000_f32) - 200_f32).abs() < 0.001_f32);
+        assert!((log_scale.scale(&0_f32) - 500_f32).abs() < f32::EPSILON);
+        assert_eq!(log_scale.kind(), ScaleKind::Log);
+        assert!((log_scale.bandwidth() - 0_f32).abs() < f32::EPSILON);
+        assert!(log_scale.is_range_reversed());
+        assert!((log_scale.tick_offset() - 0_f32).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn log_scale_custom_ticks() {
+        let log_scale = LogScale::new(1_f32, 100_f32, 0, 100)
+            .expect("unable to create scale")
+            .set_intermediate_ticks(true);
+        assert_eq!(
+            log_scale.ticks(),
+            vec![1_f32, 2_f32, 5_f32, 10_f32, 20_f32, 50_f32, 100_f32]
+        );
+
+        let log_scale = LogScale::new(1_f32, 16_f32, 0, 100)
+            .expect("unable to create scale")
+            .set_base(2_f32)
+            .expect("unable to set base");
+        assert_eq!(log_scale.ticks(), vec![1_f32, 2_f32, 4_f32, 8_f32, 16_f32]);
+        assert!((log_scale.scale(&4_f32) - 50_f32).abs() < 0.001_f32);
+    }
+
+    #[test]
+    fn log_scale_errors() {
+        assert!(matches!(
+            LogScale::new(0_f32, 100_f32, 0, 100),
+            Err(Error::LogDomainIsNotPositive)
+        ));
+        assert!(matches!(
+            LogScale::new(1_f32, -100_f32, 0, 100),
+            Err(Error::LogDomainIsNotPositive)
+        ));
+        assert!(matches!(
+            LogScale::new(1_f32, f32::INFINITY, 0, 100),
+            Err(Error::LogDomainIsNotPositive)
+        ));
+        assert!(matches!(
+            LogScale::new(1_f32, 100_f32, 0, 100)
+                .expect("unable to create scale")
+                .set_base(1_f32),
+            Err(Error::LogBaseIsInvalid)
+        ));
+        assert!(matches!(
+            LogScale::new(1_f32, 100_f32, 0, 100)
+                .expect("unable to create scale")
+                .set_base(f32::INFINITY),
+            Err(Error::LogBaseIsInvalid)
+        ));
+        assert!(matches!(
+            LogScale::new(1_f32, 1_000_f32, 0, 100)
+                .expect("unable to create scale")
+                .set_base(0.5_f32),
+            Err(Error::LogBaseIsInvalid)
+        ));
+    }
+
+    #[test]
+    fn log_scale_ticks_limit() {
+        // 61 powers of the base are reduced to every 4th power.
+        let log_scale = LogScale::new(1e-30_f32, 1e30_f32, 0, 100).expect("unable to create scale");
+        let ticks = log_scale.ticks();
+        assert_eq!(ticks.len(), 15);
+        assert!((ticks[1] / ticks[0] - 10_000_f32).abs() < 0.1_f32);
+
+        let log_scale = LogScale::new(1_f32, 1_000_000_f32, 0, 100)
+            .expect("unable to create scale")
+            .set_base(1.000_001_f32)
+            .expect("unable to set base");
+        assert!(log_scale.ticks().len() <= MAX_TICK_POWERS as usize + 1);
+    }
+}
@@ -1,13 +1,20 @@
+use crate::math::linear::{interpolate, normalize};
 use std::cmp::PartialEq;
 
 pub mod band;
+pub mod color;
+pub mod format;
 pub mod linear;
+pub mod log;
+pub mod time;
 
 /// ScaleKind represents supported scales.
 #[derive(Debug, PartialEq)]
 pub enum ScaleKind {
     Band,
     Linear,
+    Log,
+    Time,
 }
 
 /// Scale represents an axis scale that is used in views and chart.
@@ -15,9 +22,34 @@ pub trait Scale<T> {
     /// Scale the provided domain value for a scale range.
     fn scale(&self, domain: &T) -> f32;
 
+    /// Check if the provided domain value can be represented on the scale.
+    /// Scales accept every value by default.
+    fn accepts(&self, _domain: &T) -> bool {
+        true
+    }
+
     /// Get the list of ticks that represent the scale on an axis.
     fn ticks(&self) -> Vec<T>;
 
+    /// Get the label for the provided tick.
+    fn tick_label(&self, tick: &T) -> String
+    where
+        T: ToString,
+    {
+        tick.to_string()
+    }
+
+    /// Get scale range start.
+    fn range_start(&self) -> i32;
+
+    /// Get scale range end.
+    fn range_end(&self) -> i32;
+
+    /// Set scale range, views and chart axes use it to lay out the scale for the chart view size.
+    fn set_range(self, range_start: i32, range_end: i32) -> Self
+    where
+        Self: Sized;
+
     /// Get the scale kind.
     fn kind(&self) -> ScaleKind;
 
@@ -30,3 +62,64 @@ pub trait Scale<T> {
     /// Get the offset for each tick.
     fn tick_offset(&self) -> f32;
 }
+
+/// ContinuousScale represents a scale with a continuous domain, like numbers or timestamps.
+/// Views use it to accept values that are paired with their positions on such scale.
+pub trait ContinuousScale: Scale<<Self as ContinuousScale>::Domain> {
+    /// Type of the scale domain values.
+    type Domain;
+}
+
+// ScaleRange is a range of a scale that views keep to lay out their values for any view size.
+#[derive(Copy, Clone, Default)]
+pub(crate) struct ScaleRange {
+    start: i32,
+    end: i32,
+}
+
+impl ScaleRange {
+    // Get the range of the provided scale.
+    pub(crate) fn new<T, S: Scale<T> + ?Sized>(scale: &S) -> Self {
+        Self {
+            start: scale.range_start(),
+            end: scale.range_end(),
+        }
+    }
+
+    // Get the range that covers the provided view size in the direction of this range.
+    pub(crate) fn fit(&self, size: i32) -> Self {
+        if self.start > self.end {
+            return Self {
+                start: size,
+                end: 0,
+            };
+        }
+
+        Self {
+            start: 0,
+            end: size,
+        }
+    }
+
+    // Get the range size.
+    pub(crate) fn size(&self) -> f32 {
+        (self.end - self.start).abs() as f32
+    }
+
+    // Get position of the scaled value relative to the range, 0 is the range start and 1 is its end.
+    pub(crate) fn normalize(&self, scaled: f32) -> f32 {
+        normalize(self.start as f32, self.end as f32, scaled)
+    }
+
+    // Get scaled value of the position relative to the range.
+    pub(crate) fn interpolate(&self, position: f32) -> f32 {
+        interpolate(self.start as f32, self.end as f32, position)
+    }
+}
+
+// Get a copy of the scale with its range fitted to the provided view size,
+// views and chart axes compute their geometry with it when they are rendered.
+pub(crate) fn fit_scale<T, S: Scale<T> + Clone>(scale: &S, size: i32) -> S {
+    let range = ScaleRange::new(scale).fit(size);
+    scale.clone().set_range(range.start, range.end)
+}
@@ -0,0 +1,409 @@
+use crate::math::linear::{interpolate, normalize};
+use crate::{ContinuousScale, Scale, ScaleKind};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+use std::fmt::{Display, Write};
+
+const DEFAULT_TICK_COUNT: i64 = 10;
+
+const SECONDS_IN_MINUTE: i64 = 60;
+const SECONDS_IN_HOUR: i64 = 3_600;
+const SECONDS_IN_DAY: i64 = 86_400;
+const SECONDS_IN_WEEK: i64 = 604_800;
+const SECONDS_IN_MONTH: i64 = 2_592_000;
+const SECONDS_IN_YEAR: i64 = 31_536_000;
+
+const FORMAT_SECONDS: &str = "%H:%M:%S";
+const FORMAT_MINUTES: &str = "%H:%M";
+const FORMAT_DAYS: &str = "%b %d";
+const FORMAT_MONTHS: &str = "%b %Y";
+const FORMAT_YEARS: &str = "%Y";
+
+// Intervals between ticks in ascending order.
+const TICK_INTERVALS: [TickInterval; 18] = [
+    TickInterval::new(TimeUnit::Second, 1),
+    TickInterval::new(TimeUnit::Second, 5),
+    TickInterval::new(TimeUnit::Second, 15),
+    TickInterval::new(TimeUnit::Second, 30),
+    TickInterval::new(TimeUnit::Minute, 1),
+    TickInterval::new(TimeUnit::Minute, 5),
+    TickInterval::new(TimeUnit::Minute, 15),
+    TickInterval::new(TimeUnit::Minute, 30),
+    TickInterval::new(TimeUnit::Hour, 1),
+    TickInterval::new(TimeUnit::Hour, 3),
+    TickInterval::new(TimeUnit::Hour, 6),
+    TickInterval::new(TimeUnit::Hour, 12),
+    TickInterval::new(TimeUnit::Day, 1),
+    TickInterval::new(TimeUnit::Day, 2),
+    TickInterval::new(TimeUnit::Week, 1),
+    TickInterval::new(TimeUnit::Month, 1),
+    TickInterval::new(TimeUnit::Month, 3),
+    TickInterval::new(TimeUnit::Year, 1),
+];
+
+/// TimeUnit represents calendar units that are used for ticks of a time scale.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// TickInterval represents a distance between two ticks of a time scale.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct TickInterval {
+    unit: TimeUnit,
+    step: i64,
+}
+
+impl TickInterval {
+    const fn new(unit: TimeUnit, step: i64) -> Self {
+        Self { unit, step }
+    }
+
+    // Get approximate interval duration in seconds.
+    fn seconds(&self) -> i64 {
+        let unit_seconds = match self.unit {
+            TimeUnit::Second => 1,
+            TimeUnit::Minute => SECONDS_IN_MINUTE,
+            TimeUnit::Hour => SECONDS_IN_HOUR,
+            TimeUnit::Day => SECONDS_IN_DAY,
+            TimeUnit::Week => SECONDS_IN_WEEK,
+            TimeUnit::Month => SECONDS_IN_MONTH,
+            TimeUnit::Year => SECONDS_IN_YEAR,
+        };
+
+        unit_seconds * self.step
+    }
+
+    // Get tick label format.
+    fn format(&self) -> &'static str {
+        match self.unit {
+            TimeUnit::Second => FORMAT_SECONDS,
+            TimeUnit::Minute | TimeUnit::Hour => FORMAT_MINUTES,
+            TimeUnit::Day | TimeUnit::Week => FORMAT_DAYS,
+            TimeUnit::Month => FORMAT_MONTHS,
+            TimeUnit::Year => FORMAT_YEARS,
+        }
+    }
+
+    // Round local date and time down to the interval boundary.
+    fn floor(&self, local: NaiveDateTime) -> NaiveDateTime {
+        let date = local.date();
+        match self.unit {
+            TimeUnit::Second | TimeUnit::Minute | TimeUnit::Hour => {
+                let seconds = local.num_seconds_from_midnight() as i64;
+                midnight(date) + Duration::seconds(seconds - seconds % self.seconds())
+            }
+            TimeUnit::Day => midnight(date),
+            TimeUnit::Week => {
+                midnight(date) - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            TimeUnit::Month => {
+                let month = date.month0() as i64;
+                first_day(date.year() as i64, month - month % self.step)
+            }
+            TimeUnit::Year => {
+                let year = date.year() as i64;
+                first_day(year - year.rem_euclid(self.step), 0)
+            }
+        }
+    }
+
+    // Get the next interval boundary.
+    fn next(&self, local: NaiveDateTime) -> NaiveDateTime {
+        let date = local.date();
+        match self.unit {
+            TimeUnit::Month => first_day(date.year() as i64, date.month0() as i64 + self.step),
+            TimeUnit::Year => first_day(date.year() as i64 + self.step, 0),
+            _ => local + Duration::seconds(self.seconds()),
+        }
+    }
+}
+
+// Get the start of the provided day.
+fn midnight(date: NaiveDate) -> NaiveDateTime {
+    date.and_hms_opt(0, 0, 0).unwrap_or_default()
+}
+
+// Get the start of the first day of the month, month value can exceed 11.
+fn first_day(year: i64, month0: i64) -> NaiveDateTime {
+    let year = year + month0.div_euclid(12);
+    let month = month0.rem_euclid(12) as u32 + 1;
+
+    NaiveDate::from_ymd_opt(year as i32, month, 1)
+        .map(midnight)
+        .unwrap_or_default()
+}
+
+/// TimeScale represents axis scale with timestamps.
+/// Ticks are aligned to calendar units and labeled in the time zone of the domain.
+#[derive(Clone)]
+pub struct TimeScale<Tz: TimeZone = Utc> {
+    /// Start of the scale domain.
+    domain_start: DateTime<Tz>,
+
+    /// End of the scale domain.
+    domain_end: DateTime<Tz>,
+
+    /// Start of the scale range.
+    range_start: i32,
+
+    /// End of the scale range.
+    range_end: i32,
+
+    /// Maximum count of ticks on scale axis.
+    tick_count: i64,
+
+    /// Strftime format of tick labels, it's chosen by the tick interval without it.
+    tick_format: Option<String>,
+}
+
+impl<Tz: TimeZone> TimeScale<Tz> {
+    /// Create a new TimeScale.
+    pub fn new(
+        domain_start: DateTime<Tz>,
+        domain_end: DateTime<Tz>,
+        range_start: i32,
+        range_end: i32,
+    ) -> Self {
+        Self {
+            domain_start,
+            domain_end,
+            range_start,
+            range_end,
+            tick_count: DEFAULT_TICK_COUNT,
+            tick_format: None,
+        }
+    }
+
+    /// Set strftime format of tick labels like `%Y-%m-%d`.
+    /// Invalid formats fall back to the format that is chosen by the tick interval.
+    pub fn set_tick_format(mut self, tick_format: &str) -> Self {
+        self.tick_format = Some(tick_format.to_string());
+        self
+    }
+
+    // Get domain bounds in ascending order.
+    fn domain_min_max(&self) -> (&DateTime<Tz>, &DateTime<Tz>) {
+        if self.domain_start > self.domain_end {
+            return (&self.domain_end, &self.domain_start);
+        }
+
+        (&self.domain_start, &self.domain_end)
+    }
+
+    // Find the smallest calendar interval that produces no more ticks than the tick count.
+    fn tick_interval(&self) -> TickInterval {
+        let (min, max) = self.domain_min_max();
+        let span = max.clone().signed_duration_since(min.clone()).num_seconds();
+
+        for interval in TICK_INTERVALS.iter() {
+            if span / interval.seconds() <= self.tick_count {
+                return *interval;
+            }
+        }
+
+        // Use 1, 2 or 5 multiplied by a power of 10 as a step for long spans.
+        let years = span / SECONDS_IN_YEAR / self.tick_count;
+        let mut power = 1;
+        loop {
+            for multiplier in [1, 2, 5].iter() {
+                if multiplier * power >= years {
+                    return TickInterval::new(TimeUnit::Year, multiplier * power);
+                }
+            }
+            power *= 10;
+        }
+    }
+}
+
+impl<Tz: TimeZone> Scale<DateTime<Tz>> for TimeScale<Tz>
+where
+    Tz::Offset: Display,
+{
+    fn scale(&self, domain: &DateTime<Tz>) -> f32 {
+        let span = self
+            .domain_end
+            .clone()
+            .signed_duration_since(self.domain_start.clone())
+            .num_milliseconds();
+        let offset = domain
+            .clone()
+            .signed_duration_since(self.domain_start.clone())
+            .num_milliseconds();
+
+        let normalized = normalize(0_f32, span as f32, offset as f32);
+        interpolate(self.range_start as f32, self.range_end as f32, normalized)
+    }
+
+    fn accepts(&self, _domain: &DateTime<Tz>) -> bool {
+        true
+    }
+
+    fn ticks(&self) -> Vec<DateTime<Tz>> {
+        let (min, max) = self.domain_min_max();
+        if min == max {
+            return vec![min.clone()];
+        }
+
+        let interval = self.tick_interval();
+        let time_zone = min.timezone();
+        let local_max = max.naive_local();
+
+        let mut res = Vec::new();
+        let mut local = interval.floor(min.naive_local());
+        while local <= local_max {
+            if let Some(tick) = time_zone.from_local_datetime(&local).earliest() {
+                if tick >= *min && tick <= *max {
+                    res.push(tick);
+                }
+            }
+            local = interval.next(local);
+        }
+
+        res
+    }
+
+    fn tick_label(&self, tick: &DateTime<Tz>) -> String {
+        if let Some(tick_format) = &self.tick_format {
+            let mut label = String::new();
+            if write!(label, "{}", tick.format(tick_format)).is_ok() {
+                return label;
+            }
+        }
+
+        let interval = self.tick_interval();
+        let format = match interval.unit {
+            // Show the date instead of the time for ticks that start a new day.
+            TimeUnit::Second | TimeUnit::Minute | TimeUnit::Hour
+                if tick.num_seconds_from_midnight() == 0 =>
+            {
+                FORMAT_DAYS
+            }
+            _ => interval.format(),
+        };
+
+        tick.format(format).to_string()
+    }
+
+    fn range_start(&self) -> i32 {
+        self.range_start
+    }
+
+    fn range_end(&self) -> i32 {
+        self.range_end
+    }
+
+    fn set_range(mut self, range_start: i32, range_end: i32) -> Self {
+        self.range_start = range_start;
+        self.range_end = range_end;
+        self
+    }
+
+    fn kind(&self) -> ScaleKind {
+        ScaleKind::Time
+    }
+
+    fn bandwidth(&self) -> f32 {
+        0_f32
+    }
+
+    fn is_range_reversed(&self) -> bool {
+        self.range_start > self.range_end
+    }
+
+    fn tick_offset(&self) -> f32 {
+        0_f32
+    }
+}
+
+impl<Tz: TimeZone> ContinuousScale for TimeScale<Tz>
+where
+    Tz::Offset: Display,
+{
+    type Domain = DateTime<Tz>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    #[test]
+    fn time_scale_basic() {
+        let time_scale = TimeScale::new(
+            Utc.with_ymd_and_hms(2021, 5, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2021, 5, 1, 12, 0, 0).unwrap(),
+            0,
+            120,
+        );
+
+        assert_eq!(time_scale.range_start(), 0);
+        assert_eq!(time_scale.range_end(), 120);
+        assert_eq!(
+            time_scale
+                .ticks()
+                .iter()
+                .map(|tick| time_scale.tick_label(tick))
+                .collect::<Vec<String>>(),
+            vec!["May 01", "03:00", "06:00", "09:00", "12:00"]
+        );
+        assert!(
+            (time_scale.scale(&Utc.with_ymd_and_hms(2021, 5, 1, 3, 0, 0).unwrap()) - 30_f32).abs()
+                < f32::EPSILON
+        );
+        assert_eq!(time_scale.kind(), ScaleKind::Time);
+        assert!((time_scale.bandwidth() - 0_f32).abs() < f32::EPSILON);
+        assert!(!time_scale.is_range_reversed());
+        assert!((time_scale.tick_offset() - 0_f32).abs() < f32::EPSILON);
+
+        let tick = Utc.with_ymd_and_hms(2021, 5, 1, 3, 0, 0).unwrap();
+        let time_scale = time_scale.set_tick_format("%d.%m %Hh");
+        assert_eq!(time_scale.tick_label(&tick), "01.05 03h");
+        let time_scale = time_scale.set_tick_format("%Q");
+        assert_eq!(time_scale.tick_label(&tick), "03:00");
+    }
+
+    #[test]
+    fn time_scale_calendar_ticks() {
+        let offset = FixedOffset::east_opt(3 * SECONDS_IN_HOUR as i32).unwrap();
+        let time_scale = TimeScale::new(
+            offset.with_ymd_and_hms(2020, 11, 15, 10, 0, 0).unwrap(),
+            offset.with_ymd_and_hms(2021, 6, 20, 10, 0, 0).unwrap(),
+            0,
+            100,
+        );
+        let ticks = time_scale.ticks();
+
+        assert_eq!(
+            ticks
+                .iter()
+                .map(|tick| time_scale.tick_label(tick))
+                .collect::<Vec<String>>(),
+            vec![
+                "Dec 2020", "Jan 2021", "Feb 2021", "Mar 2021", "Apr 2021", "May 2021", "Jun 2021"
+            ]
+        );
+        assert_eq!(ticks[0].to_rfc3339(), "2020-12-01T00:00:00+03:00");
+
+        let time_scale = TimeScale::new(
+            Utc.with_ymd_and_hms(1990, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(),
+            0,
+            100,
+        );
+
+        assert_eq!(
+            time_scale
+                .ticks()
+                .iter()
+                .map(|tick| time_scale.tick_label(tick))
+                .collect::<Vec<String>>(),
+            vec!["1990", "1995", "2000", "2005", "2010", "2015", "2020"]
+        );
+    }
+}
@@ -1,13 +1,24 @@
 use crate::render::svg::*;
+use crate::shape::curve::{curve_path, Curve};
 use crate::shape::point::Point;
+use crate::Theme;
 use svg::Node;
 
-/// Area shape.
+/// Area shape, its points go along the area top and return along the area baseline.
 #[derive(Clone)]
 pub struct Area {
     points: Vec<Point>,
+    baseline_points: Vec<Point>,
+    curve: Curve,
     fill_color: String,
     stroke_color: String,
+    palette_series: Option<usize>,
+}
+
+impl Default for Area {
+    fn default() -> Self {
+        Self::new(Vec::new(), "", "")
+    }
 }
 
 impl Area {
@@ -15,50 +26,77 @@ impl Area {
     pub fn new(points: Vec<Point>, fill_color: &str, stroke_color: &str) -> Self {
         Self {
             points,
+            baseline_points: Vec::new(),
+            curve: Curve::Linear,
             fill_color: fill_color.to_string(),
             stroke_color: stroke_color.to_string(),
+            palette_series: None,
         }
     }
 
+    /// Set baseline points that are placed in the reversed order.
+    pub fn set_baseline_points(mut self, baseline_points: Vec<Point>) -> Self {
+        self.baseline_points = baseline_points;
+        self
+    }
+
+    /// Take fill and stroke colors of the area and its points from the theme palette color
+    /// of the provided series.
+    pub fn set_palette_series(mut self, palette_series: usize) -> Self {
+        self.palette_series = Some(palette_series);
+        self.points = self
+            .points
+            .into_iter()
+            .map(|point| point.set_palette_series(palette_series))
+            .collect();
+        self.baseline_points = self
+            .baseline_points
+            .into_iter()
+            .map(|point| point.set_palette_series(palette_series))
+            .collect();
+        self
+    }
+
+    /// Set curve that connects points of the top and of the baseline.
+    pub fn set_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
+
     /// Get area SVG representation.
-    pub fn to_svg(&self) -> svg::node::element::Group {
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
         let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_AREA);
 
+        let top: Vec<(f32, f32)> = self.points.iter().map(|p| (p.x(), p.y())).collect();
+        let baseline: Vec<(f32, f32)> = self
+            .baseline_points
+            .iter()
+            .map(|p| (p.x(), p.y()))
+            .collect();
         let mut data = svg::node::element::path::Data::new();
-        for (point_idx, point) in self.points.iter().enumerate() {
-            if point_idx == 0 {
-                data = data.move_to((point.x(), point.y()));
-            } else {
-                data = data.line_to((point.x(), point.y()));
-            }
-
-            res.append(point.to_svg());
-        }
+        data = curve_path(data, &top, self.curve, true);
+        data = curve_path(data, &baseline, self.curve, false);
         data = data.close();
 
-        let area = svg::node::element::Path::new()
-            .set(FILL_ATTR, self.fill_color.as_ref())
-            .set(STROKE_ATTR, self.stroke_color.as_ref())
-            .set(D_ATTR, data);
-        res.append(area);
-        for point in self.points.iter() {
-            res.append(point.to_svg());
+        // Area of a single point has no extent so only its point is drawn.
+        if self.points.len() > 1 {
+            let palette_color = theme.palette_color(self.palette_series);
+            let fill_color = palette_color.unwrap_or(&self.fill_color);
+            let stroke_color = palette_color.unwrap_or(&self.stroke_color);
+            let area = svg::node::element::Path::new()
+                .set(FILL_ATTR, fill_color)
+                .set(STROKE_ATTR, stroke_color)
+                .set(D_ATTR, data);
+            res.append(area);
+        }
+        for point in self.points.iter().chain(self.baseline_points.iter()) {
+            res.append(point.to_svg(theme));
         }
 
         res
     }
 }
 
-impl Default for Area {
-    fn default() -> Self {
-        Self {
-            points: Vec::new(),
-            fill_color: String::new(),
-            stroke_color: String::new(),
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,18 +105,6 @@ mod tests {
     #[test]
     fn bar_basic() {
         let expected_svg_group = r##"<g class="area">
-<g class="point" transform="translate(10,20)">
-<circle cx="0" cy="0" fill="#ed5d74" r="1" stroke="#e6121f"/>
-<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="0" y="-13">
-12
-</text>
-</g>
-<g class="point" transform="translate(30,40)">
-<circle cx="0" cy="0" fill="#ed5d74" r="1" stroke="#e6121f"/>
-<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="0" y="-13">
-12
-</text>
-</g>
 <path d="M10,20 L30,40 z" fill="#e93620" stroke="#370725"/>
 <g class="point" transform="translate(10,20)">
 <circle cx="0" cy="0" fill="#ed5d74" r="1" stroke="#e6121f"/>
@@ -118,7 +144,7 @@ mod tests {
             "#e93620",
             "#370725",
         )
-        .to_svg();
+        .to_svg(&Theme::default());
 
         assert_eq!(area_svg.to_string(), expected_svg_group);
     }
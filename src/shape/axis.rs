@@ -1,20 +1,26 @@
 use crate::render::svg::*;
 use crate::shape::axis_line::AxisLine;
 use crate::shape::axis_tick::AxisTick;
-use crate::Scale;
+use crate::{Scale, Theme};
 use std::string::ToString;
 use svg::Node;
 
 const DEFAULT_TICK_LABEL_HORIZONTAL_OFFSET: i32 = 12;
 const DEFAULT_TICK_LABEL_VERTICAL_OFFSET: i32 = 16;
 
-const DEFAULT_FONT_SIZE: &str = "14px";
-
 const DEFAULT_AXIS_LABEL_TOP_OFFSET: i32 = -32;
 const DEFAULT_AXIS_LABEL_BOTTOM_OFFSET: i32 = 42;
 const DEFAULT_AXIS_LABEL_LEFT_OFFSET: i32 = -42;
 const DEFAULT_AXIS_LABEL_RIGHT_OFFSET: i32 = -28;
 
+// Approximate width of a label character relative to the font size.
+const DEFAULT_CHAR_WIDTH_RATIO: f32 = 0.6_f32;
+
+// Distance between axis label and tick labels.
+const DEFAULT_AXIS_LABEL_GAP: i32 = 6;
+
+const DEFAULT_GRID_VISIBLE: bool = false;
+
 /// AxisPosition represents a position for axis line on a chart.
 #[derive(Copy, Clone)]
 pub enum AxisPosition {
@@ -29,10 +35,11 @@ pub struct Axis {
     ticks: Vec<AxisTick>,
     line: AxisLine,
     position: AxisPosition,
+    length: f32,
     label: String,
     label_x_attr: i32,
-    label_y_attr: i32,
     label_rotation: i32,
+    grid_visible: bool,
 }
 
 impl Axis {
@@ -43,14 +50,21 @@ impl Axis {
         view_width: i32,
         view_height: i32,
     ) -> Self {
+        let ticks = Self::axis_ticks(scale, position);
+        let length = match position {
+            AxisPosition::Top | AxisPosition::Bottom => view_width as f32,
+            AxisPosition::Left | AxisPosition::Right => view_height as f32,
+        };
+
         Self {
-            ticks: Self::axis_ticks(scale, position),
+            ticks,
             position,
+            length,
             line: Self::axis_line(position, view_width as f32, view_height as f32),
             label: String::new(),
             label_x_attr: Self::axis_label_x_attr(position, view_width, view_height),
-            label_y_attr: Self::axis_label_y_attr(position),
             label_rotation: Self::axis_label_rotation(position),
+            grid_visible: DEFAULT_GRID_VISIBLE,
         }
     }
 
@@ -59,6 +73,85 @@ impl Axis {
         self.label = label.to_string();
     }
 
+    /// Configure grid lines visibility for Axis ticks.
+    pub fn set_grid_visible(&mut self, grid_visible: bool) {
+        self.grid_visible = grid_visible;
+    }
+
+    /// Check if grid lines are visible for Axis ticks.
+    pub fn grid_visible(&self) -> bool {
+        self.grid_visible
+    }
+
+    /// Get Axis position.
+    pub fn position(&self) -> AxisPosition {
+        self.position
+    }
+
+    /// Get offsets of Axis ticks.
+    pub fn tick_offsets(&self) -> Vec<f32> {
+        self.ticks.iter().map(|tick| tick.tick_offset()).collect()
+    }
+
+    /// Get approximate distance from Axis line to the outer edge of its tick labels and label
+    /// for the font sizes of the provided theme.
+    pub fn size(&self, theme: &Theme) -> i32 {
+        if self.label.is_empty() {
+            return self.ticks_size(theme);
+        }
+
+        match self.position {
+            // Bottom label is placed above its baseline so only a descent follows it.
+            AxisPosition::Bottom => self.label_y_attr(theme).abs() + DEFAULT_AXIS_LABEL_GAP,
+            _ => self.label_y_attr(theme).abs() + theme.axis_label_font_size(),
+        }
+    }
+
+    /// Get approximate overflow of the first and the last tick labels beyond Axis line ends
+    /// for the font sizes of the provided theme.
+    pub fn overflow(&self, theme: &Theme) -> (i32, i32) {
+        let mut start = 0_f32;
+        let mut end = 0_f32;
+        for tick in self.ticks.iter() {
+            let half_size = match self.position {
+                AxisPosition::Top | AxisPosition::Bottom => {
+                    Self::tick_label_width(tick, theme) as f32 / 2_f32
+                }
+                AxisPosition::Left | AxisPosition::Right => {
+                    theme.tick_label_font_size() as f32 / 2_f32
+                }
+            };
+            start = start.max(half_size - tick.tick_offset());
+            end = end.max(tick.tick_offset() + half_size - self.length);
+        }
+
+        (start.ceil() as i32, end.ceil() as i32)
+    }
+
+    // Get approximate width of a tick label.
+    fn tick_label_width(tick: &AxisTick, theme: &Theme) -> i32 {
+        let char_width = theme.tick_label_font_size() as f32 * DEFAULT_CHAR_WIDTH_RATIO;
+        (tick.label().chars().count() as f32 * char_width).ceil() as i32
+    }
+
+    // Get approximate distance from Axis line to the outer edge of tick labels.
+    fn ticks_size(&self, theme: &Theme) -> i32 {
+        match self.position {
+            AxisPosition::Top | AxisPosition::Bottom => {
+                DEFAULT_TICK_LABEL_VERTICAL_OFFSET + theme.tick_label_font_size() / 2
+            }
+            AxisPosition::Left | AxisPosition::Right => {
+                DEFAULT_TICK_LABEL_HORIZONTAL_OFFSET
+                    + self
+                        .ticks
+                        .iter()
+                        .map(|tick| Self::tick_label_width(tick, theme))
+                        .max()
+                        .unwrap_or(0)
+            }
+        }
+    }
+
     fn axis_ticks<T: ToString>(scale: &dyn Scale<T>, position: AxisPosition) -> Vec<AxisTick> {
         let mut res = Vec::new();
         let label_offset = match position {
@@ -68,7 +161,12 @@ impl Axis {
 
         for tick in scale.ticks() {
             let tick_offset = scale.scale(&tick) + scale.tick_offset();
-            let axis_tick = AxisTick::new(position, tick_offset, &*tick.to_string(), label_offset);
+            let axis_tick = AxisTick::new(
+                position,
+                tick_offset,
+                &scale.tick_label(&tick),
+                label_offset,
+            );
             res.push(axis_tick);
         }
 
@@ -94,12 +192,22 @@ impl Axis {
         }
     }
 
-    fn axis_label_y_attr(position: AxisPosition) -> i32 {
-        match position {
-            AxisPosition::Top => DEFAULT_AXIS_LABEL_TOP_OFFSET,
-            AxisPosition::Bottom => DEFAULT_AXIS_LABEL_BOTTOM_OFFSET,
-            AxisPosition::Left => DEFAULT_AXIS_LABEL_LEFT_OFFSET,
-            AxisPosition::Right => DEFAULT_AXIS_LABEL_RIGHT_OFFSET,
+    // Axis label is moved away from the line when it would overlap tick labels.
+    fn label_y_attr(&self, theme: &Theme) -> i32 {
+        let ticks_size = self.ticks_size(theme);
+        match self.position {
+            AxisPosition::Top => {
+                DEFAULT_AXIS_LABEL_TOP_OFFSET.min(-(ticks_size + DEFAULT_AXIS_LABEL_GAP))
+            }
+            AxisPosition::Bottom => DEFAULT_AXIS_LABEL_BOTTOM_OFFSET
+                .max(ticks_size + DEFAULT_AXIS_LABEL_GAP + theme.axis_label_font_size()),
+            AxisPosition::Left => {
+                DEFAULT_AXIS_LABEL_LEFT_OFFSET.min(-(ticks_size + DEFAULT_AXIS_LABEL_GAP))
+            }
+            // Right label is rotated clockwise so its y attribute points away from the view.
+            AxisPosition::Right => {
+                DEFAULT_AXIS_LABEL_RIGHT_OFFSET.min(-(ticks_size + DEFAULT_AXIS_LABEL_GAP))
+            }
         }
     }
 
@@ -112,7 +220,7 @@ impl Axis {
     }
 
     /// Get axis SVG representation.
-    pub fn to_svg(&self) -> svg::node::element::Group {
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
         let axis_class = match self.position {
             AxisPosition::Top | AxisPosition::Bottom => CLASS_X_AXIS,
             AxisPosition::Left | AxisPosition::Right => CLASS_Y_AXIS,
@@ -120,9 +228,9 @@ impl Axis {
 
         let mut res = svg::node::element::Group::new()
             .set(CLASS_ATTR, axis_class)
-            .add(self.line.to_svg());
+            .add(self.line.to_svg(theme));
         for tick in self.ticks.iter() {
-            res.append(tick.to_svg());
+            res.append(tick.to_svg(theme));
         }
 
         if self.label.is_empty() {
@@ -131,11 +239,11 @@ impl Axis {
 
         let axis_label = svg::node::element::Text::new()
             .set(X_ATTR, self.label_x_attr)
-            .set(Y_ATTR, self.label_y_attr)
+            .set(Y_ATTR, self.label_y_attr(theme))
             .set(TEXT_ANCHOR_ATTR, TEXT_ANCHOR_MIDDLE)
-            .set(FONT_SIZE_ATTR, DEFAULT_FONT_SIZE)
-            .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
-            .set(FILL_ATTR, DEFAULT_FONT_COLOR)
+            .set(FONT_SIZE_ATTR, px(theme.axis_label_font_size()))
+            .set(FONT_FAMILY_ATTR, theme.font_family())
+            .set(FILL_ATTR, theme.font_color())
             .set(TRANSFORM_ATTR, rotate_a(self.label_rotation))
             .add(svg::node::Text::new(&self.label));
         res.append(axis_label);
@@ -178,7 +286,7 @@ a2
             0,
             1000,
         );
-        let axis_svg = Axis::new(&scale, AxisPosition::Left, 100, 200).to_svg();
+        let axis_svg = Axis::new(&scale, AxisPosition::Left, 100, 200).to_svg(&Theme::default());
 
         assert_eq!(axis_svg.to_string(), expected_svg_group);
     }
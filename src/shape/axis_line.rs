@@ -1,4 +1,5 @@
 use crate::render::svg::*;
+use crate::Theme;
 
 /// AxisLine represents line of an axis.
 pub struct AxisLine {
@@ -7,7 +8,6 @@ pub struct AxisLine {
     x2: f32,
     y2: f32,
     stroke_width: i32,
-    stroke_color: String,
 }
 
 impl AxisLine {
@@ -19,7 +19,6 @@ impl AxisLine {
             y1,
             y2,
             stroke_width: DEFAULT_STROKE_WIDTH,
-            stroke_color: DEFAULT_STROKE_COLOR.to_string(),
         }
     }
 
@@ -34,7 +33,7 @@ impl AxisLine {
     }
 
     /// Get SVG representation of an line.
-    pub fn to_svg(&self) -> svg::node::element::Line {
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Line {
         svg::node::element::Line::new()
             .set(X1_ATTR, self.x1)
             .set(X2_ATTR, self.x2)
@@ -42,7 +41,7 @@ impl AxisLine {
             .set(Y2_ATTR, self.y2)
             .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
             .set(STROKE_WIDTH_ATTR, self.stroke_width)
-            .set(STROKE_ATTR, self.stroke_color.to_owned())
+            .set(STROKE_ATTR, theme.axis_color())
     }
 }
 
@@ -54,7 +53,7 @@ mod tests {
     fn axis_line_horizontal_basic() {
         let expected_svg_group = r##"<line shape-rendering="crispEdges" stroke="#bbbbbb" stroke-width="1" x1="0" x2="10" y1="0" y2="0"/>"##;
 
-        let axis_line_svg = AxisLine::new_horizontal(10_f32).to_svg();
+        let axis_line_svg = AxisLine::new_horizontal(10_f32).to_svg(&Theme::default());
 
         assert_eq!(axis_line_svg.to_string(), expected_svg_group);
     }
@@ -63,7 +62,7 @@ mod tests {
     fn axis_line_vertical_basic() {
         let expected_svg_group = r##"<line shape-rendering="crispEdges" stroke="#bbbbbb" stroke-width="1" x1="0" x2="0" y1="0" y2="10"/>"##;
 
-        let axis_line_svg = AxisLine::new_vertical(10_f32).to_svg();
+        let axis_line_svg = AxisLine::new_vertical(10_f32).to_svg(&Theme::default());
 
         assert_eq!(axis_line_svg.to_string(), expected_svg_group);
     }
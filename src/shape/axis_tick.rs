@@ -1,9 +1,9 @@
 use crate::render::svg::*;
 use crate::shape::axis::AxisPosition;
+use crate::Theme;
 use svg::Node;
 
 const DEFAULT_LINE_LENGTH: i32 = 6;
-const DEFAULT_FONT_SIZE: &str = "12px";
 const DEFAULT_LABEL_ROTATION: i32 = 0;
 
 /// AxisTick represents a single tick on axis line.
@@ -32,8 +32,18 @@ impl AxisTick {
         }
     }
 
+    /// Get tick offset on the axis.
+    pub fn tick_offset(&self) -> f32 {
+        self.tick_offset
+    }
+
+    /// Get tick label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
     /// Get SVG representation of a tick.
-    pub fn to_svg(&self) -> svg::node::element::Group {
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
         let translate_x: f32;
         let translate_y: f32;
         let x2_attr: i32;
@@ -91,7 +101,7 @@ impl AxisTick {
             .set(X2_ATTR, x2_attr)
             .set(Y2_ATTR, y2_attr)
             .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
-            .set(STROKE_ATTR, DEFAULT_STROKE_COLOR)
+            .set(STROKE_ATTR, theme.axis_color())
             .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH);
         res.append(tick_line);
 
@@ -108,9 +118,9 @@ impl AxisTick {
             .set(Y_ATTR, tick_label_offset_y)
             .set(DY_ATTR, DEFAULT_DY)
             .set(TEXT_ANCHOR_ATTR, tick_label_text_anchor)
-            .set(FONT_SIZE_ATTR, DEFAULT_FONT_SIZE)
-            .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
-            .set(FILL_ATTR, DEFAULT_FONT_COLOR)
+            .set(FONT_SIZE_ATTR, px(theme.tick_label_font_size()))
+            .set(FONT_FAMILY_ATTR, theme.font_family())
+            .set(FILL_ATTR, theme.font_color())
             .add(svg::node::Text::new(self.label.to_owned()));
         res.append(tick_label);
 
@@ -131,7 +141,8 @@ tick
 </text>
 </g>"##;
 
-        let axis_tick_svg = AxisTick::new(AxisPosition::Top, 2_f32, "tick", 4).to_svg();
+        let axis_tick_svg =
+            AxisTick::new(AxisPosition::Top, 2_f32, "tick", 4).to_svg(&Theme::default());
 
         assert_eq!(axis_tick_svg.to_string(), expected_svg_group);
     }
@@ -1,10 +1,9 @@
 use crate::color::{COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_2};
 use crate::math::linear::range;
 use crate::render::svg::*;
-use crate::Orientation;
+use crate::{Orientation, Theme};
 use svg::Node;
 
-const DEFAULT_FONT_SIZE: &str = "14px";
 const DEFAULT_LABEL_HORIZONTAL_OFFSET: f32 = 12_f32;
 const DEFAULT_LABEL_VERTICAL_OFFSET: f32 = 16_f32;
 const DEFAULT_LABEL_VISIBLE: bool = true;
@@ -25,13 +24,14 @@ pub enum BarLabelPosition {
 pub struct Bar {
     start: f32,
     end: f32,
-    size: f32,
     width: f32,
     offset: f32,
     orientation: Orientation,
     fill_color: String,
     stroke_color: String,
+    palette_series: Option<usize>,
     stroke_width: i32,
+    label: String,
     label_visible: bool,
     label_position: BarLabelPosition,
     label_text_anchor: String,
@@ -51,13 +51,14 @@ impl Bar {
         Bar {
             start,
             end,
-            size,
             width,
             offset,
             orientation,
             fill_color: COLOR_HEX_BLUE_2.to_string(),
             stroke_color: COLOR_HEX_BLUE_1.to_string(),
+            palette_series: None,
             stroke_width: DEFAULT_STROKE_WIDTH,
+            label: size.to_string(),
             label_visible: DEFAULT_LABEL_VISIBLE,
             label_position: DEFAULT_LABEL_POSITION,
             label_text_anchor: Self::label_text_anchor(DEFAULT_LABEL_POSITION, orientation),
@@ -77,12 +78,24 @@ impl Bar {
         self
     }
 
+    /// Set bar label text, bar size is used by default.
+    pub fn set_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
     /// Set bar label visibility.
     pub fn set_label_visible(mut self, label_visible: bool) -> Self {
         self.label_visible = label_visible;
         self
     }
 
+    /// Take fill and stroke colors from the theme palette color of the provided series.
+    pub fn set_palette_series(mut self, palette_series: usize) -> Self {
+        self.palette_series = Some(palette_series);
+        self
+    }
+
     /// Set label position for bar.
     pub fn set_label_position(mut self, label_position: BarLabelPosition) -> Self {
         self.label_position = label_position;
@@ -158,32 +171,35 @@ impl Bar {
     }
 
     /// Get bar SVG representation.
-    pub fn to_svg(&self) -> svg::node::element::Group {
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
         let x_attr = match self.orientation {
-            Orientation::Horizontal => (X_ATTR),
-            Orientation::Vertical => (Y_ATTR),
+            Orientation::Horizontal => X_ATTR,
+            Orientation::Vertical => Y_ATTR,
         };
         let y_attr = match self.orientation {
-            Orientation::Horizontal => (Y_ATTR),
-            Orientation::Vertical => (X_ATTR),
+            Orientation::Horizontal => Y_ATTR,
+            Orientation::Vertical => X_ATTR,
         };
         let w_attr = match self.orientation {
-            Orientation::Horizontal => (WIDTH_ATTR),
-            Orientation::Vertical => (HEIGHT_ATTR),
+            Orientation::Horizontal => WIDTH_ATTR,
+            Orientation::Vertical => HEIGHT_ATTR,
         };
         let h_attr = match self.orientation {
-            Orientation::Horizontal => (HEIGHT_ATTR),
-            Orientation::Vertical => (WIDTH_ATTR),
+            Orientation::Horizontal => HEIGHT_ATTR,
+            Orientation::Vertical => WIDTH_ATTR,
         };
+        let palette_color = theme.palette_color(self.palette_series);
+        let fill_color = palette_color.unwrap_or(&self.fill_color);
+        let stroke_color = palette_color.unwrap_or(&self.stroke_color);
         let rect = svg::node::element::Rectangle::new()
             .set(x_attr, self.start)
             .set(y_attr, START)
             .set(w_attr, range(self.start, self.end))
             .set(h_attr, self.width)
             .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
-            .set(FILL_ATTR, self.fill_color.to_string())
+            .set(FILL_ATTR, fill_color)
             .set(STROKE_WIDTH_ATTR, self.stroke_width)
-            .set(STROKE_ATTR, self.stroke_color.to_string());
+            .set(STROKE_ATTR, stroke_color);
 
         let offset_x = match self.orientation {
             Orientation::Horizontal => 0.0,
@@ -207,10 +223,10 @@ impl Bar {
             .set(y_attr, self.width / 2.0) // we want label to be centered vertically
             .set(TEXT_ANCHOR_ATTR, self.label_text_anchor.to_owned())
             .set(DY_ATTR, DEFAULT_DY)
-            .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
-            .set(FILL_ATTR, DEFAULT_FONT_COLOR)
-            .set(FONT_SIZE_ATTR, DEFAULT_FONT_SIZE)
-            .add(svg::node::Text::new(self.size.to_string()));
+            .set(FONT_FAMILY_ATTR, theme.font_family())
+            .set(FILL_ATTR, theme.font_color())
+            .set(FONT_SIZE_ATTR, px(theme.label_font_size()))
+            .add(svg::node::Text::new(self.label.as_str()));
         group.append(label);
 
         group
@@ -236,7 +252,7 @@ mod tests {
             .set_stroke_color(COLOR_HEX_GREEN_2)
             .set_label_visible(true)
             .set_label_position(BarLabelPosition::StartOutside)
-            .to_svg();
+            .to_svg(&Theme::default());
 
         assert_eq!(bar_svg.to_string(), expected_svg_group);
     }
@@ -0,0 +1,238 @@
+use crate::math::linear::range;
+use crate::render::svg::*;
+use crate::shape::point::Point;
+use crate::{Orientation, Theme};
+use svg::Node;
+
+const DEFAULT_MEDIAN_STROKE_WIDTH: i32 = 2;
+const DEFAULT_WHISKER_CAP_RATIO: f32 = 0.5_f32;
+
+// Boxes that take their colors from a palette are translucent so the median line stays visible.
+const DEFAULT_PALETTE_FILL_OPACITY: f32 = 0.3_f32;
+
+/// BoxPlot represents a box-and-whisker shape that can be used with vertical or horizontal views.
+/// Values are provided as scaled positions along the value axis.
+#[derive(Clone)]
+pub struct BoxPlot {
+    min: f32,
+    first_quartile: f32,
+    median: f32,
+    third_quartile: f32,
+    max: f32,
+    width: f32,
+    offset: f32,
+    orientation: Orientation,
+    fill_color: String,
+    stroke_color: String,
+    palette_series: Option<usize>,
+    outliers: Vec<Point>,
+}
+
+impl BoxPlot {
+    /// Create a new BoxPlot.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        min: f32,
+        first_quartile: f32,
+        median: f32,
+        third_quartile: f32,
+        max: f32,
+        width: f32,
+        offset: f32,
+        orientation: Orientation,
+    ) -> Self {
+        Self {
+            min,
+            first_quartile,
+            median,
+            third_quartile,
+            max,
+            width,
+            offset,
+            orientation,
+            fill_color: String::new(),
+            stroke_color: String::new(),
+            palette_series: None,
+            outliers: Vec::new(),
+        }
+    }
+
+    /// Set box fill color.
+    pub fn set_fill_color(mut self, fill_color: &str) -> Self {
+        self.fill_color = fill_color.to_string();
+        self
+    }
+
+    /// Set box and whiskers stroke color.
+    pub fn set_stroke_color(mut self, stroke_color: &str) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    /// Take colors of the box, whiskers and outliers from the theme palette color of the provided series.
+    /// The box is filled with a translucent palette color.
+    pub fn set_palette_series(mut self, palette_series: usize) -> Self {
+        self.palette_series = Some(palette_series);
+        self.outliers = self
+            .outliers
+            .into_iter()
+            .map(|outlier| outlier.set_palette_series(palette_series))
+            .collect();
+        self
+    }
+
+    /// Set outlier points, their coordinates are relative to the box plot offset.
+    pub fn set_outliers(mut self, outliers: Vec<Point>) -> Self {
+        self.outliers = outliers;
+        self
+    }
+
+    // Create a line between two points that are set as (value position, cross position) pairs.
+    fn line(
+        &self,
+        stroke_color: &str,
+        start: (f32, f32),
+        end: (f32, f32),
+        stroke_width: i32,
+    ) -> svg::node::element::Line {
+        let (x1, y1, x2, y2) = match self.orientation {
+            Orientation::Horizontal => (start.0, start.1, end.0, end.1),
+            Orientation::Vertical => (start.1, start.0, end.1, end.0),
+        };
+
+        svg::node::element::Line::new()
+            .set(X1_ATTR, x1)
+            .set(Y1_ATTR, y1)
+            .set(X2_ATTR, x2)
+            .set(Y2_ATTR, y2)
+            .set(STROKE_ATTR, stroke_color)
+            .set(STROKE_WIDTH_ATTR, stroke_width)
+    }
+
+    /// Get box plot SVG representation.
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
+        let (offset_x, offset_y) = match self.orientation {
+            Orientation::Horizontal => (START, self.offset),
+            Orientation::Vertical => (self.offset, START),
+        };
+        let mut res = svg::node::element::Group::new()
+            .set(TRANSFORM_ATTR, translate_x_y(offset_x, offset_y))
+            .set(CLASS_ATTR, CLASS_BOX_PLOT);
+
+        let palette_color = theme.palette_color(self.palette_series);
+        let stroke_color = palette_color.unwrap_or(&self.stroke_color);
+
+        let center = self.width / 2_f32;
+        let cap_start = center - self.width * DEFAULT_WHISKER_CAP_RATIO / 2_f32;
+        let cap_end = center + self.width * DEFAULT_WHISKER_CAP_RATIO / 2_f32;
+
+        // Whiskers go from box edges to the minimum and maximum and end with caps.
+        res.append(self.line(
+            stroke_color,
+            (self.min, center),
+            (self.first_quartile, center),
+            DEFAULT_STROKE_WIDTH,
+        ));
+        res.append(self.line(
+            stroke_color,
+            (self.third_quartile, center),
+            (self.max, center),
+            DEFAULT_STROKE_WIDTH,
+        ));
+        res.append(self.line(
+            stroke_color,
+            (self.min, cap_start),
+            (self.min, cap_end),
+            DEFAULT_STROKE_WIDTH,
+        ));
+        res.append(self.line(
+            stroke_color,
+            (self.max, cap_start),
+            (self.max, cap_end),
+            DEFAULT_STROKE_WIDTH,
+        ));
+
+        // Box position doesn't depend on the value scale direction.
+        let box_start = self.first_quartile.min(self.third_quartile);
+        let box_size = range(self.first_quartile, self.third_quartile).abs();
+        let (x, y, width, height) = match self.orientation {
+            Orientation::Horizontal => (box_start, START, box_size, self.width),
+            Orientation::Vertical => (START, box_start, self.width, box_size),
+        };
+        let mut rect = svg::node::element::Rectangle::new()
+            .set(X_ATTR, x)
+            .set(Y_ATTR, y)
+            .set(WIDTH_ATTR, width)
+            .set(HEIGHT_ATTR, height)
+            .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
+            .set(FILL_ATTR, palette_color.unwrap_or(&self.fill_color))
+            .set(STROKE_ATTR, stroke_color)
+            .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH);
+        if palette_color.is_some() {
+            rect.assign(FILL_OPACITY_ATTR, DEFAULT_PALETTE_FILL_OPACITY);
+        }
+        res.append(rect);
+
+        res.append(self.line(
+            stroke_color,
+            (self.median, START),
+            (self.median, self.width),
+            DEFAULT_MEDIAN_STROKE_WIDTH,
+        ));
+
+        for outlier in self.outliers.iter() {
+            res.append(outlier.to_svg(theme));
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_5};
+    use crate::PointType;
+
+    #[test]
+    fn box_plot_basic() {
+        let expected_svg_group = r##"<g class="box-plot" transform="translate(10,0)">
+<line stroke="#0e3569" stroke-width="1" x1="10" x2="10" y1="90" y2="70"/>
+<line stroke="#0e3569" stroke-width="1" x1="10" x2="10" y1="30" y2="10"/>
+<line stroke="#0e3569" stroke-width="1" x1="5" x2="15" y1="90" y2="90"/>
+<line stroke="#0e3569" stroke-width="1" x1="5" x2="15" y1="10" y2="10"/>
+<rect fill="#a5c9f2" height="40" shape-rendering="crispEdges" stroke="#0e3569" stroke-width="1" width="20" x="0" y="30"/>
+<line stroke="#0e3569" stroke-width="2" x1="0" x2="20" y1="50" y2="50"/>
+<g class="point" transform="translate(10,0)">
+<circle cx="0" cy="0" fill="#a5c9f2" r="2" stroke="#0e3569"/>
+</g>
+</g>"##;
+
+        let outlier = Point::new(
+            10_f32,
+            0_f32,
+            PointType::Circle,
+            2,
+            "",
+            COLOR_HEX_BLUE_5,
+            COLOR_HEX_BLUE_1,
+        )
+        .set_label_visible(false);
+        let box_plot_svg = BoxPlot::new(
+            90_f32,
+            70_f32,
+            50_f32,
+            30_f32,
+            10_f32,
+            20_f32,
+            10_f32,
+            Orientation::Vertical,
+        )
+        .set_fill_color(COLOR_HEX_BLUE_5)
+        .set_stroke_color(COLOR_HEX_BLUE_1)
+        .set_outliers(vec![outlier])
+        .to_svg(&Theme::default());
+
+        assert_eq!(box_plot_svg.to_string(), expected_svg_group);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::render::svg::*;
+use crate::shape::bar::Bar;
+use crate::{Orientation, Theme};
+use svg::Node;
+
+const DEFAULT_OHLC_STROKE_WIDTH: i32 = 2;
+
+/// CandlestickStyle contains available styles of financial views.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CandlestickStyle {
+    /// Body between open and close prices with wicks to high and low prices.
+    Candlestick,
+
+    /// Vertical line between high and low prices with ticks of open price on the left
+    /// and close price on the right.
+    Ohlc,
+}
+
+/// Candle represents a trading period shape.
+/// Prices are provided as scaled positions along the y axis.
+#[derive(Clone)]
+pub struct Candle {
+    open: f32,
+    high: f32,
+    low: f32,
+    close: f32,
+    width: f32,
+    offset: f32,
+    style: CandlestickStyle,
+    fill_color: String,
+    stroke_color: String,
+}
+
+impl Candle {
+    /// Create a new Candle.
+    pub fn new(
+        open: f32,
+        high: f32,
+        low: f32,
+        close: f32,
+        width: f32,
+        offset: f32,
+        style: CandlestickStyle,
+    ) -> Self {
+        Self {
+            open,
+            high,
+            low,
+            close,
+            width,
+            offset,
+            style,
+            fill_color: String::new(),
+            stroke_color: String::new(),
+        }
+    }
+
+    /// Set candle fill color.
+    pub fn set_fill_color(mut self, fill_color: &str) -> Self {
+        self.fill_color = fill_color.to_string();
+        self
+    }
+
+    /// Set candle stroke color.
+    pub fn set_stroke_color(mut self, stroke_color: &str) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    fn line(
+        &self,
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        stroke_width: i32,
+    ) -> svg::node::element::Line {
+        svg::node::element::Line::new()
+            .set(X1_ATTR, x1)
+            .set(Y1_ATTR, y1)
+            .set(X2_ATTR, x2)
+            .set(Y2_ATTR, y2)
+            .set(STROKE_ATTR, self.stroke_color.as_ref())
+            .set(STROKE_WIDTH_ATTR, stroke_width)
+    }
+
+    /// Get candle SVG representation.
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new()
+            .set(TRANSFORM_ATTR, translate_x_y(self.offset, START))
+            .set(CLASS_ATTR, CLASS_CANDLE);
+        let center = self.width / 2_f32;
+
+        match self.style {
+            CandlestickStyle::Candlestick => {
+                res.append(self.line(center, self.high, center, self.low, DEFAULT_STROKE_WIDTH));
+
+                // Body is a vertical bar between open and close positions.
+                let body = Bar::new(
+                    self.open.min(self.close),
+                    self.open.max(self.close),
+                    START,
+                    self.width,
+                    START,
+                    Orientation::Vertical,
+                )
+                .set_fill_color(&self.fill_color)
+                .set_stroke_color(&self.stroke_color)
+                .set_label_visible(false);
+                res.append(body.to_svg(theme));
+            }
+            CandlestickStyle::Ohlc => {
+                res.append(self.line(
+                    center,
+                    self.high,
+                    center,
+                    self.low,
+                    DEFAULT_OHLC_STROKE_WIDTH,
+                ));
+                res.append(self.line(
+                    START,
+                    self.open,
+                    center,
+                    self.open,
+                    DEFAULT_OHLC_STROKE_WIDTH,
+                ));
+                res.append(self.line(
+                    center,
+                    self.close,
+                    self.width,
+                    self.close,
+                    DEFAULT_OHLC_STROKE_WIDTH,
+                ));
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{COLOR_HEX_GREEN_2, COLOR_HEX_GREEN_4};
+
+    #[test]
+    fn candle_basic() {
+        let expected_svg_group = r##"<g class="candle" transform="translate(10,0)">
+<line stroke="#00400e" stroke-width="1" x1="5" x2="5" y1="10" y2="90"/>
+<g class="bar" transform="translate(0,0)">
+<rect fill="#117401" height="40" shape-rendering="crispEdges" stroke="#00400e" stroke-width="1" width="10" x="0" y="30"/>
+</g>
+</g>"##;
+
+        let candle_svg = Candle::new(
+            70_f32,
+            10_f32,
+            90_f32,
+            30_f32,
+            10_f32,
+            10_f32,
+            CandlestickStyle::Candlestick,
+        )
+        .set_fill_color(COLOR_HEX_GREEN_4)
+        .set_stroke_color(COLOR_HEX_GREEN_2)
+        .to_svg(&Theme::default());
+        assert_eq!(candle_svg.to_string(), expected_svg_group);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::render::svg::*;
+use crate::Theme;
+use svg::Node;
+
+const DEFAULT_LABEL_VISIBLE: bool = false;
+
+/// Cell represents a colored rectangle of a matrix with an optional centered label.
+#[derive(Clone)]
+pub struct Cell {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    fill_color: String,
+    label: String,
+    label_color: String,
+    label_visible: bool,
+}
+
+impl Cell {
+    /// Create a new Cell.
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            fill_color: String::new(),
+            label: String::new(),
+            label_color: DEFAULT_FONT_COLOR.to_string(),
+            label_visible: DEFAULT_LABEL_VISIBLE,
+        }
+    }
+
+    /// Set cell fill color.
+    pub fn set_fill_color(mut self, fill_color: &str) -> Self {
+        self.fill_color = fill_color.to_string();
+        self
+    }
+
+    /// Set cell label and its color.
+    pub fn set_label(mut self, label: &str, label_color: &str) -> Self {
+        self.label = label.to_string();
+        self.label_color = label_color.to_string();
+        self
+    }
+
+    /// Set cell label visibility.
+    pub fn set_label_visible(mut self, label_visible: bool) -> Self {
+        self.label_visible = label_visible;
+        self
+    }
+
+    /// Get cell SVG representation.
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new()
+            .set(TRANSFORM_ATTR, translate_x_y(self.x, self.y))
+            .set(CLASS_ATTR, CLASS_CELL);
+
+        res.append(
+            svg::node::element::Rectangle::new()
+                .set(X_ATTR, START)
+                .set(Y_ATTR, START)
+                .set(WIDTH_ATTR, self.width)
+                .set(HEIGHT_ATTR, self.height)
+                .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
+                .set(FILL_ATTR, self.fill_color.as_ref()),
+        );
+
+        if self.label_visible {
+            res.append(
+                svg::node::element::Text::new()
+                    .set(X_ATTR, self.width / 2_f32)
+                    .set(Y_ATTR, self.height / 2_f32)
+                    .set(DY_ATTR, DEFAULT_DY)
+                    .set(TEXT_ANCHOR_ATTR, TEXT_ANCHOR_MIDDLE)
+                    .set(FONT_FAMILY_ATTR, theme.font_family())
+                    .set(FILL_ATTR, self.label_color.as_ref())
+                    .set(FONT_SIZE_ATTR, px(theme.label_font_size()))
+                    .add(svg::node::Text::new(&self.label)),
+            );
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cell_basic() {
+        let expected_svg_group = r##"<g class="cell" transform="translate(10,20)">
+<rect fill="#0e3569" height="20" shape-rendering="crispEdges" width="30" x="0" y="0"/>
+<text dy=".35em" fill="#ffffff" font-family="sans-serif" font-size="14px" text-anchor="middle" x="15" y="10">
+0.5
+</text>
+</g>"##;
+
+        let cell_svg = Cell::new(10_f32, 20_f32, 30_f32, 20_f32)
+            .set_fill_color("#0e3569")
+            .set_label("0.5", "#ffffff")
+            .set_label_visible(true)
+            .to_svg(&Theme::default());
+        assert_eq!(cell_svg.to_string(), expected_svg_group);
+    }
+}
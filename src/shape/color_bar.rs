@@ -0,0 +1,185 @@
+use crate::math::linear::interpolate;
+use crate::render::svg::*;
+use crate::{ColorScale, Orientation, Theme};
+use svg::Node;
+
+// Approximate width of a single label character for the default font size.
+const DEFAULT_CHAR_WIDTH: i32 = 8;
+const DEFAULT_LABEL_HEIGHT: i32 = 14;
+const DEFAULT_LABEL_GAP: i32 = 6;
+
+const DEFAULT_BAR_LENGTH: i32 = 150;
+const DEFAULT_BAR_THICKNESS: i32 = 14;
+
+// Gradient is drawn with solid segments so it's rendered the same way in every output format.
+const DEFAULT_SEGMENTS_COUNT: i32 = 50;
+
+/// ColorBar represents a gradient bar with labels of a color scale domain.
+/// Vertical bar has the domain end at the top and horizontal bar has it on the right.
+#[derive(Clone)]
+pub struct ColorBar {
+    color_scale: ColorScale,
+    orientation: Orientation,
+}
+
+impl ColorBar {
+    /// Create a new ColorBar.
+    pub fn new(color_scale: ColorScale, orientation: Orientation) -> Self {
+        Self {
+            color_scale,
+            orientation,
+        }
+    }
+
+    fn labels(&self) -> (String, String) {
+        (
+            self.color_scale.tick_label(self.color_scale.domain_start()),
+            self.color_scale.tick_label(self.color_scale.domain_end()),
+        )
+    }
+
+    /// Get color bar width including labels.
+    pub fn width(&self) -> i32 {
+        match self.orientation {
+            Orientation::Horizontal => DEFAULT_BAR_LENGTH,
+            Orientation::Vertical => {
+                let (start_label, end_label) = self.labels();
+                let label_chars = start_label.chars().count().max(end_label.chars().count());
+                DEFAULT_BAR_THICKNESS + DEFAULT_LABEL_GAP + label_chars as i32 * DEFAULT_CHAR_WIDTH
+            }
+        }
+    }
+
+    /// Get color bar height including labels.
+    pub fn height(&self) -> i32 {
+        match self.orientation {
+            Orientation::Horizontal => {
+                DEFAULT_BAR_THICKNESS + DEFAULT_LABEL_GAP + DEFAULT_LABEL_HEIGHT
+            }
+            Orientation::Vertical => DEFAULT_BAR_LENGTH + DEFAULT_LABEL_HEIGHT,
+        }
+    }
+
+    fn label(
+        theme: &Theme,
+        text: &str,
+        x: i32,
+        y: i32,
+        text_anchor: &str,
+    ) -> svg::node::element::Text {
+        svg::node::element::Text::new()
+            .set(X_ATTR, x)
+            .set(Y_ATTR, y)
+            .set(DY_ATTR, DEFAULT_DY)
+            .set(FILL_ATTR, theme.font_color())
+            .set(TEXT_ANCHOR_ATTR, text_anchor)
+            .set(FONT_SIZE_ATTR, px(theme.label_font_size()))
+            .set(FONT_FAMILY_ATTR, theme.font_family())
+            .add(svg::node::Text::new(text))
+    }
+
+    /// Get color bar SVG representation.
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_COLOR_BAR);
+
+        // Vertical bar leaves room for the labels that are centered at its ends.
+        let bar_start = match self.orientation {
+            Orientation::Horizontal => 0,
+            Orientation::Vertical => DEFAULT_LABEL_HEIGHT / 2,
+        };
+        let segment_length = DEFAULT_BAR_LENGTH as f32 / DEFAULT_SEGMENTS_COUNT as f32;
+        for i in 0..DEFAULT_SEGMENTS_COUNT {
+            let position = (i as f32 + 0.5_f32) / DEFAULT_SEGMENTS_COUNT as f32;
+            let offset = bar_start as f32 + i as f32 * segment_length;
+            let (x, y, width, height, value_position) = match self.orientation {
+                Orientation::Horizontal => (
+                    offset,
+                    START,
+                    segment_length,
+                    DEFAULT_BAR_THICKNESS as f32,
+                    position,
+                ),
+                Orientation::Vertical => (
+                    START,
+                    offset,
+                    DEFAULT_BAR_THICKNESS as f32,
+                    segment_length,
+                    1_f32 - position,
+                ),
+            };
+            let value = interpolate(
+                self.color_scale.domain_start(),
+                self.color_scale.domain_end(),
+                value_position,
+            );
+            res.append(
+                svg::node::element::Rectangle::new()
+                    .set(X_ATTR, x)
+                    .set(Y_ATTR, y)
+                    .set(WIDTH_ATTR, width)
+                    .set(HEIGHT_ATTR, height)
+                    .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
+                    .set(FILL_ATTR, self.color_scale.color(value).to_string()),
+            );
+        }
+
+        let (start_label, end_label) = self.labels();
+        match self.orientation {
+            Orientation::Horizontal => {
+                let y = DEFAULT_BAR_THICKNESS + DEFAULT_LABEL_GAP + DEFAULT_LABEL_HEIGHT / 2;
+                res.append(Self::label(theme, &start_label, 0, y, TEXT_ANCHOR_START));
+                res.append(Self::label(
+                    theme,
+                    &end_label,
+                    DEFAULT_BAR_LENGTH,
+                    y,
+                    TEXT_ANCHOR_END,
+                ));
+            }
+            Orientation::Vertical => {
+                let x = DEFAULT_BAR_THICKNESS + DEFAULT_LABEL_GAP;
+                res.append(Self::label(
+                    theme,
+                    &end_label,
+                    x,
+                    bar_start,
+                    TEXT_ANCHOR_START,
+                ));
+                res.append(Self::label(
+                    theme,
+                    &start_label,
+                    x,
+                    bar_start + DEFAULT_BAR_LENGTH,
+                    TEXT_ANCHOR_START,
+                ));
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_bar_basic() {
+        let color_bar = ColorBar::new(
+            ColorScale::new_sequential(0_f32, 100_f32),
+            Orientation::Vertical,
+        );
+        assert_eq!(color_bar.width(), 14 + 6 + 3 * 8);
+        assert_eq!(color_bar.height(), 150 + 14);
+
+        let svg = color_bar.to_svg(&Theme::default()).to_string();
+        assert!(svg.starts_with(
+            r##"<g class="color-bar">
+<rect fill="rgb(16,54,106)" height="3" shape-rendering="crispEdges" width="14" x="0" y="7"/>"##
+        ));
+        assert!(svg.ends_with(r##"<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="start" x="20" y="157">
+0
+</text>
+</g>"##));
+    }
+}
@@ -0,0 +1,226 @@
+use svg::node::element::path::Data;
+
+/// Curve contains available interpolations between adjacent points of lines and areas.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Curve {
+    /// Straight segments between points.
+    Linear,
+
+    /// Value changes at the x of the current point, so each step ends at the previous x.
+    StepBefore,
+
+    /// Value holds until the x of the next point, useful for counters and state timelines.
+    StepAfter,
+
+    /// Value changes halfway between adjacent points.
+    StepMiddle,
+
+    /// Cubic curve that keeps monotonicity of the data so it never overshoots points.
+    Monotone,
+
+    /// Uniform Catmull-Rom spline that passes through every point.
+    CatmullRom,
+
+    /// Cubic basis spline that smooths points and passes only through the first and the last one.
+    Basis,
+}
+
+/// Append curve segments through the provided points to the path data.
+/// The path either starts at the first point or continues to it with a straight segment.
+pub(crate) fn curve_path(mut data: Data, points: &[(f32, f32)], curve: Curve, start: bool) -> Data {
+    let first = match points.first() {
+        Some(first) => *first,
+        None => return data,
+    };
+    data = if start {
+        data.move_to(first)
+    } else {
+        data.line_to(first)
+    };
+
+    match curve {
+        Curve::Linear => {
+            for point in points.iter().skip(1) {
+                data = data.line_to(*point);
+            }
+        }
+        Curve::StepBefore => {
+            for pair in points.windows(2) {
+                data = data.line_to((pair[0].0, pair[1].1)).line_to(pair[1]);
+            }
+        }
+        Curve::StepAfter => {
+            for pair in points.windows(2) {
+                data = data.line_to((pair[1].0, pair[0].1)).line_to(pair[1]);
+            }
+        }
+        Curve::StepMiddle => {
+            for pair in points.windows(2) {
+                let middle_x = (pair[0].0 + pair[1].0) / 2_f32;
+                data = data
+                    .line_to((middle_x, pair[0].1))
+                    .line_to((middle_x, pair[1].1))
+                    .line_to(pair[1]);
+            }
+        }
+        Curve::Monotone => {
+            let tangents = monotone_tangents(points);
+            for (idx, pair) in points.windows(2).enumerate() {
+                let dx = (pair[1].0 - pair[0].0) / 3_f32;
+                data = data.cubic_curve_to((
+                    pair[0].0 + dx,
+                    pair[0].1 + dx * tangents[idx],
+                    pair[1].0 - dx,
+                    pair[1].1 - dx * tangents[idx + 1],
+                    pair[1].0,
+                    pair[1].1,
+                ));
+            }
+        }
+        Curve::CatmullRom => {
+            for idx in 1..points.len() {
+                let p0 = points[idx.saturating_sub(2)];
+                let p1 = points[idx - 1];
+                let p2 = points[idx];
+                let p3 = points[(idx + 1).min(points.len() - 1)];
+                data = data.cubic_curve_to((
+                    p1.0 + (p2.0 - p0.0) / 6_f32,
+                    p1.1 + (p2.1 - p0.1) / 6_f32,
+                    p2.0 - (p3.0 - p1.0) / 6_f32,
+                    p2.1 - (p3.1 - p1.1) / 6_f32,
+                    p2.0,
+                    p2.1,
+                ));
+            }
+        }
+        Curve::Basis => data = basis_path(data, points),
+    }
+
+    data
+}
+
+// Compute tangents of a monotone cubic curve with the Steffen method,
+// they are limited by adjacent slopes so segments never leave the range of their points.
+fn monotone_tangents(points: &[(f32, f32)]) -> Vec<f32> {
+    let slope = |p0: (f32, f32), p1: (f32, f32)| {
+        let h = p1.0 - p0.0;
+        if h == 0_f32 {
+            0_f32
+        } else {
+            (p1.1 - p0.1) / h
+        }
+    };
+
+    let count = points.len();
+    if count < 2 {
+        return vec![0_f32; count];
+    }
+    if count == 2 {
+        let s = slope(points[0], points[1]);
+        return vec![s, s];
+    }
+
+    let mut tangents = vec![0_f32; count];
+    for idx in 1..count - 1 {
+        let (p0, p1, p2) = (points[idx - 1], points[idx], points[idx + 1]);
+        let (h0, h1) = (p1.0 - p0.0, p2.0 - p1.0);
+        let (s0, s1) = (slope(p0, p1), slope(p1, p2));
+        let p = if h0 + h1 == 0_f32 {
+            0_f32
+        } else {
+            (s0 * h1 + s1 * h0) / (h0 + h1)
+        };
+        let limit = s0.abs().min(s1.abs()).min(0.5_f32 * p.abs());
+        tangents[idx] = (s0.signum() + s1.signum()) * limit;
+    }
+
+    // End tangents continue the parabola through the end point and its neighbour.
+    let end_tangent = |s: f32, t: f32| (3_f32 * s - t) / 2_f32;
+    tangents[0] = end_tangent(slope(points[0], points[1]), tangents[1]);
+    tangents[count - 1] = end_tangent(
+        slope(points[count - 2], points[count - 1]),
+        tangents[count - 2],
+    );
+
+    tangents
+}
+
+// Append cubic basis spline segments, the path is expected to be at the first point.
+fn basis_path(mut data: Data, points: &[(f32, f32)]) -> Data {
+    if points.len() == 2 {
+        return data.line_to(points[1]);
+    }
+    if points.len() < 2 {
+        return data;
+    }
+
+    let (p0, p1) = (points[0], points[1]);
+    data = data.line_to(((5_f32 * p0.0 + p1.0) / 6_f32, (5_f32 * p0.1 + p1.1) / 6_f32));
+
+    let segment = |data: Data, p0: (f32, f32), p1: (f32, f32), p2: (f32, f32)| {
+        data.cubic_curve_to((
+            (2_f32 * p0.0 + p1.0) / 3_f32,
+            (2_f32 * p0.1 + p1.1) / 3_f32,
+            (p0.0 + 2_f32 * p1.0) / 3_f32,
+            (p0.1 + 2_f32 * p1.1) / 3_f32,
+            (p0.0 + 4_f32 * p1.0 + p2.0) / 6_f32,
+            (p0.1 + 4_f32 * p1.1 + p2.1) / 6_f32,
+        ))
+    };
+    for triple in points.windows(3) {
+        data = segment(data, triple[0], triple[1], triple[2]);
+    }
+
+    // The last point is repeated so the spline ends exactly at it.
+    let last = points[points.len() - 1];
+    let before_last = points[points.len() - 2];
+    data = segment(data, before_last, last, last);
+
+    data.line_to(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn curve_steps() {
+        let points = [(0_f32, 0_f32), (10_f32, 10_f32)];
+        let path = |curve| curve_path(Data::new(), &points, curve, true);
+
+        assert_eq!(
+            svg::node::Value::from(path(Curve::Linear)).to_string(),
+            "M0,0 L10,10"
+        );
+        assert_eq!(
+            svg::node::Value::from(path(Curve::StepAfter)).to_string(),
+            "M0,0 L10,0 L10,10"
+        );
+        assert_eq!(
+            svg::node::Value::from(path(Curve::StepBefore)).to_string(),
+            "M0,0 L0,10 L10,10"
+        );
+        assert_eq!(
+            svg::node::Value::from(path(Curve::StepMiddle)).to_string(),
+            "M0,0 L5,0 L5,10 L10,10"
+        );
+    }
+
+    #[test]
+    fn curve_monotone_tangents() {
+        // Flat extremum and plateau keep zero tangents so the curve doesn't overshoot them.
+        let points = [
+            (0_f32, 0_f32),
+            (1_f32, 10_f32),
+            (2_f32, 0_f32),
+            (3_f32, 0_f32),
+            (4_f32, 5_f32),
+        ];
+        let tangents = monotone_tangents(&points);
+        assert_eq!(tangents[1], 0_f32);
+        assert_eq!(tangents[2], 0_f32);
+        assert_eq!(tangents[3], 0_f32);
+        assert_eq!(tangents[0], 15_f32);
+        assert_eq!(tangents[4], 7.5_f32);
+    }
+}
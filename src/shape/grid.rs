@@ -0,0 +1,199 @@
+use crate::render::svg::*;
+use crate::shape::axis::{Axis, AxisPosition};
+use crate::Color;
+use svg::Node;
+
+const DEFAULT_MAJOR_STROKE_COLOR: &str = "#e0e0e0";
+const DEFAULT_MINOR_STROKE_COLOR: &str = "#f0f0f0";
+
+/// GridStyle contains stroke settings for grid lines.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridStyle {
+    stroke_color: String,
+    stroke_width: i32,
+    stroke_dasharray: Vec<i32>,
+}
+
+impl GridStyle {
+    /// Create a new GridStyle with settings of major grid lines.
+    pub fn new() -> Self {
+        Self {
+            stroke_color: DEFAULT_MAJOR_STROKE_COLOR.to_string(),
+            stroke_width: DEFAULT_STROKE_WIDTH,
+            stroke_dasharray: Vec::new(),
+        }
+    }
+
+    /// Create a new GridStyle with settings of minor grid lines.
+    pub fn new_minor() -> Self {
+        Self::new().set_stroke_color(Color::new_from_hex(DEFAULT_MINOR_STROKE_COLOR))
+    }
+
+    /// Set grid lines stroke color.
+    pub fn set_stroke_color(mut self, stroke_color: Color) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    /// Set grid lines stroke width.
+    pub fn set_stroke_width(mut self, stroke_width: i32) -> Self {
+        self.stroke_width = stroke_width;
+        self
+    }
+
+    /// Set grid lines dash pattern as lengths of alternating dashes and gaps.
+    /// Lines are solid if the pattern is empty.
+    pub fn set_stroke_dasharray(mut self, stroke_dasharray: &[i32]) -> Self {
+        self.stroke_dasharray = stroke_dasharray.to_vec();
+        self
+    }
+
+    fn apply(&self, line: svg::node::element::Line) -> svg::node::element::Line {
+        let line = line
+            .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
+            .set(STROKE_ATTR, self.stroke_color.as_ref())
+            .set(STROKE_WIDTH_ATTR, self.stroke_width);
+        if self.stroke_dasharray.is_empty() {
+            return line;
+        }
+
+        let dasharray = self
+            .stroke_dasharray
+            .iter()
+            .map(|dash| dash.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        line.set(STROKE_DASHARRAY_ATTR, dasharray)
+    }
+}
+
+impl Default for GridStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Grid represents horizontal and vertical lines placed at axes ticks.
+pub struct Grid {
+    view_width: f32,
+    view_height: f32,
+    vertical_offsets: Vec<f32>,
+    horizontal_offsets: Vec<f32>,
+    major_style: GridStyle,
+    minor_style: Option<GridStyle>,
+}
+
+impl Grid {
+    /// Create a new Grid.
+    pub fn new(
+        view_width: i32,
+        view_height: i32,
+        major_style: GridStyle,
+        minor_style: Option<GridStyle>,
+    ) -> Self {
+        Self {
+            view_width: view_width as f32,
+            view_height: view_height as f32,
+            vertical_offsets: Vec::new(),
+            horizontal_offsets: Vec::new(),
+            major_style,
+            minor_style,
+        }
+    }
+
+    /// Add lines for ticks of the provided axis.
+    /// Horizontal axes produce vertical lines and vertical axes produce horizontal lines.
+    pub fn add_axis(&mut self, axis: &Axis) {
+        let offsets = match axis.position() {
+            AxisPosition::Top | AxisPosition::Bottom => &mut self.vertical_offsets,
+            AxisPosition::Left | AxisPosition::Right => &mut self.horizontal_offsets,
+        };
+        for tick_offset in axis.tick_offsets() {
+            if !offsets.contains(&tick_offset) {
+                offsets.push(tick_offset);
+            }
+        }
+        offsets.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    }
+
+    // Get offsets of minor lines that are placed in the middle between major lines.
+    fn minor_offsets(offsets: &[f32]) -> Vec<f32> {
+        offsets
+            .windows(2)
+            .map(|pair| (pair[0] + pair[1]) / 2_f32)
+            .collect()
+    }
+
+    fn vertical_line(x: f32, height: f32) -> svg::node::element::Line {
+        svg::node::element::Line::new()
+            .set(X1_ATTR, x)
+            .set(X2_ATTR, x)
+            .set(Y1_ATTR, START)
+            .set(Y2_ATTR, height)
+    }
+
+    fn horizontal_line(y: f32, width: f32) -> svg::node::element::Line {
+        svg::node::element::Line::new()
+            .set(X1_ATTR, START)
+            .set(X2_ATTR, width)
+            .set(Y1_ATTR, y)
+            .set(Y2_ATTR, y)
+    }
+
+    /// Get grid SVG representation.
+    pub fn to_svg(&self) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_GRID);
+
+        // Minor lines are drawn first so major lines are placed over them.
+        if let Some(ref minor_style) = self.minor_style {
+            for x in Self::minor_offsets(&self.vertical_offsets) {
+                res.append(minor_style.apply(Self::vertical_line(x, self.view_height)));
+            }
+            for y in Self::minor_offsets(&self.horizontal_offsets) {
+                res.append(minor_style.apply(Self::horizontal_line(y, self.view_width)));
+            }
+        }
+
+        for x in self.vertical_offsets.iter() {
+            res.append(
+                self.major_style
+                    .apply(Self::vertical_line(*x, self.view_height)),
+            );
+        }
+        for y in self.horizontal_offsets.iter() {
+            res.append(
+                self.major_style
+                    .apply(Self::horizontal_line(*y, self.view_width)),
+            );
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BandScale;
+
+    #[test]
+    fn grid_basic() {
+        let expected_svg_group = r##"<g class="grid">
+<line shape-rendering="crispEdges" stroke="#f0f0f0" stroke-dasharray="2,2" stroke-width="1" x1="0" x2="100" y1="50" y2="50"/>
+<line shape-rendering="crispEdges" stroke="#e0e0e0" stroke-width="2" x1="0" x2="100" y1="25" y2="25"/>
+<line shape-rendering="crispEdges" stroke="#e0e0e0" stroke-width="2" x1="0" x2="100" y1="75" y2="75"/>
+</g>"##;
+
+        let scale = BandScale::new(vec!["A".to_string(), "B".to_string()], 0, 100)
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+        let axis = Axis::new(&scale, AxisPosition::Left, 100, 100);
+        let major_style = GridStyle::new().set_stroke_width(2);
+        let minor_style = GridStyle::new_minor().set_stroke_dasharray(&[2, 2]);
+        let mut grid = Grid::new(100, 100, major_style, Some(minor_style));
+        grid.add_axis(&axis);
+        grid.add_axis(&axis);
+
+        assert_eq!(grid.to_svg().to_string(), expected_svg_group);
+    }
+}
@@ -0,0 +1,346 @@
+use crate::render::svg::*;
+use crate::shape::color_bar::ColorBar;
+use crate::shape::point::{Point, PointType};
+use crate::{Orientation, Theme};
+use svg::Node;
+
+// Approximate width of a single label character for the default font size.
+const DEFAULT_CHAR_WIDTH: i32 = 8;
+
+const DEFAULT_ENTRY_HEIGHT: i32 = 22;
+const DEFAULT_ENTRY_GAP: i32 = 16;
+
+const DEFAULT_MARKER_WIDTH: i32 = 20;
+const DEFAULT_MARKER_SQUARE_SIZE: i32 = 14;
+const DEFAULT_MARKER_POINT_SIZE: i32 = 5;
+const DEFAULT_MARKER_LINE_STROKE_WIDTH: i32 = 2;
+const DEFAULT_MARKER_LABEL_GAP: i32 = 6;
+
+/// LegendPosition contains available positions of a chart legend.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LegendPosition {
+    /// Legend is placed above the views, under the title.
+    Top,
+
+    /// Legend is placed to the right of the views.
+    Right,
+
+    /// Legend is placed below the views.
+    Bottom,
+
+    /// Legend is placed inside the top left corner of the views.
+    InsideTopLeft,
+
+    /// Legend is placed inside the top right corner of the views.
+    InsideTopRight,
+
+    /// Legend is placed inside the bottom left corner of the views.
+    InsideBottomLeft,
+
+    /// Legend is placed inside the bottom right corner of the views.
+    InsideBottomRight,
+}
+
+/// LegendMarker contains available markers of legend entries.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LegendMarker {
+    /// Filled square that is used for bars and areas.
+    Square,
+
+    /// Line that is used for lines without points.
+    Line,
+
+    /// Point of the provided type that is used for scatters.
+    Point(PointType),
+
+    /// Line with a point of the provided type in the middle that is used for lines with points.
+    LinePoint(PointType),
+}
+
+/// LegendEntry represents a single named series in a chart legend.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegendEntry {
+    label: String,
+    marker: LegendMarker,
+    fill_color: String,
+    stroke_color: String,
+    palette_series: Option<usize>,
+}
+
+impl LegendEntry {
+    /// Create a new LegendEntry.
+    pub fn new(label: &str, marker: LegendMarker, fill_color: &str, stroke_color: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            marker,
+            fill_color: fill_color.to_string(),
+            stroke_color: stroke_color.to_string(),
+            palette_series: None,
+        }
+    }
+
+    /// Take fill and stroke colors from the theme palette color of the provided series.
+    pub fn set_palette_series(mut self, palette_series: usize) -> Self {
+        self.palette_series = Some(palette_series);
+        self
+    }
+
+    // Get a copy of the entry with its palette series moved by the provided offset.
+    pub(crate) fn offset_palette_series(&self, offset: usize) -> Self {
+        let mut entry = self.clone();
+        entry.palette_series = self.palette_series.map(|series| series + offset);
+        entry
+    }
+
+    /// Get entry label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get entry marker.
+    pub fn marker(&self) -> LegendMarker {
+        self.marker
+    }
+
+    /// Get entry fill color.
+    pub fn fill_color(&self) -> &str {
+        &self.fill_color
+    }
+
+    /// Get entry stroke color.
+    pub fn stroke_color(&self) -> &str {
+        &self.stroke_color
+    }
+
+    // Get approximate entry width including marker and label.
+    fn width(&self) -> i32 {
+        DEFAULT_MARKER_WIDTH
+            + DEFAULT_MARKER_LABEL_GAP
+            + self.label.chars().count() as i32 * DEFAULT_CHAR_WIDTH
+    }
+
+    fn marker_to_svg(&self, theme: &Theme) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new();
+        let center = DEFAULT_MARKER_WIDTH / 2;
+        let palette_color = theme.palette_color(self.palette_series);
+        let fill_color = palette_color.unwrap_or(&self.fill_color);
+        let stroke_color = palette_color.unwrap_or(&self.stroke_color);
+
+        match self.marker {
+            LegendMarker::Square => {
+                res.append(
+                    svg::node::element::Rectangle::new()
+                        .set(X_ATTR, center - DEFAULT_MARKER_SQUARE_SIZE / 2)
+                        .set(Y_ATTR, -DEFAULT_MARKER_SQUARE_SIZE / 2)
+                        .set(WIDTH_ATTR, DEFAULT_MARKER_SQUARE_SIZE)
+                        .set(HEIGHT_ATTR, DEFAULT_MARKER_SQUARE_SIZE)
+                        .set(FILL_ATTR, fill_color)
+                        .set(STROKE_ATTR, stroke_color),
+                );
+            }
+            LegendMarker::Line | LegendMarker::LinePoint(_) => {
+                res.append(
+                    svg::node::element::Line::new()
+                        .set(X1_ATTR, START)
+                        .set(Y1_ATTR, START)
+                        .set(X2_ATTR, DEFAULT_MARKER_WIDTH)
+                        .set(Y2_ATTR, START)
+                        .set(STROKE_WIDTH_ATTR, DEFAULT_MARKER_LINE_STROKE_WIDTH)
+                        .set(STROKE_ATTR, stroke_color),
+                );
+            }
+            LegendMarker::Point(_) => {}
+        }
+
+        if let LegendMarker::Point(point_type) | LegendMarker::LinePoint(point_type) = self.marker {
+            let point = Point::new(
+                center as f32,
+                START,
+                point_type,
+                DEFAULT_MARKER_POINT_SIZE,
+                "",
+                fill_color,
+                stroke_color,
+            )
+            .set_label_visible(false);
+            res.append(point.to_svg(theme));
+        }
+
+        res
+    }
+}
+
+/// Legend represents a list of legend entries placed in rows or columns.
+/// Color bars are placed under the entries.
+pub struct Legend {
+    entries: Vec<LegendEntry>,
+    offsets: Vec<(i32, i32)>,
+    color_bars: Vec<(ColorBar, i32)>,
+    width: i32,
+    height: i32,
+}
+
+impl Legend {
+    /// Create a new Legend.
+    /// Entries of a horizontal legend are wrapped into a new row when they don't fit max_width.
+    pub fn new(entries: Vec<LegendEntry>, orientation: Orientation, max_width: i32) -> Self {
+        let mut offsets = Vec::with_capacity(entries.len());
+        let mut width = 0;
+        let mut x = 0;
+        let mut y = 0;
+
+        for entry in entries.iter() {
+            let entry_width = entry.width();
+            match orientation {
+                Orientation::Horizontal => {
+                    if x > 0 && x + entry_width > max_width {
+                        x = 0;
+                        y += DEFAULT_ENTRY_HEIGHT;
+                    }
+                    offsets.push((x, y));
+                    width = width.max(x + entry_width);
+                    x += entry_width + DEFAULT_ENTRY_GAP;
+                }
+                Orientation::Vertical => {
+                    offsets.push((x, y));
+                    width = width.max(entry_width);
+                    y += DEFAULT_ENTRY_HEIGHT;
+                }
+            }
+        }
+
+        let height = match (orientation, entries.is_empty()) {
+            (_, true) => 0,
+            (Orientation::Horizontal, false) => y + DEFAULT_ENTRY_HEIGHT,
+            (Orientation::Vertical, false) => y,
+        };
+
+        Self {
+            entries,
+            offsets,
+            color_bars: Vec::new(),
+            width,
+            height,
+        }
+    }
+
+    /// Add a color bar under the existing entries and color bars.
+    pub fn add_color_bar(mut self, color_bar: ColorBar) -> Self {
+        if self.height > 0 {
+            self.height += DEFAULT_ENTRY_GAP;
+        }
+        self.width = self.width.max(color_bar.width());
+        let offset = self.height;
+        self.height += color_bar.height();
+        self.color_bars.push((color_bar, offset));
+        self
+    }
+
+    /// Check if legend has no entries and color bars.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty() && self.color_bars.is_empty()
+    }
+
+    /// Get legend width.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Get legend height.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Get legend SVG representation.
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_LEGEND);
+
+        for (entry, (x, y)) in self.entries.iter().zip(self.offsets.iter()) {
+            let entry_group = svg::node::element::Group::new()
+                .set(CLASS_ATTR, CLASS_LEGEND_ENTRY)
+                .set(
+                    TRANSFORM_ATTR,
+                    translate_x_y(*x, *y + DEFAULT_ENTRY_HEIGHT / 2),
+                )
+                .add(entry.marker_to_svg(theme))
+                .add(
+                    svg::node::element::Text::new()
+                        .set(X_ATTR, DEFAULT_MARKER_WIDTH + DEFAULT_MARKER_LABEL_GAP)
+                        .set(Y_ATTR, START)
+                        .set(DY_ATTR, DEFAULT_DY)
+                        .set(FILL_ATTR, theme.font_color())
+                        .set(TEXT_ANCHOR_ATTR, TEXT_ANCHOR_START)
+                        .set(FONT_SIZE_ATTR, px(theme.label_font_size()))
+                        .set(FONT_FAMILY_ATTR, theme.font_family())
+                        .add(svg::node::Text::new(entry.label())),
+                );
+            res.append(entry_group);
+        }
+
+        for (color_bar, offset) in self.color_bars.iter() {
+            let mut color_bar_group = color_bar.to_svg(theme);
+            color_bar_group.assign(TRANSFORM_ATTR, translate_x_y(0, *offset));
+            res.append(color_bar_group);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legend_basic() {
+        let expected_svg_group = r##"<g class="legend">
+<g class="legend-entry" transform="translate(0,11)">
+<g>
+<rect fill="#5095e5" height="14" stroke="#1960b2" width="14" x="3" y="-7"/>
+</g>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="start" x="26" y="0">
+A
+</text>
+</g>
+<g class="legend-entry" transform="translate(0,33)">
+<g>
+<line stroke="#1960b2" stroke-width="2" x1="0" x2="20" y1="0" y2="0"/>
+<g class="point" transform="translate(10,0)">
+<circle cx="0" cy="0" fill="#5095e5" r="5" stroke="#1960b2"/>
+</g>
+</g>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="start" x="26" y="0">
+B
+</text>
+</g>
+</g>"##;
+
+        let entries = vec![
+            LegendEntry::new("A", LegendMarker::Square, "#5095e5", "#1960b2"),
+            LegendEntry::new(
+                "B",
+                LegendMarker::LinePoint(PointType::Circle),
+                "#5095e5",
+                "#1960b2",
+            ),
+        ];
+
+        // Both entries fit into a single row.
+        let legend = Legend::new(entries.clone(), Orientation::Horizontal, 100);
+        assert_eq!(legend.width(), 34 + 16 + 34);
+        assert_eq!(legend.height(), 22);
+
+        // Second entry is wrapped into a new row.
+        let legend = Legend::new(entries.clone(), Orientation::Horizontal, 60);
+        assert_eq!(legend.width(), 34);
+        assert_eq!(legend.height(), 44);
+
+        let legend = Legend::new(entries, Orientation::Vertical, 0);
+        assert_eq!(legend.width(), 34);
+        assert_eq!(legend.height(), 44);
+        assert_eq!(
+            legend.to_svg(&Theme::default()).to_string(),
+            expected_svg_group
+        );
+    }
+}
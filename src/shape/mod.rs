@@ -3,4 +3,12 @@ pub(crate) mod axis;
 pub(crate) mod axis_line;
 pub(crate) mod axis_tick;
 pub(crate) mod bar;
+pub(crate) mod box_plot;
+pub(crate) mod candle;
+pub(crate) mod cell;
+pub(crate) mod color_bar;
+pub(crate) mod curve;
+pub(crate) mod grid;
+pub(crate) mod legend;
 pub(crate) mod point;
+pub(crate) mod sector;
@@ -1,4 +1,5 @@
 use crate::render::svg::*;
+use crate::Theme;
 use svg::Node;
 
 const DEFAULT_LABEL_VISIBLE: bool = true;
@@ -14,8 +15,6 @@ const DEFAULT_Y_LABEL_HORIZONTAL: i32 = 0;
 const DEFAULT_Y_LABEL_VERTICAL: i32 = 12;
 const DEFAULT_Y_LABEL_BETWEEN: i32 = 8;
 
-const DEFAULT_FONT_SIZE: &str = "14px";
-
 const DEFAULT_POINT_VISIBLE: bool = true;
 
 /// PointType contains available types of points.
@@ -53,6 +52,7 @@ pub struct Point {
     y_label: String,
     fill_color: String,
     stroke_color: String,
+    palette_series: Option<usize>,
     label_text_anchor: String,
     label_x_attr: i32,
     label_y_attr: i32,
@@ -79,6 +79,7 @@ impl Point {
             y_label: y_label.to_string(),
             fill_color: fill_color.to_string(),
             stroke_color: stroke_color.to_string(),
+            palette_series: None,
             label_visible: DEFAULT_LABEL_VISIBLE,
             label_position: DEFAULT_LABEL_POSITION,
             label_text_anchor: Self::label_text_anchor(DEFAULT_LABEL_POSITION),
@@ -93,6 +94,12 @@ impl Point {
         self
     }
 
+    /// Take fill and stroke colors from the theme palette color of the provided series.
+    pub fn set_palette_series(mut self, palette_series: usize) -> Self {
+        self.palette_series = Some(palette_series);
+        self
+    }
+
     /// Set custom x for label.
     pub fn set_x_label(mut self, x_label: &str) -> Self {
         self.x_label = x_label.to_string();
@@ -118,6 +125,7 @@ impl Point {
     pub fn x(&self) -> f32 {
         self.x
     }
+
     /// Get y x value of a point.
     pub fn y(&self) -> f32 {
         self.y
@@ -164,10 +172,13 @@ impl Point {
     }
 
     /// Get point SVG representation.
-    pub fn to_svg(&self) -> svg::node::element::Group {
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
         let mut res = svg::node::element::Group::new()
             .set(TRANSFORM_ATTR, translate_x_y(self.x, self.y))
             .set(CLASS_ATTR, CLASS_POINT);
+        let palette_color = theme.palette_color(self.palette_series);
+        let fill_color = palette_color.unwrap_or(&self.fill_color);
+        let stroke_color = palette_color.unwrap_or(&self.stroke_color);
 
         // Draw point if needed.
         if self.point_visible {
@@ -178,19 +189,19 @@ impl Point {
                             .set(CX_ATTR, START)
                             .set(CY_ATTR, START)
                             .set(R_ATTR, self.size)
-                            .set(FILL_ATTR, self.fill_color.as_ref())
-                            .set(STROKE_ATTR, self.stroke_color.as_ref()),
+                            .set(FILL_ATTR, fill_color)
+                            .set(STROKE_ATTR, stroke_color),
                     );
                 }
                 PointType::Square => {
                     res.append(
                         svg::node::element::Rectangle::new()
-                            .set(X_ATTR, -(self.size as i32))
-                            .set(Y_ATTR, -(self.size as i32))
+                            .set(X_ATTR, -self.size)
+                            .set(Y_ATTR, -self.size)
                             .set(WIDTH_ATTR, 2 * self.size)
                             .set(HEIGHT_ATTR, 2 * self.size)
-                            .set(FILL_ATTR, self.fill_color.as_ref())
-                            .set(STROKE_ATTR, self.stroke_color.as_ref()),
+                            .set(FILL_ATTR, fill_color)
+                            .set(STROKE_ATTR, stroke_color),
                     );
                 }
                 PointType::X => {
@@ -198,21 +209,21 @@ impl Point {
                         svg::node::element::Group::new()
                             .add(
                                 svg::node::element::Line::new()
-                                    .set(X1_ATTR, -(self.size as i32))
-                                    .set(Y1_ATTR, -(self.size as i32))
+                                    .set(X1_ATTR, -self.size)
+                                    .set(Y1_ATTR, -self.size)
                                     .set(X2_ATTR, self.size)
                                     .set(Y2_ATTR, self.size)
                                     .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH)
-                                    .set(STROKE_ATTR, self.stroke_color.as_ref()),
+                                    .set(STROKE_ATTR, stroke_color),
                             )
                             .add(
                                 svg::node::element::Line::new()
                                     .set(X1_ATTR, self.size)
-                                    .set(Y1_ATTR, -(self.size as i32))
-                                    .set(X2_ATTR, -(self.size as i32))
+                                    .set(Y1_ATTR, -self.size)
+                                    .set(X2_ATTR, -self.size)
                                     .set(Y2_ATTR, self.size)
                                     .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH)
-                                    .set(STROKE_ATTR, self.stroke_color.as_ref()),
+                                    .set(STROKE_ATTR, stroke_color),
                             ),
                     );
                 }
@@ -227,16 +238,16 @@ impl Point {
             if self.x_label.is_empty() {
                 label = svg::node::element::Text::new()
                     .set(DY_ATTR, DEFAULT_DY)
-                    .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
-                    .set(FILL_ATTR, DEFAULT_FONT_COLOR)
-                    .set(FONT_SIZE_ATTR, DEFAULT_FONT_SIZE)
+                    .set(FONT_FAMILY_ATTR, theme.font_family())
+                    .set(FILL_ATTR, theme.font_color())
+                    .set(FONT_SIZE_ATTR, px(theme.label_font_size()))
                     .add(svg::node::Text::new(self.y_label.to_string()));
             } else {
                 label = svg::node::element::Text::new()
                     .set(DY_ATTR, DEFAULT_DY)
-                    .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
-                    .set(FILL_ATTR, DEFAULT_FONT_COLOR)
-                    .set(FONT_SIZE_ATTR, DEFAULT_FONT_SIZE)
+                    .set(FONT_FAMILY_ATTR, theme.font_family())
+                    .set(FILL_ATTR, theme.font_color())
+                    .set(FONT_SIZE_ATTR, px(theme.label_font_size()))
                     .add(svg::node::Text::new(pair_x_y(&self.x_label, &self.y_label)));
             }
 
@@ -274,7 +285,7 @@ thirty
             "#8a87f6",
         )
         .set_label_position(PointLabelPosition::BottomLeft)
-        .to_svg();
+        .to_svg(&Theme::default());
         assert_eq!(point_svg.to_string(), expected_svg_group);
     }
 }
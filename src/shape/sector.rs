@@ -0,0 +1,281 @@
+use crate::render::svg::*;
+use crate::Theme;
+use std::f32::consts::PI;
+use svg::Node;
+
+const DEFAULT_LABEL_VISIBLE: bool = true;
+const DEFAULT_LABEL_POSITION: PieLabelPosition = PieLabelPosition::Inside;
+
+const DEFAULT_LEADER_LINE_RADIAL_LENGTH: f32 = 12_f32;
+const DEFAULT_LEADER_LINE_HORIZONTAL_LENGTH: f32 = 10_f32;
+const DEFAULT_LEADER_LINE_LABEL_GAP: f32 = 4_f32;
+
+/// PieLabelPosition contains available types of pie slice label positions.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PieLabelPosition {
+    /// Label is placed in the middle of a slice.
+    Inside,
+
+    /// Label is placed outside of a slice and connected to it with a leader line.
+    Outside,
+}
+
+/// PieLabelFormat contains available formats of pie slice labels.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PieLabelFormat {
+    /// Slice label is used.
+    Label,
+
+    /// Slice value is used, it's formatted with the value format of the pie view.
+    Value,
+
+    /// Slice percentage of the sum of all values is used.
+    Percentage,
+
+    /// Slice label is followed by its percentage.
+    LabelAndPercentage,
+}
+
+/// Sector represents an annular sector shape that is used for pie slices.
+/// Angles are measured in radians clockwise from the top.
+#[derive(Clone)]
+pub struct Sector {
+    start_angle: f32,
+    end_angle: f32,
+    inner_radius: f32,
+    outer_radius: f32,
+    fill_color: String,
+    stroke_color: String,
+    palette_series: Option<usize>,
+    label: String,
+    label_visible: bool,
+    label_position: PieLabelPosition,
+}
+
+impl Sector {
+    /// Create a new Sector.
+    pub fn new(start_angle: f32, end_angle: f32, inner_radius: f32, outer_radius: f32) -> Self {
+        Self {
+            start_angle,
+            end_angle,
+            inner_radius,
+            outer_radius,
+            fill_color: String::new(),
+            stroke_color: String::new(),
+            palette_series: None,
+            label: String::new(),
+            label_visible: DEFAULT_LABEL_VISIBLE,
+            label_position: DEFAULT_LABEL_POSITION,
+        }
+    }
+
+    /// Set sector fill color.
+    pub fn set_fill_color(mut self, fill_color: &str) -> Self {
+        self.fill_color = fill_color.to_string();
+        self
+    }
+
+    /// Set sector stroke color.
+    pub fn set_stroke_color(mut self, stroke_color: &str) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    /// Take fill color from the theme palette color of the provided series.
+    pub fn set_palette_series(mut self, palette_series: usize) -> Self {
+        self.palette_series = Some(palette_series);
+        self
+    }
+
+    /// Set sector label.
+    pub fn set_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// Set sector label visibility.
+    pub fn set_label_visible(mut self, label_visible: bool) -> Self {
+        self.label_visible = label_visible;
+        self
+    }
+
+    /// Set sector label position.
+    pub fn set_label_position(mut self, label_position: PieLabelPosition) -> Self {
+        self.label_position = label_position;
+        self
+    }
+
+    // Get coordinates of a point at the provided angle and radius.
+    // Rounding errors of trigonometric functions are removed for points on the axes.
+    fn polar(angle: f32, radius: f32) -> (f32, f32) {
+        let snap = |v: f32| if v.abs() < 1e-4 { 0_f32 } else { v };
+        (snap(radius * angle.sin()), snap(-radius * angle.cos()))
+    }
+
+    fn path_data(&self) -> svg::node::element::path::Data {
+        let angle = self.end_angle - self.start_angle;
+
+        // Full circle can't be drawn with a single arc so it's split in halves.
+        let angles = if angle >= 2_f32 * PI - f32::EPSILON {
+            vec![
+                self.start_angle,
+                self.start_angle + PI,
+                self.start_angle + 2_f32 * PI,
+            ]
+        } else {
+            vec![self.start_angle, self.end_angle]
+        };
+        let large_arc = if angle > PI && angles.len() == 2 {
+            1
+        } else {
+            0
+        };
+
+        let mut data = svg::node::element::path::Data::new()
+            .move_to(Self::polar(angles[0], self.outer_radius));
+        for end in angles.iter().skip(1) {
+            let (x, y) = Self::polar(*end, self.outer_radius);
+            data = data.elliptical_arc_to((
+                self.outer_radius,
+                self.outer_radius,
+                0,
+                large_arc,
+                1,
+                x,
+                y,
+            ));
+        }
+
+        if self.inner_radius > 0_f32 {
+            data = data.line_to(Self::polar(
+                *angles.last().unwrap_or(&0_f32),
+                self.inner_radius,
+            ));
+            for end in angles.iter().rev().skip(1) {
+                let (x, y) = Self::polar(*end, self.inner_radius);
+                data = data.elliptical_arc_to((
+                    self.inner_radius,
+                    self.inner_radius,
+                    0,
+                    large_arc,
+                    0,
+                    x,
+                    y,
+                ));
+            }
+        } else if angles.len() == 2 {
+            data = data.line_to((START, START));
+        }
+
+        data.close()
+    }
+
+    fn label_to_svg(&self, theme: &Theme) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new();
+        let middle_angle = (self.start_angle + self.end_angle) / 2_f32;
+
+        let mut label = svg::node::element::Text::new()
+            .set(DY_ATTR, DEFAULT_DY)
+            .set(FONT_FAMILY_ATTR, theme.font_family())
+            .set(FILL_ATTR, theme.font_color())
+            .set(FONT_SIZE_ATTR, px(theme.label_font_size()))
+            .add(svg::node::Text::new(&self.label));
+
+        match self.label_position {
+            PieLabelPosition::Inside => {
+                let (x, y) = Self::polar(
+                    middle_angle,
+                    (self.inner_radius + self.outer_radius) / 2_f32,
+                );
+                label.assign(X_ATTR, x);
+                label.assign(Y_ATTR, y);
+                label.assign(TEXT_ANCHOR_ATTR, TEXT_ANCHOR_MIDDLE);
+            }
+            PieLabelPosition::Outside => {
+                // Leader line goes out of the slice and turns horizontally to the label.
+                let start = Self::polar(middle_angle, self.outer_radius);
+                let elbow = Self::polar(
+                    middle_angle,
+                    self.outer_radius + DEFAULT_LEADER_LINE_RADIAL_LENGTH,
+                );
+                let (direction, text_anchor) = if elbow.0 >= 0_f32 {
+                    (1_f32, TEXT_ANCHOR_START)
+                } else {
+                    (-1_f32, TEXT_ANCHOR_END)
+                };
+                let end = (
+                    elbow.0 + direction * DEFAULT_LEADER_LINE_HORIZONTAL_LENGTH,
+                    elbow.1,
+                );
+
+                let leader_line = svg::node::element::Path::new()
+                    .set(FILL_ATTR, FILL_NONE)
+                    .set(STROKE_ATTR, theme.axis_color())
+                    .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH)
+                    .set(
+                        D_ATTR,
+                        svg::node::element::path::Data::new()
+                            .move_to(start)
+                            .line_to(elbow)
+                            .line_to(end),
+                    );
+                res.append(leader_line);
+
+                label.assign(X_ATTR, end.0 + direction * DEFAULT_LEADER_LINE_LABEL_GAP);
+                label.assign(Y_ATTR, end.1);
+                label.assign(TEXT_ANCHOR_ATTR, text_anchor);
+            }
+        }
+        res.append(label);
+
+        res
+    }
+
+    /// Get sector SVG representation.
+    pub fn to_svg(&self, theme: &Theme) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_SECTOR);
+
+        let sector = svg::node::element::Path::new()
+            .set(
+                FILL_ATTR,
+                theme
+                    .palette_color(self.palette_series)
+                    .unwrap_or(&self.fill_color),
+            )
+            .set(STROKE_ATTR, self.stroke_color.as_ref())
+            .set(D_ATTR, self.path_data());
+        res.append(sector);
+
+        if self.label_visible && !self.label.is_empty() {
+            res.append(self.label_to_svg(theme));
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sector_basic() {
+        let expected_svg_group = r##"<g class="sector">
+<path d="M0,-10 A10,10,0,0,1,10,0 L5,0 A5,5,0,0,0,0,-5 z" fill="#5095e5" stroke="#ffffff"/>
+<g>
+<path d="M7.071068,-7.071068 L15.556349,-15.556349 L25.556349,-15.556349" fill="none" stroke="#bbbbbb" stroke-width="1"/>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="start" x="29.556349" y="-15.556349">
+25%
+</text>
+</g>
+</g>"##;
+
+        let sector_svg = Sector::new(0_f32, PI / 2_f32, 5_f32, 10_f32)
+            .set_fill_color("#5095e5")
+            .set_stroke_color("#ffffff")
+            .set_label("25%")
+            .set_label_position(PieLabelPosition::Outside)
+            .to_svg(&Theme::default());
+        assert_eq!(sector_svg.to_string(), expected_svg_group);
+    }
+}
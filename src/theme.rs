@@ -0,0 +1,291 @@
+use crate::render::svg::{DEFAULT_FONT_COLOR, DEFAULT_FONT_FAMILY, DEFAULT_STROKE_COLOR};
+use crate::{Color, GridStyle, Palette};
+
+const DEFAULT_TITLE_FONT_SIZE: i32 = 24;
+const DEFAULT_AXIS_LABEL_FONT_SIZE: i32 = 14;
+const DEFAULT_TICK_LABEL_FONT_SIZE: i32 = 12;
+const DEFAULT_LABEL_FONT_SIZE: i32 = 14;
+
+const DEFAULT_MARGIN_TOP: i32 = 90;
+const DEFAULT_MARGIN_BOTTOM: i32 = 50;
+const DEFAULT_MARGIN_LEFT: i32 = 60;
+const DEFAULT_MARGIN_RIGHT: i32 = 40;
+const DEFAULT_PADDING: i32 = 10;
+
+/// Theme contains typography, colors, grid style and spacing that a chart applies to its shapes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    font_family: String,
+    font_color: String,
+    title_font_size: i32,
+    axis_label_font_size: i32,
+    tick_label_font_size: i32,
+    label_font_size: i32,
+    axis_color: String,
+    background_color: Option<String>,
+    grid_style: GridStyle,
+    grid_minor_style: GridStyle,
+    margin_top: i32,
+    margin_bottom: i32,
+    margin_left: i32,
+    margin_right: i32,
+    padding: i32,
+    palette: Option<Palette>,
+    palette_offset: usize,
+}
+
+impl Theme {
+    /// Create a new light Theme with dark text on a transparent background.
+    pub fn light() -> Self {
+        Self {
+            font_family: DEFAULT_FONT_FAMILY.to_string(),
+            font_color: DEFAULT_FONT_COLOR.to_string(),
+            title_font_size: DEFAULT_TITLE_FONT_SIZE,
+            axis_label_font_size: DEFAULT_AXIS_LABEL_FONT_SIZE,
+            tick_label_font_size: DEFAULT_TICK_LABEL_FONT_SIZE,
+            label_font_size: DEFAULT_LABEL_FONT_SIZE,
+            axis_color: DEFAULT_STROKE_COLOR.to_string(),
+            background_color: None,
+            grid_style: GridStyle::new(),
+            grid_minor_style: GridStyle::new_minor(),
+            margin_top: DEFAULT_MARGIN_TOP,
+            margin_bottom: DEFAULT_MARGIN_BOTTOM,
+            margin_left: DEFAULT_MARGIN_LEFT,
+            margin_right: DEFAULT_MARGIN_RIGHT,
+            padding: DEFAULT_PADDING,
+            palette: None,
+            palette_offset: 0,
+        }
+    }
+
+    /// Create a new dark Theme with light text on a dark background.
+    pub fn dark() -> Self {
+        Self::light()
+            .set_font_color(Color::new_from_hex("#e6e6e6"))
+            .set_axis_color(Color::new_from_hex("#808080"))
+            .set_background_color(Color::new_from_hex("#1e1e1e"))
+            .set_grid_style(GridStyle::new().set_stroke_color(Color::new_from_hex("#3c3c3c")))
+            .set_grid_minor_style(GridStyle::new().set_stroke_color(Color::new_from_hex("#2c2c2c")))
+            .set_palette(Palette::tableau10())
+    }
+
+    /// Create a new high contrast Theme with larger black text and black axes on a white background.
+    pub fn high_contrast() -> Self {
+        Self::light()
+            .set_font_color(Color::new_from_hex("#000000"))
+            .set_title_font_size(DEFAULT_TITLE_FONT_SIZE + 4)
+            .set_axis_label_font_size(DEFAULT_AXIS_LABEL_FONT_SIZE + 2)
+            .set_tick_label_font_size(DEFAULT_TICK_LABEL_FONT_SIZE + 2)
+            .set_label_font_size(DEFAULT_LABEL_FONT_SIZE + 2)
+            .set_axis_color(Color::new_from_hex("#000000"))
+            .set_background_color(Color::new_from_hex("#ffffff"))
+            .set_grid_style(GridStyle::new().set_stroke_color(Color::new_from_hex("#767676")))
+            .set_grid_minor_style(GridStyle::new().set_stroke_color(Color::new_from_hex("#b0b0b0")))
+            .set_palette(Palette::okabe_ito())
+    }
+
+    /// Create a new grayscale Theme for printing with serif text and dashed grid lines on a white background.
+    pub fn print_grayscale() -> Self {
+        Self::light()
+            .set_font_family("serif")
+            .set_font_color(Color::new_from_hex("#000000"))
+            .set_axis_color(Color::new_from_hex("#404040"))
+            .set_background_color(Color::new_from_hex("#ffffff"))
+            .set_grid_style(
+                GridStyle::new()
+                    .set_stroke_color(Color::new_from_hex("#a0a0a0"))
+                    .set_stroke_dasharray(&[4, 2]),
+            )
+            .set_grid_minor_style(
+                GridStyle::new()
+                    .set_stroke_color(Color::new_from_hex("#d0d0d0"))
+                    .set_stroke_dasharray(&[2, 2]),
+            )
+            .set_palette(Palette::grayscale())
+    }
+
+    /// Set font family of all texts.
+    pub fn set_font_family(mut self, font_family: &str) -> Self {
+        self.font_family = font_family.to_string();
+        self
+    }
+
+    /// Set color of all texts except labels that contrast with their shapes.
+    pub fn set_font_color(mut self, font_color: Color) -> Self {
+        self.font_color = font_color.to_string();
+        self
+    }
+
+    /// Set font size of the chart title in pixels.
+    pub fn set_title_font_size(mut self, title_font_size: i32) -> Self {
+        self.title_font_size = title_font_size;
+        self
+    }
+
+    /// Set font size of axis labels in pixels.
+    pub fn set_axis_label_font_size(mut self, axis_label_font_size: i32) -> Self {
+        self.axis_label_font_size = axis_label_font_size;
+        self
+    }
+
+    /// Set font size of axis tick labels in pixels.
+    pub fn set_tick_label_font_size(mut self, tick_label_font_size: i32) -> Self {
+        self.tick_label_font_size = tick_label_font_size;
+        self
+    }
+
+    /// Set font size of view value labels and legend entries in pixels.
+    pub fn set_label_font_size(mut self, label_font_size: i32) -> Self {
+        self.label_font_size = label_font_size;
+        self
+    }
+
+    /// Set stroke color of axis lines and ticks.
+    pub fn set_axis_color(mut self, axis_color: Color) -> Self {
+        self.axis_color = axis_color.to_string();
+        self
+    }
+
+    /// Set chart background color, the background is transparent by default.
+    pub fn set_background_color(mut self, background_color: Color) -> Self {
+        self.background_color = Some(background_color.to_string());
+        self
+    }
+
+    /// Set style of major grid lines.
+    pub fn set_grid_style(mut self, grid_style: GridStyle) -> Self {
+        self.grid_style = grid_style;
+        self
+    }
+
+    /// Set style of minor grid lines.
+    pub fn set_grid_minor_style(mut self, grid_minor_style: GridStyle) -> Self {
+        self.grid_minor_style = grid_minor_style;
+        self
+    }
+
+    /// Set chart margins that are used unless they are set on the chart.
+    pub fn set_margins(mut self, top: i32, bottom: i32, left: i32, right: i32) -> Self {
+        self.margin_top = top;
+        self.margin_bottom = bottom;
+        self.margin_left = left;
+        self.margin_right = right;
+        self
+    }
+
+    /// Set padding between the legend and the views and around automatic margins.
+    pub fn set_padding(mut self, padding: i32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set palette that colors views and series without explicitly set colors.
+    /// Views keep their default colors when the theme has no palette.
+    pub fn set_palette(mut self, palette: Palette) -> Self {
+        self.palette = Some(palette);
+        self
+    }
+
+    // Set index of the palette color that is assigned to the first series of a view.
+    pub(crate) fn set_palette_offset(mut self, palette_offset: usize) -> Self {
+        self.palette_offset = palette_offset;
+        self
+    }
+
+    /// Get font family of all texts.
+    pub fn font_family(&self) -> &str {
+        &self.font_family
+    }
+
+    /// Get color of all texts.
+    pub fn font_color(&self) -> &str {
+        &self.font_color
+    }
+
+    /// Get font size of the chart title in pixels.
+    pub fn title_font_size(&self) -> i32 {
+        self.title_font_size
+    }
+
+    /// Get font size of axis labels in pixels.
+    pub fn axis_label_font_size(&self) -> i32 {
+        self.axis_label_font_size
+    }
+
+    /// Get font size of axis tick labels in pixels.
+    pub fn tick_label_font_size(&self) -> i32 {
+        self.tick_label_font_size
+    }
+
+    /// Get font size of view value labels and legend entries in pixels.
+    pub fn label_font_size(&self) -> i32 {
+        self.label_font_size
+    }
+
+    /// Get stroke color of axis lines and ticks.
+    pub fn axis_color(&self) -> &str {
+        &self.axis_color
+    }
+
+    /// Get chart background color.
+    pub fn background_color(&self) -> Option<&str> {
+        self.background_color.as_deref()
+    }
+
+    /// Get style of major grid lines.
+    pub fn grid_style(&self) -> &GridStyle {
+        &self.grid_style
+    }
+
+    /// Get style of minor grid lines.
+    pub fn grid_minor_style(&self) -> &GridStyle {
+        &self.grid_minor_style
+    }
+
+    /// Get top, bottom, left and right chart margins.
+    pub fn margins(&self) -> (i32, i32, i32, i32) {
+        (
+            self.margin_top,
+            self.margin_bottom,
+            self.margin_left,
+            self.margin_right,
+        )
+    }
+
+    /// Get padding between the legend and the views and around automatic margins.
+    pub fn padding(&self) -> i32 {
+        self.padding
+    }
+
+    /// Get palette that colors views and series without explicitly set colors.
+    pub fn palette(&self) -> Option<&Palette> {
+        self.palette.as_ref()
+    }
+
+    // Get palette color of the series with the provided index inside a view.
+    pub(crate) fn palette_color(&self, series: Option<usize>) -> Option<&str> {
+        self.palette.as_ref()?.hex(self.palette_offset + series?)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn theme_builtin() {
+        assert_eq!(Theme::default(), Theme::light());
+        assert_eq!(Theme::light().background_color(), None);
+        assert_eq!(Theme::dark().background_color(), Some("#1e1e1e"));
+        assert_eq!(Theme::high_contrast().tick_label_font_size(), 14);
+        assert_eq!(Theme::print_grayscale().font_family(), "serif");
+        assert_eq!(Theme::light().palette(), None);
+        assert_eq!(Theme::dark().palette(), Some(&Palette::tableau10()));
+    }
+}
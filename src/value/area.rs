@@ -0,0 +1,89 @@
+use crate::color::{COLOR_HEX_GREEN_1, COLOR_HEX_GREEN_5};
+use crate::Color;
+
+/// AreaValues contains values and color settings for a layer of stacked area views.
+#[derive(Clone)]
+pub struct AreaValues {
+    values: Vec<f32>,
+    name: String,
+    fill_color: String,
+    stroke_color: String,
+    default_colors: bool,
+}
+
+impl AreaValues {
+    /// Create a new AreaValues.
+    pub fn new(values: Vec<f32>) -> Self {
+        Self {
+            values,
+            name: String::new(),
+            fill_color: COLOR_HEX_GREEN_5.to_string(),
+            stroke_color: COLOR_HEX_GREEN_1.to_string(),
+            default_colors: true,
+        }
+    }
+
+    /// Set name for AreaValues that is used in a chart legend.
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set fill color for AreaValues.
+    pub fn set_fill_color(mut self, fill_color: Color) -> Self {
+        self.fill_color = fill_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    /// Set stroke color for AreaValues.
+    pub fn set_stroke_color(mut self, stroke_color: Color) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    /// Get values.
+    pub fn values(&self) -> &Vec<f32> {
+        &self.values
+    }
+
+    /// Get name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get fill color.
+    pub fn fill_color(&self) -> &str {
+        &self.fill_color
+    }
+
+    /// Get stroke color.
+    pub fn stroke_color(&self) -> &str {
+        &self.stroke_color
+    }
+
+    /// Check if colors aren't set explicitly so a chart palette can assign them.
+    pub fn default_colors(&self) -> bool {
+        self.default_colors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{COLOR_HEX_BLUE_2, COLOR_HEX_BLUE_4};
+
+    #[test]
+    fn area_values_basic() {
+        let area_values = AreaValues::new(vec![10_f32, 20_f32, 5_f32])
+            .set_name("Blue")
+            .set_fill_color(Color::new_from_hex(COLOR_HEX_BLUE_4))
+            .set_stroke_color(Color::new_from_hex(COLOR_HEX_BLUE_2));
+
+        assert_eq!(*area_values.values(), vec![10_f32, 20_f32, 5_f32]);
+        assert_eq!(area_values.name(), "Blue");
+        assert_eq!(area_values.fill_color(), COLOR_HEX_BLUE_4);
+        assert_eq!(area_values.stroke_color(), COLOR_HEX_BLUE_2);
+    }
+}
@@ -2,10 +2,13 @@ use crate::color::{COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_2};
 use crate::Color;
 
 /// BarsValues contains values and color settings for bar views.
+#[derive(Clone)]
 pub struct BarsValues {
     values: Vec<f32>,
+    name: String,
     fill_color: String,
     stroke_color: String,
+    default_colors: bool,
 }
 
 impl BarsValues {
@@ -13,20 +16,30 @@ impl BarsValues {
     pub fn new(values: Vec<f32>) -> Self {
         Self {
             values,
+            name: String::new(),
             fill_color: COLOR_HEX_BLUE_2.to_string(),
             stroke_color: COLOR_HEX_BLUE_1.to_string(),
+            default_colors: true,
         }
     }
 
+    /// Set name for BarsValues that is used in a chart legend.
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
     /// Set fill color for BarsValues.
     pub fn set_fill_color(mut self, fill_color: Color) -> Self {
         self.fill_color = fill_color.to_string();
+        self.default_colors = false;
         self
     }
 
     /// Set stroke color for BarsValues.
     pub fn set_stroke_color(mut self, stroke_color: Color) -> Self {
         self.stroke_color = stroke_color.to_string();
+        self.default_colors = false;
         self
     }
 
@@ -35,6 +48,11 @@ impl BarsValues {
         &self.values
     }
 
+    /// Get name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     /// Get fill color.
     pub fn fill_color(&self) -> &str {
         &self.fill_color
@@ -44,6 +62,11 @@ impl BarsValues {
     pub fn stroke_color(&self) -> &str {
         &self.stroke_color
     }
+
+    /// Check if colors aren't set explicitly so a chart palette can assign them.
+    pub fn default_colors(&self) -> bool {
+        self.default_colors
+    }
 }
 
 #[cfg(test)]
@@ -54,6 +77,7 @@ mod tests {
     #[test]
     fn bars_values_basic() {
         let bars_values = BarsValues::new(vec![77_f32, 12_f32, 32_f32, 24_f32, 6_f32])
+            .set_name("Green")
             .set_fill_color(Color::new_from_hex(COLOR_HEX_GREEN_5))
             .set_stroke_color(Color::new_from_hex(COLOR_HEX_GREEN_3));
 
@@ -61,6 +85,7 @@ mod tests {
             *bars_values.values(),
             vec![77_f32, 12_f32, 32_f32, 24_f32, 6_f32]
         );
+        assert_eq!(bars_values.name(), "Green");
         assert_eq!(bars_values.fill_color(), COLOR_HEX_GREEN_5);
         assert_eq!(bars_values.stroke_color(), COLOR_HEX_GREEN_3);
     }
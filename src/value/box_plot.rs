@@ -0,0 +1,150 @@
+use crate::math::statistics::quantile;
+use crate::{BoxPlotWhiskers, Error};
+
+/// BoxPlotSummary contains a five-number summary and outliers of a box plot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoxPlotSummary {
+    min: f32,
+    first_quartile: f32,
+    median: f32,
+    third_quartile: f32,
+    max: f32,
+    outliers: Vec<f32>,
+}
+
+impl BoxPlotSummary {
+    /// Create a new BoxPlotSummary from precomputed values.
+    /// Minimum and maximum are the ends of whiskers.
+    pub fn new(
+        min: f32,
+        first_quartile: f32,
+        median: f32,
+        third_quartile: f32,
+        max: f32,
+    ) -> Result<Self, Error> {
+        let values = [min, first_quartile, median, third_quartile, max];
+        if values.iter().any(|value| !value.is_finite())
+            || values.windows(2).any(|pair| pair[0] > pair[1])
+        {
+            return Err(Error::BoxPlotSummaryIsInvalid);
+        }
+
+        Ok(Self {
+            min,
+            first_quartile,
+            median,
+            third_quartile,
+            max,
+            outliers: Vec::new(),
+        })
+    }
+
+    /// Calculate a BoxPlotSummary from raw samples, non-finite samples are ignored.
+    pub fn from_samples(samples: &[f32], whiskers: BoxPlotWhiskers) -> Result<Self, Error> {
+        let mut sorted_samples: Vec<f32> = samples
+            .iter()
+            .copied()
+            .filter(|sample| sample.is_finite())
+            .collect();
+        if sorted_samples.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+        sorted_samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let first_quartile = quantile(&sorted_samples, 0.25_f32);
+        let median = quantile(&sorted_samples, 0.5_f32);
+        let third_quartile = quantile(&sorted_samples, 0.75_f32);
+
+        let (low_fence, high_fence) = match whiskers {
+            BoxPlotWhiskers::MinMax => (f32::NEG_INFINITY, f32::INFINITY),
+            BoxPlotWhiskers::Iqr(factor) => {
+                let iqr = third_quartile - first_quartile;
+                (first_quartile - factor * iqr, third_quartile + factor * iqr)
+            }
+        };
+        let inside = |sample: &&f32| **sample >= low_fence && **sample <= high_fence;
+
+        // Whiskers end at the furthest samples inside fences but never inside the box.
+        let min = sorted_samples
+            .iter()
+            .find(inside)
+            .map_or(first_quartile, |sample| sample.min(first_quartile));
+        let max = sorted_samples
+            .iter()
+            .rev()
+            .find(inside)
+            .map_or(third_quartile, |sample| sample.max(third_quartile));
+        let outliers = sorted_samples
+            .iter()
+            .copied()
+            .filter(|sample| !inside(&sample))
+            .collect();
+
+        Ok(Self::new(min, first_quartile, median, third_quartile, max)?.set_outliers(outliers))
+    }
+
+    /// Set outliers that are drawn as points beyond whiskers.
+    pub fn set_outliers(mut self, outliers: Vec<f32>) -> Self {
+        self.outliers = outliers;
+        self
+    }
+
+    /// Get whisker minimum.
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Get first quartile.
+    pub fn first_quartile(&self) -> f32 {
+        self.first_quartile
+    }
+
+    /// Get median.
+    pub fn median(&self) -> f32 {
+        self.median
+    }
+
+    /// Get third quartile.
+    pub fn third_quartile(&self) -> f32 {
+        self.third_quartile
+    }
+
+    /// Get whisker maximum.
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Get outliers.
+    pub fn outliers(&self) -> &[f32] {
+        &self.outliers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn box_plot_summary_from_samples() {
+        let samples = vec![
+            1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32, 30_f32,
+        ];
+        let summary =
+            BoxPlotSummary::from_samples(&samples, BoxPlotWhiskers::Iqr(1.5_f32)).unwrap();
+        assert_eq!(
+            summary,
+            BoxPlotSummary::new(1_f32, 3_f32, 5_f32, 7_f32, 8_f32)
+                .unwrap()
+                .set_outliers(vec![30_f32])
+        );
+
+        let summary = BoxPlotSummary::from_samples(&samples, BoxPlotWhiskers::MinMax).unwrap();
+        assert!((summary.max() - 30_f32).abs() < f32::EPSILON);
+        assert!(summary.outliers().is_empty());
+
+        assert!(matches!(
+            BoxPlotSummary::new(2_f32, 1_f32, 3_f32, 4_f32, 5_f32),
+            Err(Error::BoxPlotSummaryIsInvalid)
+        ));
+    }
+}
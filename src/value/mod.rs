@@ -1 +1,6 @@
+pub mod area;
 pub mod bar;
+pub mod box_plot;
+pub mod ohlc;
+pub mod optional;
+pub mod pie;
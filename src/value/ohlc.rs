@@ -0,0 +1,90 @@
+/// Ohlc contains open, high, low and close prices and an optional volume of a trading period.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ohlc {
+    open: f32,
+    high: f32,
+    low: f32,
+    close: f32,
+    volume: Option<f32>,
+}
+
+impl Ohlc {
+    /// Create a new Ohlc.
+    pub fn new(open: f32, high: f32, low: f32, close: f32) -> Self {
+        Self {
+            open,
+            high,
+            low,
+            close,
+            volume: None,
+        }
+    }
+
+    /// Set traded volume that is shown in a volume panel.
+    pub fn set_volume(mut self, volume: f32) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+
+    /// Get open price.
+    pub fn open(&self) -> f32 {
+        self.open
+    }
+
+    /// Get high price.
+    pub fn high(&self) -> f32 {
+        self.high
+    }
+
+    /// Get low price.
+    pub fn low(&self) -> f32 {
+        self.low
+    }
+
+    /// Get close price.
+    pub fn close(&self) -> f32 {
+        self.close
+    }
+
+    /// Get traded volume.
+    pub fn volume(&self) -> Option<f32> {
+        self.volume
+    }
+
+    /// Check if close price is not lower than open price.
+    pub fn is_up(&self) -> bool {
+        self.close >= self.open
+    }
+
+    /// Check if prices are finite and high and low prices contain open and close prices.
+    pub fn is_valid(&self) -> bool {
+        let prices = [self.open, self.high, self.low, self.close];
+        prices.iter().all(|price| price.is_finite())
+            && self.low <= self.open.min(self.close)
+            && self.high >= self.open.max(self.close)
+            && self
+                .volume
+                .is_none_or(|volume| volume.is_finite() && volume >= 0_f32)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Ohlc {
+    fn from(values: (f32, f32, f32, f32)) -> Self {
+        Self::new(values.0, values.1, values.2, values.3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ohlc_basic() {
+        let ohlc = Ohlc::from((10_f32, 15_f32, 8_f32, 12_f32)).set_volume(100_f32);
+        assert!(ohlc.is_up());
+        assert!(ohlc.is_valid());
+        assert_eq!(ohlc.volume(), Some(100_f32));
+
+        assert!(!Ohlc::new(10_f32, 11_f32, 8_f32, 12_f32).is_valid());
+    }
+}
@@ -0,0 +1,75 @@
+use chrono::{DateTime, TimeZone};
+
+/// OptionalValue represents a data value that can be missing.
+/// Numbers are missing when they are `None` or `NaN`.
+pub trait OptionalValue {
+    /// Type of the present value.
+    type Value;
+
+    /// Get the present value or `None` if the value is missing.
+    fn value(&self) -> Option<Self::Value>;
+
+    /// Get the value that replaces missing values when they're treated as zero.
+    fn zero() -> Option<Self::Value> {
+        None
+    }
+}
+
+impl OptionalValue for f32 {
+    type Value = f32;
+
+    fn value(&self) -> Option<f32> {
+        if self.is_nan() {
+            return None;
+        }
+
+        Some(*self)
+    }
+
+    fn zero() -> Option<f32> {
+        Some(0_f32)
+    }
+}
+
+impl OptionalValue for Option<f32> {
+    type Value = f32;
+
+    fn value(&self) -> Option<f32> {
+        self.and_then(|value| value.value())
+    }
+
+    fn zero() -> Option<f32> {
+        Some(0_f32)
+    }
+}
+
+impl OptionalValue for String {
+    type Value = String;
+
+    fn value(&self) -> Option<String> {
+        Some(self.clone())
+    }
+}
+
+impl<Tz: TimeZone> OptionalValue for DateTime<Tz> {
+    type Value = DateTime<Tz>;
+
+    fn value(&self) -> Option<DateTime<Tz>> {
+        Some(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optional_value_basic() {
+        assert_eq!(12_f32.value(), Some(12_f32));
+        assert_eq!(f32::NAN.value(), None);
+        assert_eq!(Some(f32::NAN).value(), None);
+        assert_eq!(None::<f32>.value(), None);
+        assert_eq!(<Option<f32>>::zero(), Some(0_f32));
+        assert_eq!(String::zero(), None);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::color::{COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_2};
+use crate::Color;
+
+/// PieSlice contains a labelled value and color settings for pie views.
+#[derive(Clone)]
+pub struct PieSlice {
+    label: String,
+    value: f32,
+    fill_color: String,
+    stroke_color: String,
+    default_colors: bool,
+}
+
+impl PieSlice {
+    /// Create a new PieSlice.
+    pub fn new(label: &str, value: f32) -> Self {
+        Self {
+            label: label.to_string(),
+            value,
+            fill_color: COLOR_HEX_BLUE_2.to_string(),
+            stroke_color: COLOR_HEX_BLUE_1.to_string(),
+            default_colors: true,
+        }
+    }
+
+    /// Set fill color for PieSlice.
+    pub fn set_fill_color(mut self, fill_color: Color) -> Self {
+        self.fill_color = fill_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    /// Set stroke color for PieSlice.
+    pub fn set_stroke_color(mut self, stroke_color: Color) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    /// Get label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get value.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Get fill color.
+    pub fn fill_color(&self) -> &str {
+        &self.fill_color
+    }
+
+    /// Get stroke color.
+    pub fn stroke_color(&self) -> &str {
+        &self.stroke_color
+    }
+
+    /// Check if colors aren't set explicitly so a chart palette can assign them.
+    pub fn default_colors(&self) -> bool {
+        self.default_colors
+    }
+}
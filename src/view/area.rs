@@ -1,8 +1,14 @@
 use crate::color::{COLOR_HEX_GREEN_1, COLOR_HEX_GREEN_4, COLOR_HEX_GREEN_5};
 use crate::render::svg::*;
+use crate::scale::ScaleRange;
 use crate::shape::area::Area;
 use crate::shape::point::Point;
-use crate::{BandScale, Color, Error, LinearScale, PointLabelPosition, PointType, Scale, View};
+use crate::view::{scale_position, ViewPoint};
+use crate::Theme;
+use crate::{
+    BandScale, Color, ContinuousScale, Curve, Error, LegendEntry, LegendMarker, LinearScale,
+    MissingValues, OptionalValue, PointLabelPosition, PointType, Scale, View,
+};
 use svg::Node;
 
 const DEFAULT_LABEL_VISIBLE: bool = true;
@@ -11,33 +17,69 @@ const DEFAULT_LABEL_POSITION: PointLabelPosition = PointLabelPosition::Top;
 const DEFAULT_POINT_TYPE: PointType = PointType::Circle;
 const DEFAULT_POINT_VISIBLE: bool = true;
 
+const DEFAULT_BASELINE: AreaBaseline = AreaBaseline::Zero;
+const DEFAULT_CURVE: Curve = Curve::Linear;
+const DEFAULT_MISSING_VALUES: MissingValues = MissingValues::Break;
+
+// Top points of an area and points of its baseline in the reversed order.
+type AreaPoints = (Vec<ViewPoint>, Vec<ViewPoint>);
+
+/// AreaBaseline contains available bottom bounds of an area.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AreaBaseline {
+    /// Area is filled down to zero of the y scale.
+    /// Scales that can't represent zero, like log scales, use their range start.
+    Zero,
+
+    /// Area is filled down to the provided value.
+    Value(f32),
+
+    /// Area is filled down to the low series that contains a value for each data element,
+    /// so the area represents a range band.
+    Low(Vec<f32>),
+}
+
 /// View that represents area.
 #[derive(Clone)]
-pub struct AreaView {
-    x_scale: BandScale,
-    y_scale: LinearScale,
-    area: Area,
+pub struct AreaView<X = BandScale, Y = LinearScale> {
+    x_scale: X,
+    y_scale: Y,
+    areas: Vec<AreaPoints>,
+    x_range: ScaleRange,
+    y_range: ScaleRange,
+    baseline: AreaBaseline,
+    curve: Curve,
+    missing_values: MissingValues,
+    name: String,
     fill_color: String,
     stroke_color: String,
     point_fill_color: String,
     point_stroke_color: String,
+    default_colors: bool,
     point_type: PointType,
     point_visible: bool,
     point_label_visible: bool,
     point_label_position: PointLabelPosition,
 }
 
-impl AreaView {
+impl<X, Y> AreaView<X, Y> {
     /// Create a new Area.
-    pub fn new(x_scale: BandScale, y_scale: LinearScale) -> Self {
+    pub fn new(x_scale: X, y_scale: Y) -> Self {
         Self {
             x_scale,
             y_scale,
+            name: String::new(),
             fill_color: COLOR_HEX_GREEN_5.to_string(),
             stroke_color: COLOR_HEX_GREEN_1.to_string(),
             point_fill_color: COLOR_HEX_GREEN_4.to_string(),
             point_stroke_color: COLOR_HEX_GREEN_1.to_string(),
-            area: Area::default(),
+            default_colors: true,
+            areas: Vec::new(),
+            x_range: ScaleRange::default(),
+            y_range: ScaleRange::default(),
+            baseline: DEFAULT_BASELINE,
+            curve: DEFAULT_CURVE,
+            missing_values: DEFAULT_MISSING_VALUES,
             point_type: DEFAULT_POINT_TYPE,
             point_visible: DEFAULT_POINT_VISIBLE,
             point_label_visible: DEFAULT_LABEL_VISIBLE,
@@ -45,26 +87,55 @@ impl AreaView {
         }
     }
 
+    /// Set area name that is used in a chart legend.
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set area baseline.
+    pub fn set_baseline(mut self, baseline: AreaBaseline) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Set curve that connects area points.
+    pub fn set_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Configure how missing values are drawn.
+    pub fn set_missing_values(mut self, missing_values: MissingValues) -> Self {
+        self.missing_values = missing_values;
+        self
+    }
+
     /// Set area fill color.
     pub fn set_fill_color(mut self, fill_color: Color) -> Self {
         self.fill_color = fill_color.to_string();
+        self.default_colors = false;
         self
     }
+
     /// Set area stroke color.
     pub fn set_stroke_color(mut self, stroke_color: Color) -> Self {
         self.stroke_color = stroke_color.to_string();
+        self.default_colors = false;
         self
     }
 
     /// Set area point fill color.
     pub fn set_point_fill_color(mut self, point_fill_color: Color) -> Self {
         self.point_fill_color = point_fill_color.to_string();
+        self.default_colors = false;
         self
     }
 
     /// Set area point stroke color.
     pub fn set_point_stroke_color(mut self, point_stroke_color: Color) -> Self {
         self.point_stroke_color = point_stroke_color.to_string();
+        self.default_colors = false;
         self
     }
 
@@ -92,154 +163,255 @@ impl AreaView {
         self
     }
 
+    // Create areas of top and baseline points from the provided x and y domain values.
+    // Missing values split the area into separate areas, get skipped or are replaced with zero.
+    fn areas<XD, D: OptionalValue<Value = f32>>(
+        &self,
+        values: &[(&XD, &D)],
+    ) -> Result<Vec<AreaPoints>, Error>
+    where
+        X: Scale<XD>,
+        Y: Scale<f32>,
+    {
+        let low_values = match &self.baseline {
+            AreaBaseline::Zero => vec![0_f32; values.len()],
+            AreaBaseline::Value(value) => vec![*value; values.len()],
+            AreaBaseline::Low(low_values) => {
+                if low_values.len() != values.len() {
+                    return Err(Error::AreaBaselineIsInvalid);
+                }
+                low_values.clone()
+            }
+        };
+
+        // Collect segments of indices and top points of present values.
+        let mut segments = vec![Vec::new()];
+        for (idx, (x_value, y_value)) in values.iter().enumerate() {
+            if !self.x_scale.accepts(x_value) {
+                return Err(Error::ValueIsNotInScaleDomain);
+            }
+            let (y_value, present) = match (y_value.value(), self.missing_values) {
+                (Some(y_value), _) => (y_value, true),
+                (None, MissingValues::Bridge) => continue,
+                (None, missing_values) => match D::zero() {
+                    Some(zero)
+                        if missing_values == MissingValues::Zero && self.y_scale.accepts(&zero) =>
+                    {
+                        (zero, false)
+                    }
+                    _ => {
+                        segments.push(Vec::new());
+                        continue;
+                    }
+                },
+            };
+            if !self.y_scale.accepts(&y_value) {
+                return Err(Error::ValueIsNotInScaleDomain);
+            }
+
+            let point = ViewPoint {
+                x: scale_position(&self.x_scale, x_value),
+                y: scale_position(&self.y_scale, &y_value),
+                x_label: String::new(),
+                y_label: self.y_scale.tick_label(&y_value),
+                present,
+            };
+            if let Some(segment) = segments.last_mut() {
+                segment.push((idx, point));
+            }
+        }
+
+        let mut areas = Vec::new();
+        for segment in segments.into_iter().filter(|segment| !segment.is_empty()) {
+            // Constant baselines only need the corners under the last and the first points,
+            // low series return through every data element.
+            let baseline_segment: Vec<&(usize, ViewPoint)> = match self.baseline {
+                AreaBaseline::Low(_) => segment.iter().rev().collect(),
+                _ => vec![&segment[segment.len() - 1], &segment[0]],
+            };
+            let mut baseline_points = Vec::new();
+            for (idx, top_point) in baseline_segment {
+                baseline_points.push(ViewPoint {
+                    x: top_point.x,
+                    y: self.baseline_y(low_values[*idx])?,
+                    x_label: String::new(),
+                    y_label: self.y_scale.tick_label(&low_values[*idx]),
+                    present: false,
+                });
+            }
+
+            let points = segment.into_iter().map(|(_, point)| point).collect();
+            areas.push((points, baseline_points));
+        }
+
+        Ok(areas)
+    }
+
+    // Get baseline position relative to the y scale range that is kept inside of the range.
+    fn baseline_y(&self, low_value: f32) -> Result<f32, Error>
+    where
+        Y: Scale<f32>,
+    {
+        let scaled_low = if self.y_scale.accepts(&low_value) {
+            self.y_scale.scale(&low_value)
+        } else if self.baseline == AreaBaseline::Zero {
+            self.y_scale.range_start() as f32
+        } else {
+            return Err(Error::ValueIsNotInScaleDomain);
+        };
+        let range_min = self.y_scale.range_start().min(self.y_scale.range_end()) as f32;
+        let range_max = self.y_scale.range_start().max(self.y_scale.range_end()) as f32;
+
+        Ok(ScaleRange::new(&self.y_scale).normalize(scaled_low.max(range_min).min(range_max)))
+    }
+}
+
+impl<Y: Scale<f32>> AreaView<BandScale, Y> {
     /// Set area data.
-    pub fn set_data(mut self, data: &[f32]) -> Result<Self, Error> {
+    /// Values are placed on categories of x scale in the same order, they can be missing.
+    pub fn set_data<D: OptionalValue<Value = f32>>(mut self, data: &[D]) -> Result<Self, Error> {
         if data.is_empty() {
             return Err(Error::DataIsEmpty);
         }
-        if data.len() != self.x_scale.ticks().len() {
+        let categories = self.x_scale.ticks();
+        if data.len() != categories.len() {
             return Err(Error::CategoriesCountDoesntEqual);
         }
 
-        // Compute offsets in case there is a non-zero bandwidth.
-        let x_bandwidth_offset = {
-            if self.x_scale.is_range_reversed() {
-                -self.x_scale.tick_offset()
-            } else {
-                self.x_scale.tick_offset()
-            }
-        };
-        let y_bandwidth_offset = {
-            if self.y_scale.is_range_reversed() {
-                -self.y_scale.tick_offset()
-            } else {
-                self.y_scale.tick_offset()
-            }
-        };
+        let values: Vec<(&String, &D)> = categories.iter().zip(data.iter()).collect();
+        self.areas = self.areas(&values)?;
+        self.x_range = ScaleRange::new(&self.x_scale);
+        self.y_range = ScaleRange::new(&self.y_scale);
 
-        let categories = self.x_scale.ticks();
-        let mut points = Vec::new();
-        for (idx, value) in data.iter().enumerate() {
-            let category = &categories[idx];
-            let scaled_x = &self.x_scale.scale(category);
-            let scaled_y = self.y_scale.scale(value);
-
-            let point = Point::new(
-                scaled_x + x_bandwidth_offset,
-                scaled_y + y_bandwidth_offset,
+        Ok(self)
+    }
+}
+
+impl<X: ContinuousScale, Y: Scale<f32>> AreaView<X, Y> {
+    /// Set area data.
+    /// Each value is paired with its position on a continuous x scale, values can be missing.
+    pub fn set_data<D: OptionalValue<Value = f32>>(
+        mut self,
+        data: &[(X::Domain, D)],
+    ) -> Result<Self, Error> {
+        if data.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+
+        let values: Vec<(&X::Domain, &D)> = data
+            .iter()
+            .map(|(x_value, y_value)| (x_value, y_value))
+            .collect();
+        self.areas = self.areas(&values)?;
+        self.x_range = ScaleRange::new(&self.x_scale);
+        self.y_range = ScaleRange::new(&self.y_scale);
+
+        Ok(self)
+    }
+}
+
+impl<X, Y> AreaView<X, Y> {
+    // Get area SVG representation for the provided scale ranges.
+    fn to_svg_with_ranges(
+        &self,
+        x_range: ScaleRange,
+        y_range: ScaleRange,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        let point = |view_point: &ViewPoint| {
+            Point::new(
+                x_range.interpolate(view_point.x),
+                y_range.interpolate(view_point.y),
                 self.point_type,
                 DEFAULT_POINT_SIZE,
-                &value.to_string(),
-                &self.point_fill_color.to_string(),
-                &self.point_stroke_color.to_string(),
+                &view_point.y_label,
+                &self.point_fill_color,
+                &self.point_stroke_color,
             )
-            .set_point_visible(self.point_visible)
-            .set_label_visible(self.point_label_visible)
-            .set_label_position(self.point_label_position);
-            points.push(point);
-        }
-
-        let y_origin = {
-            if self.y_scale.is_range_reversed() {
-                self.y_scale.range_start()
-            } else {
-                self.y_scale.range_end()
-            }
+            .set_point_visible(self.point_visible && view_point.present)
+            .set_label_visible(self.point_label_visible && view_point.present)
+            .set_label_position(self.point_label_position)
         };
 
-        let last_point = Point::new(
-            self.x_scale
-                .scale(&categories[self.x_scale.ticks().len() - 1])
-                + x_bandwidth_offset,
-            y_origin as f32,
-            self.point_type,
-            DEFAULT_POINT_SIZE,
-            &data[0].to_string(),
-            &self.point_fill_color,
-            &self.point_stroke_color,
-        )
-        .set_point_visible(false)
-        .set_label_visible(false);
-        points.push(last_point);
-
-        let first_point = Point::new(
-            self.x_scale.scale(&categories[0]) + x_bandwidth_offset,
-            y_origin as f32,
-            self.point_type,
-            DEFAULT_POINT_SIZE,
-            &data[0].to_string(),
-            &self.point_fill_color,
-            &self.point_stroke_color,
-        )
-        .set_point_visible(false)
-        .set_label_visible(false);
-        points.push(first_point);
-
-        self.area = Area::new(points, &self.fill_color, &self.stroke_color);
+        let mut res = svg::node::element::Group::new();
+        for (points, baseline_points) in self.areas.iter() {
+            let area = Area::new(
+                points.iter().map(point).collect(),
+                &self.fill_color,
+                &self.stroke_color,
+            )
+            .set_baseline_points(baseline_points.iter().map(point).collect())
+            .set_curve(self.curve);
+            // Area without explicitly set colors takes them from the theme palette.
+            let area = if self.default_colors {
+                area.set_palette_series(0)
+            } else {
+                area
+            };
+            res.append(area.to_svg(theme));
+        }
 
-        Ok(self)
+        res
     }
 }
 
-impl View for AreaView {
+impl<X, Y> View for AreaView<X, Y> {
     /// Get area SVG representation.
     fn to_svg(&self) -> svg::node::element::Group {
-        let mut res = svg::node::element::Group::new();
-        res.append(self.area.to_svg());
+        self.to_svg_with_ranges(self.x_range, self.y_range, &Theme::default())
+    }
 
-        res
+    /// Get area SVG representation for the provided view size.
+    fn to_svg_with_size(&self, width: i32, height: i32) -> svg::node::element::Group {
+        self.to_svg_with_theme(width, height, &Theme::default())
+    }
+
+    /// Get area SVG representation for the provided view size and theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        self.to_svg_with_ranges(self.x_range.fit(width), self.y_range.fit(height), theme)
+    }
+
+    /// Get count of area series that take their colors from the chart palette.
+    fn palette_series_count(&self) -> usize {
+        self.default_colors as usize
+    }
+
+    /// Get area legend entries.
+    fn legend_entries(&self) -> Vec<LegendEntry> {
+        if self.name.is_empty() {
+            return Vec::new();
+        }
+
+        let entry = LegendEntry::new(
+            &self.name,
+            LegendMarker::Square,
+            &self.fill_color,
+            &self.stroke_color,
+        );
+        if self.default_colors {
+            return vec![entry.set_palette_series(0)];
+        }
+
+        vec![entry]
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Color;
+    use crate::{Color, LogScale};
 
     #[test]
     fn area_basic() {
         let expected_svg_group = r##"<g>
 <g class="area">
-<g class="point" transform="translate(13.414631,99.01)">
-<g>
-<line stroke="#ffffff" stroke-width="2px" x1="-5" x2="5" y1="-5" y2="5"/>
-<line stroke="#ffffff" stroke-width="2px" x1="5" x2="-5" y1="-5" y2="5"/>
-</g>
-<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="0" y="17">
-89.1
-</text>
-</g>
-<g class="point" transform="translate(37.804874,99.865555)">
-<g>
-<line stroke="#ffffff" stroke-width="2px" x1="-5" x2="5" y1="-5" y2="5"/>
-<line stroke="#ffffff" stroke-width="2px" x1="5" x2="-5" y1="-5" y2="5"/>
-</g>
-<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="0" y="17">
-12.1
-</text>
-</g>
-<g class="point" transform="translate(62.195118,99.5)">
-<g>
-<line stroke="#ffffff" stroke-width="2px" x1="-5" x2="5" y1="-5" y2="5"/>
-<line stroke="#ffffff" stroke-width="2px" x1="5" x2="-5" y1="-5" y2="5"/>
-</g>
-<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="0" y="17">
-45
-</text>
-</g>
-<g class="point" transform="translate(86.585365,99.76667)">
-<g>
-<line stroke="#ffffff" stroke-width="2px" x1="-5" x2="5" y1="-5" y2="5"/>
-<line stroke="#ffffff" stroke-width="2px" x1="5" x2="-5" y1="-5" y2="5"/>
-</g>
-<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="0" y="17">
-21
-</text>
-</g>
-<g class="point" transform="translate(86.585365,100)"/>
-<g class="point" transform="translate(13.414631,100)"/>
-<path d="M13.414631,99.01 L37.804874,99.865555 L62.195118,99.5 L86.585365,99.76667 L86.585365,100 L13.414631,100 z" fill="#038d05" stroke="#ff006c"/>
-<g class="point" transform="translate(13.414631,99.01)">
+<path d="M13.41463,99.01 L37.804874,99.865555 L62.195118,99.5 L86.585365,99.76667 L86.585365,100 L13.41463,100 z" fill="#038d05" stroke="#ff006c"/>
+<g class="point" transform="translate(13.41463,99.01)">
 <g>
 <line stroke="#ffffff" stroke-width="2px" x1="-5" x2="5" y1="-5" y2="5"/>
 <line stroke="#ffffff" stroke-width="2px" x1="5" x2="-5" y1="-5" y2="5"/>
@@ -276,7 +448,7 @@ mod tests {
 </text>
 </g>
 <g class="point" transform="translate(86.585365,100)"/>
-<g class="point" transform="translate(13.414631,100)"/>
+<g class="point" transform="translate(13.41463,100)"/>
 </g>
 </g>"##;
 
@@ -303,4 +475,91 @@ mod tests {
         let area_svg = area.to_svg();
         assert_eq!(area_svg.to_string(), expected_svg_group);
     }
+
+    #[test]
+    fn area_baseline() {
+        let x_scale = BandScale::new(vec!["a".to_string(), "b".to_string()], 0, 100)
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+        let y_scale = LinearScale::new(-10_f32, 10_f32, 100, 0);
+        let data = vec![5_f32, -5_f32];
+
+        let area = AreaView::new(x_scale.clone(), y_scale.clone())
+            .set_data(&data)
+            .expect("unable to set data");
+        assert!(area
+            .to_svg()
+            .to_string()
+            .contains(r##"<path d="M25,25 L75,75 L75,50 L25,50 z""##));
+
+        let area = AreaView::new(x_scale.clone(), y_scale.clone())
+            .set_baseline(AreaBaseline::Low(vec![-10_f32, 0_f32]))
+            .set_data(&data)
+            .expect("unable to set data");
+        assert!(area
+            .to_svg()
+            .to_string()
+            .contains(r##"<path d="M25,25 L75,75 L75,50 L25,100 z""##));
+
+        assert!(matches!(
+            AreaView::new(x_scale, y_scale)
+                .set_baseline(AreaBaseline::Low(vec![0_f32]))
+                .set_data(&data),
+            Err(Error::AreaBaselineIsInvalid)
+        ));
+    }
+
+    #[test]
+    fn area_missing_values() {
+        let x_scale = BandScale::new(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            0,
+            90,
+        )
+        .set_inner_padding(0_f32)
+        .set_outer_padding(0_f32);
+        let y_scale = LinearScale::new(0_f32, 100_f32, 100, 0);
+        let data = vec![10_f32, f32::NAN, 30_f32];
+
+        let area_svg = AreaView::new(x_scale.clone(), y_scale.clone())
+            .set_data(&data)
+            .expect("unable to set data")
+            .to_svg()
+            .to_string();
+        assert!(!area_svg.contains("<path"));
+        assert!(area_svg.contains(r##"<g class="point" transform="translate(15,90)">"##));
+        assert!(area_svg.contains(r##"<g class="point" transform="translate(75,70)">"##));
+
+        let area_svg = AreaView::new(x_scale, y_scale)
+            .set_missing_values(MissingValues::Bridge)
+            .set_data(&data)
+            .expect("unable to set data")
+            .to_svg()
+            .to_string();
+        assert!(area_svg.contains(r##"<path d="M15,90 L75,70 L75,100 L15,100 z""##));
+    }
+
+    #[test]
+    fn area_missing_values_log_scale() {
+        let x_scale = BandScale::new(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            0,
+            90,
+        )
+        .set_inner_padding(0_f32)
+        .set_outer_padding(0_f32);
+        let y_scale = LogScale::new(1_f32, 100_f32, 100, 0).expect("unable to create scale");
+        let data = vec![10_f32, f32::NAN, 100_f32];
+
+        // Zero is outside of the log scale domain so the area is broken at the missing value.
+        let area_svg = AreaView::new(x_scale, y_scale)
+            .set_missing_values(MissingValues::Zero)
+            .set_data(&data)
+            .expect("unable to set data")
+            .to_svg()
+            .to_string();
+        assert!(!area_svg.contains("<path"));
+        assert!(area_svg.contains(r##"<g class="point" transform="translate(15,50)">"##));
+        assert!(area_svg.contains(r##"<g class="point" transform="translate(75,0)">"##));
+    }
 }
@@ -0,0 +1,331 @@
+use crate::color::{COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_5};
+use crate::render::svg::*;
+use crate::scale::fit_scale;
+use crate::shape::box_plot::BoxPlot;
+use crate::shape::point::Point;
+use crate::Theme;
+use crate::{
+    BandScale, BoxPlotSummary, Color, Error, LegendEntry, LegendMarker, LinearScale, Orientation,
+    PointType, Scale, View,
+};
+use svg::node::Node;
+
+const DEFAULT_ORIENTATION: Orientation = Orientation::Vertical;
+const DEFAULT_WHISKERS: BoxPlotWhiskers = BoxPlotWhiskers::Iqr(1.5_f32);
+const DEFAULT_OUTLIER_POINT_TYPE: PointType = PointType::Circle;
+const DEFAULT_OUTLIER_POINT_SIZE: i32 = 3;
+
+/// BoxPlotWhiskers contains available ways to calculate whiskers from raw samples.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BoxPlotWhiskers {
+    /// Whiskers end at the furthest samples within the provided multiple of the interquartile range
+    /// from the box, samples beyond them are outliers. The common multiple is 1.5.
+    Iqr(f32),
+
+    /// Whiskers end at the minimum and maximum samples and there are no outliers.
+    MinMax,
+}
+
+/// BoxPlotView represents a chart view with box-and-whisker plots for categories.
+/// Boxes are placed on the band scale and their values on the value scale, vertical orientation
+/// uses the band scale for x axis and horizontal orientation uses it for y axis.
+#[derive(Clone)]
+pub struct BoxPlotView<S = LinearScale> {
+    band_scale: BandScale,
+    value_scale: S,
+    orientation: Orientation,
+    whiskers: BoxPlotWhiskers,
+    name: String,
+    fill_color: String,
+    stroke_color: String,
+    default_colors: bool,
+    outlier_point_type: PointType,
+    outlier_fill_color: String,
+    outlier_stroke_color: String,
+    summaries: Vec<BoxPlotSummary>,
+}
+
+impl<S: Scale<f32>> BoxPlotView<S> {
+    /// Create a new BoxPlotView.
+    pub fn new(band_scale: BandScale, value_scale: S) -> Self {
+        Self {
+            band_scale,
+            value_scale,
+            orientation: DEFAULT_ORIENTATION,
+            whiskers: DEFAULT_WHISKERS,
+            name: String::new(),
+            fill_color: COLOR_HEX_BLUE_5.to_string(),
+            stroke_color: COLOR_HEX_BLUE_1.to_string(),
+            default_colors: true,
+            outlier_point_type: DEFAULT_OUTLIER_POINT_TYPE,
+            outlier_fill_color: COLOR_HEX_BLUE_5.to_string(),
+            outlier_stroke_color: COLOR_HEX_BLUE_1.to_string(),
+            summaries: Vec::new(),
+        }
+    }
+
+    /// Configure orientation of box plots.
+    pub fn set_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Configure how whiskers are calculated from raw samples.
+    pub fn set_whiskers(mut self, whiskers: BoxPlotWhiskers) -> Self {
+        self.whiskers = whiskers;
+        self
+    }
+
+    /// Set box plots name that is used in a chart legend.
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set boxes fill color.
+    pub fn set_fill_color(mut self, fill_color: Color) -> Self {
+        self.fill_color = fill_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    /// Set boxes and whiskers stroke color.
+    pub fn set_stroke_color(mut self, stroke_color: Color) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    /// Set outliers point type.
+    pub fn set_outlier_point_type(mut self, outlier_point_type: PointType) -> Self {
+        self.outlier_point_type = outlier_point_type;
+        self
+    }
+
+    /// Set outliers fill color.
+    pub fn set_outlier_fill_color(mut self, outlier_fill_color: Color) -> Self {
+        self.outlier_fill_color = outlier_fill_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    /// Set outliers stroke color.
+    pub fn set_outlier_stroke_color(mut self, outlier_stroke_color: Color) -> Self {
+        self.outlier_stroke_color = outlier_stroke_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    // Get box plot view SVG representation for the provided scales.
+    fn to_svg_with_scales(
+        &self,
+        band_scale: &BandScale,
+        value_scale: &S,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        let categories = band_scale.ticks();
+        let center = band_scale.bandwidth() / 2_f32;
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_BOX_PLOTS);
+
+        for (summary, category) in self.summaries.iter().zip(categories.iter()) {
+            let values = [
+                summary.min(),
+                summary.first_quartile(),
+                summary.median(),
+                summary.third_quartile(),
+                summary.max(),
+            ];
+            let outliers = summary
+                .outliers()
+                .iter()
+                .map(|outlier| {
+                    let position = value_scale.scale(outlier);
+                    let (x, y) = match self.orientation {
+                        Orientation::Horizontal => (position, center),
+                        Orientation::Vertical => (center, position),
+                    };
+                    Point::new(
+                        x,
+                        y,
+                        self.outlier_point_type,
+                        DEFAULT_OUTLIER_POINT_SIZE,
+                        &value_scale.tick_label(outlier),
+                        &self.outlier_fill_color,
+                        &self.outlier_stroke_color,
+                    )
+                    .set_label_visible(false)
+                })
+                .collect();
+
+            let box_plot = BoxPlot::new(
+                value_scale.scale(&values[0]),
+                value_scale.scale(&values[1]),
+                value_scale.scale(&values[2]),
+                value_scale.scale(&values[3]),
+                value_scale.scale(&values[4]),
+                band_scale.bandwidth(),
+                band_scale.scale(category),
+                self.orientation,
+            )
+            .set_fill_color(&self.fill_color)
+            .set_stroke_color(&self.stroke_color)
+            .set_outliers(outliers);
+            // Box plots without explicitly set colors take them from the theme palette.
+            let box_plot = if self.default_colors {
+                box_plot.set_palette_series(0)
+            } else {
+                box_plot
+            };
+            res.append(box_plot.to_svg(theme));
+        }
+
+        res
+    }
+
+    /// Set raw samples for each category of the band scale.
+    pub fn set_data(self, samples: &[Vec<f32>]) -> Result<Self, Error> {
+        let summaries = samples
+            .iter()
+            .map(|category_samples| BoxPlotSummary::from_samples(category_samples, self.whiskers))
+            .collect::<Result<Vec<BoxPlotSummary>, Error>>()?;
+
+        self.set_summaries(&summaries)
+    }
+
+    /// Set precomputed five-number summaries for each category of the band scale.
+    pub fn set_summaries(mut self, summaries: &[BoxPlotSummary]) -> Result<Self, Error> {
+        if summaries.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+        let categories = self.band_scale.ticks();
+        if summaries.len() > categories.len() {
+            return Err(Error::CategoriesCountIsLess);
+        }
+
+        for summary in summaries.iter() {
+            if [
+                summary.min(),
+                summary.first_quartile(),
+                summary.median(),
+                summary.third_quartile(),
+                summary.max(),
+            ]
+            .iter()
+            .chain(summary.outliers().iter())
+            .any(|value| !self.value_scale.accepts(value))
+            {
+                return Err(Error::ValueIsNotInScaleDomain);
+            }
+        }
+        self.summaries = summaries.to_vec();
+
+        Ok(self)
+    }
+}
+
+impl<S: Scale<f32> + Clone> View for BoxPlotView<S> {
+    /// Get box plot view SVG representation.
+    fn to_svg(&self) -> svg::node::element::Group {
+        self.to_svg_with_scales(&self.band_scale, &self.value_scale, &Theme::default())
+    }
+
+    /// Get box plot view SVG representation for the provided view size.
+    fn to_svg_with_size(&self, width: i32, height: i32) -> svg::node::element::Group {
+        self.to_svg_with_theme(width, height, &Theme::default())
+    }
+
+    /// Get box plot view SVG representation for the provided view size and theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        let (band_size, value_size) = match self.orientation {
+            Orientation::Horizontal => (height, width),
+            Orientation::Vertical => (width, height),
+        };
+
+        self.to_svg_with_scales(
+            &fit_scale(&self.band_scale, band_size),
+            &fit_scale(&self.value_scale, value_size),
+            theme,
+        )
+    }
+
+    /// Get count of box plot series that take their colors from the chart palette.
+    fn palette_series_count(&self) -> usize {
+        self.default_colors as usize
+    }
+
+    /// Get box plot view legend entries.
+    fn legend_entries(&self) -> Vec<LegendEntry> {
+        if self.name.is_empty() {
+            return Vec::new();
+        }
+
+        let entry = LegendEntry::new(
+            &self.name,
+            LegendMarker::Square,
+            &self.fill_color,
+            &self.stroke_color,
+        );
+        if self.default_colors {
+            return vec![entry.set_palette_series(0)];
+        }
+
+        vec![entry]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Palette;
+
+    #[test]
+    fn box_plot_horizontal() {
+        let expected_svg_group = r##"<g class="box-plots">
+<g class="box-plot" transform="translate(0,0)">
+<line stroke="#0e3569" stroke-width="1" x1="10" x2="30.000002" y1="10" y2="10"/>
+<line stroke="#0e3569" stroke-width="1" x1="70" x2="80" y1="10" y2="10"/>
+<line stroke="#0e3569" stroke-width="1" x1="10" x2="10" y1="5" y2="15"/>
+<line stroke="#0e3569" stroke-width="1" x1="80" x2="80" y1="5" y2="15"/>
+<rect fill="#a5c9f2" height="20" shape-rendering="crispEdges" stroke="#0e3569" stroke-width="1" width="40" x="30.000002" y="0"/>
+<line stroke="#0e3569" stroke-width="2" x1="50" x2="50" y1="0" y2="20"/>
+<g class="point" transform="translate(100,10)">
+<rect fill="#a5c9f2" height="6" stroke="#0e3569" width="6" x="-3" y="-3"/>
+</g>
+</g>
+</g>"##;
+
+        let band_scale = BandScale::new(vec!["A".to_string()], 0, 20)
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+        let value_scale = LinearScale::new(0_f32, 10_f32, 0, 100);
+        let data = vec![vec![
+            1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32, 10_f32,
+        ]];
+        let box_plot = BoxPlotView::new(band_scale, value_scale)
+            .set_orientation(Orientation::Horizontal)
+            .set_outlier_point_type(PointType::Square)
+            .set_whiskers(BoxPlotWhiskers::Iqr(0.5_f32))
+            .set_data(&data)
+            .expect("unable to set data");
+        assert_eq!(box_plot.to_svg().to_string(), expected_svg_group);
+    }
+
+    #[test]
+    fn box_plot_palette() {
+        let band_scale = BandScale::new(vec!["A".to_string()], 0, 20);
+        let value_scale = LinearScale::new(0_f32, 10_f32, 0, 100);
+        let box_plot = BoxPlotView::new(band_scale, value_scale)
+            .set_data(&[vec![1_f32, 2_f32, 3_f32, 4_f32, 5_f32]])
+            .expect("unable to set data");
+        let theme = Theme::default().set_palette(Palette::okabe_ito());
+        let box_plot_svg = box_plot.to_svg_with_theme(20, 100, &theme).to_string();
+        assert!(box_plot_svg.contains(r##"fill="#e69f00" fill-opacity="0.3""##));
+        assert!(!box_plot_svg.contains(COLOR_HEX_BLUE_1));
+        assert_eq!(box_plot.palette_series_count(), 1);
+    }
+}
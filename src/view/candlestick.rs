@@ -0,0 +1,402 @@
+use crate::color::{COLOR_HEX_GREEN_2, COLOR_HEX_GREEN_4, COLOR_HEX_RED_2, COLOR_HEX_RED_4};
+use crate::render::svg::*;
+use crate::scale::{fit_scale, ScaleRange};
+use crate::shape::bar::Bar;
+use crate::shape::candle::{Candle, CandlestickStyle};
+use crate::view::scale_position;
+use crate::Theme;
+use crate::{
+    BandScale, Color, ContinuousScale, Error, LegendEntry, LegendMarker, LinearScale, Ohlc,
+    Orientation, Scale, View,
+};
+use svg::node::Node;
+
+const DEFAULT_STYLE: CandlestickStyle = CandlestickStyle::Candlestick;
+
+// Candles on continuous scales take this part of the smallest distance between adjacent candles.
+const DEFAULT_CANDLE_WIDTH_RATIO: f32 = 0.7_f32;
+
+// Width of a single candle on a continuous scale.
+const DEFAULT_SINGLE_CANDLE_WIDTH: f32 = 10_f32;
+
+const DEFAULT_VOLUME_VISIBLE: bool = false;
+const DEFAULT_VOLUME_PANEL_HEIGHT: i32 = 80;
+const DEFAULT_VOLUME_PANEL_GAP: i32 = 10;
+
+/// CandlestickView represents a chart view with candlesticks or OHLC bars of trading periods.
+/// An optional volume panel is drawn at the bottom of the view right below the y scale range,
+/// chart y axes leave space for it.
+#[derive(Clone)]
+pub struct CandlestickView<X = BandScale, Y = LinearScale> {
+    x_scale: X,
+    y_scale: Y,
+    style: CandlestickStyle,
+    name: String,
+    up_fill_color: String,
+    up_stroke_color: String,
+    down_fill_color: String,
+    down_stroke_color: String,
+    candle_width: Option<f32>,
+    volume_visible: bool,
+    volume_panel_height: i32,
+    data: Vec<(f32, Ohlc)>,
+    default_candle_width: f32,
+    x_range: ScaleRange,
+}
+
+impl<X, Y: Scale<f32>> CandlestickView<X, Y> {
+    /// Create a new CandlestickView.
+    pub fn new(x_scale: X, y_scale: Y) -> Self {
+        Self {
+            x_scale,
+            y_scale,
+            style: DEFAULT_STYLE,
+            name: String::new(),
+            up_fill_color: COLOR_HEX_GREEN_4.to_string(),
+            up_stroke_color: COLOR_HEX_GREEN_2.to_string(),
+            down_fill_color: COLOR_HEX_RED_4.to_string(),
+            down_stroke_color: COLOR_HEX_RED_2.to_string(),
+            candle_width: None,
+            volume_visible: DEFAULT_VOLUME_VISIBLE,
+            volume_panel_height: DEFAULT_VOLUME_PANEL_HEIGHT,
+            data: Vec::new(),
+            default_candle_width: 0_f32,
+            x_range: ScaleRange::default(),
+        }
+    }
+
+    /// Configure style of trading periods.
+    pub fn set_style(mut self, style: CandlestickStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set view name that is used in a chart legend.
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set fill color of periods where close price is not lower than open price.
+    pub fn set_up_fill_color(mut self, up_fill_color: Color) -> Self {
+        self.up_fill_color = up_fill_color.to_string();
+        self
+    }
+
+    /// Set stroke color of periods where close price is not lower than open price.
+    pub fn set_up_stroke_color(mut self, up_stroke_color: Color) -> Self {
+        self.up_stroke_color = up_stroke_color.to_string();
+        self
+    }
+
+    /// Set fill color of periods where close price is lower than open price.
+    pub fn set_down_fill_color(mut self, down_fill_color: Color) -> Self {
+        self.down_fill_color = down_fill_color.to_string();
+        self
+    }
+
+    /// Set stroke color of periods where close price is lower than open price.
+    pub fn set_down_stroke_color(mut self, down_stroke_color: Color) -> Self {
+        self.down_stroke_color = down_stroke_color.to_string();
+        self
+    }
+
+    /// Set candle width, candles on band scales use the scale bandwidth by default
+    /// and candles on continuous scales depend on the distance between them.
+    pub fn set_candle_width(mut self, candle_width: f32) -> Self {
+        self.candle_width = Some(candle_width);
+        self
+    }
+
+    /// Configure volume panel visibility.
+    pub fn set_volume_visible(mut self, volume_visible: bool) -> Self {
+        self.volume_visible = volume_visible;
+        self
+    }
+
+    /// Set volume panel height.
+    pub fn set_volume_panel_height(mut self, volume_panel_height: i32) -> Self {
+        self.volume_panel_height = volume_panel_height;
+        self
+    }
+
+    // Keep the provided values with their candle centers and the candle width
+    // that are relative to the x scale range.
+    fn set_candles(&mut self, centers: &[f32], width: f32, data: &[Ohlc]) -> Result<(), Error> {
+        if data.iter().any(|ohlc| !ohlc.is_valid()) {
+            return Err(Error::OhlcValuesAreInvalid);
+        }
+        if data
+            .iter()
+            .any(|ohlc| !self.y_scale.accepts(&ohlc.high()) || !self.y_scale.accepts(&ohlc.low()))
+        {
+            return Err(Error::ValueIsNotInScaleDomain);
+        }
+
+        self.data = centers.iter().cloned().zip(data.iter().cloned()).collect();
+        self.default_candle_width = width;
+
+        Ok(())
+    }
+
+    // Get candlestick view SVG representation for the provided x scale range and y scale.
+    fn to_svg_with_scales(
+        &self,
+        x_range: ScaleRange,
+        y_scale: &Y,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        let width = self
+            .candle_width
+            .unwrap_or(self.default_candle_width * x_range.size());
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_CANDLESTICK);
+
+        // Volume panel is placed below the y scale range and its bars are scaled to the max volume.
+        if self.volume_visible {
+            let max_volume = self
+                .data
+                .iter()
+                .filter_map(|(_, ohlc)| ohlc.volume())
+                .fold(0_f32, f32::max);
+            let panel_bottom = (y_scale.range_start().max(y_scale.range_end())
+                + DEFAULT_VOLUME_PANEL_GAP
+                + self.volume_panel_height) as f32;
+            let mut volume_group = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_VOLUME);
+            for (center, ohlc) in self.data.iter() {
+                let volume = match ohlc.volume() {
+                    Some(volume) if max_volume > 0_f32 => volume,
+                    _ => continue,
+                };
+                let (fill_color, stroke_color) = self.colors(ohlc);
+                let bar_height = volume / max_volume * self.volume_panel_height as f32;
+                let volume_bar = Bar::new(
+                    panel_bottom - bar_height,
+                    panel_bottom,
+                    volume,
+                    width,
+                    x_range.interpolate(*center) - width / 2_f32,
+                    Orientation::Vertical,
+                )
+                .set_fill_color(fill_color)
+                .set_stroke_color(stroke_color)
+                .set_label_visible(false);
+                volume_group.append(volume_bar.to_svg(theme));
+            }
+            res.append(volume_group);
+        }
+
+        for (center, ohlc) in self.data.iter() {
+            let (fill_color, stroke_color) = self.colors(ohlc);
+            let candle = Candle::new(
+                y_scale.scale(&ohlc.open()),
+                y_scale.scale(&ohlc.high()),
+                y_scale.scale(&ohlc.low()),
+                y_scale.scale(&ohlc.close()),
+                width,
+                x_range.interpolate(*center) - width / 2_f32,
+                self.style,
+            )
+            .set_fill_color(fill_color)
+            .set_stroke_color(stroke_color);
+            res.append(candle.to_svg(theme));
+        }
+
+        res
+    }
+
+    fn colors(&self, ohlc: &Ohlc) -> (&str, &str) {
+        if ohlc.is_up() {
+            (&self.up_fill_color, &self.up_stroke_color)
+        } else {
+            (&self.down_fill_color, &self.down_stroke_color)
+        }
+    }
+}
+
+impl<Y: Scale<f32>> CandlestickView<BandScale, Y> {
+    /// Set values for trading periods.
+    /// Values are placed on categories of x scale in the same order.
+    pub fn set_data(mut self, data: &[Ohlc]) -> Result<Self, Error> {
+        if data.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+        let categories = self.x_scale.ticks();
+        if data.len() > categories.len() {
+            return Err(Error::CategoriesCountIsLess);
+        }
+
+        let x_range = ScaleRange::new(&self.x_scale);
+        let bandwidth = self.x_scale.bandwidth();
+        let centers: Vec<f32> = categories
+            .iter()
+            .map(|category| x_range.normalize(self.x_scale.scale(category) + bandwidth / 2_f32))
+            .collect();
+        self.set_candles(&centers, bandwidth / x_range.size(), data)?;
+        self.x_range = x_range;
+
+        Ok(self)
+    }
+}
+
+impl<X: ContinuousScale, Y: Scale<f32>> CandlestickView<X, Y> {
+    /// Set values for trading periods.
+    /// Each value is paired with its position on a continuous x scale.
+    pub fn set_data(mut self, data: &[(X::Domain, Ohlc)]) -> Result<Self, Error> {
+        if data.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+        if data
+            .iter()
+            .any(|(x_value, _)| !self.x_scale.accepts(x_value))
+        {
+            return Err(Error::ValueIsNotInScaleDomain);
+        }
+
+        let x_range = ScaleRange::new(&self.x_scale);
+        let centers: Vec<f32> = data
+            .iter()
+            .map(|(x_value, _)| scale_position(&self.x_scale, x_value))
+            .collect();
+        let width = {
+            let mut sorted_centers = centers.clone();
+            sorted_centers.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            sorted_centers
+                .windows(2)
+                .map(|pair| pair[1] - pair[0])
+                .filter(|distance| *distance > 0_f32)
+                .fold(None, |min: Option<f32>, distance| {
+                    Some(min.map_or(distance, |min| min.min(distance)))
+                })
+                .map_or(DEFAULT_SINGLE_CANDLE_WIDTH / x_range.size(), |distance| {
+                    distance * DEFAULT_CANDLE_WIDTH_RATIO
+                })
+        };
+        let values: Vec<Ohlc> = data.iter().map(|(_, ohlc)| *ohlc).collect();
+        self.set_candles(&centers, width, &values)?;
+        self.x_range = x_range;
+
+        Ok(self)
+    }
+}
+
+impl<X, Y: Scale<f32> + Clone> View for CandlestickView<X, Y> {
+    /// Get candlestick view SVG representation.
+    fn to_svg(&self) -> svg::node::element::Group {
+        self.to_svg_with_scales(self.x_range, &self.y_scale, &Theme::default())
+    }
+
+    /// Get candlestick view SVG representation for the provided view size.
+    fn to_svg_with_size(&self, width: i32, height: i32) -> svg::node::element::Group {
+        self.to_svg_with_theme(width, height, &Theme::default())
+    }
+
+    /// Get candlestick view SVG representation for the provided view size and theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        self.to_svg_with_scales(
+            self.x_range.fit(width),
+            &fit_scale(&self.y_scale, height - self.bottom_panel_height()),
+            theme,
+        )
+    }
+
+    /// Get height of the volume panel and its gap below the y scale range.
+    fn bottom_panel_height(&self) -> i32 {
+        if !self.volume_visible {
+            return 0;
+        }
+        DEFAULT_VOLUME_PANEL_GAP + self.volume_panel_height
+    }
+
+    /// Get candlestick view legend entries.
+    fn legend_entries(&self) -> Vec<LegendEntry> {
+        if self.name.is_empty() {
+            return Vec::new();
+        }
+
+        vec![LegendEntry::new(
+            &self.name,
+            LegendMarker::Square,
+            &self.up_fill_color,
+            &self.up_stroke_color,
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn candlestick_basic() {
+        let expected_svg_group = r##"<g class="candlestick">
+<g class="volume">
+<g class="bar" transform="translate(5,0)">
+<rect fill="#117401" height="40" shape-rendering="crispEdges" stroke="#00400e" stroke-width="1" width="30" x="0" y="110"/>
+</g>
+<g class="bar" transform="translate(45,0)">
+<rect fill="#d93a3a" height="20" shape-rendering="crispEdges" stroke="#8c1414" stroke-width="1" width="30" x="0" y="130"/>
+</g>
+</g>
+<g class="candle" transform="translate(5,0)">
+<line stroke="#00400e" stroke-width="1" x1="15" x2="15" y1="10" y2="90"/>
+<g class="bar" transform="translate(0,0)">
+<rect fill="#117401" height="40" shape-rendering="crispEdges" stroke="#00400e" stroke-width="1" width="30" x="0" y="30"/>
+</g>
+</g>
+<g class="candle" transform="translate(45,0)">
+<line stroke="#8c1414" stroke-width="1" x1="15" x2="15" y1="20" y2="90"/>
+<g class="bar" transform="translate(0,0)">
+<rect fill="#d93a3a" height="50" shape-rendering="crispEdges" stroke="#8c1414" stroke-width="1" width="30" x="0" y="25"/>
+</g>
+</g>
+</g>"##;
+
+        let x_scale = LinearScale::new(0_f32, 80_f32, 0, 80);
+        let y_scale = LinearScale::new(0_f32, 100_f32, 100, 0);
+        let data = vec![
+            (
+                20_f32,
+                Ohlc::new(30_f32, 90_f32, 10_f32, 70_f32).set_volume(200_f32),
+            ),
+            (
+                60_f32,
+                Ohlc::new(75_f32, 80_f32, 10_f32, 25_f32).set_volume(100_f32),
+            ),
+        ];
+        let candlestick = CandlestickView::new(x_scale.clone(), y_scale.clone())
+            .set_candle_width(30_f32)
+            .set_volume_visible(true)
+            .set_volume_panel_height(40)
+            .set_data(&data)
+            .expect("unable to set data");
+        assert_eq!(candlestick.to_svg().to_string(), expected_svg_group);
+
+        // Volume panel that is set after data is laid out below the fitted y scale range.
+        let candlestick = CandlestickView::new(x_scale, y_scale)
+            .set_candle_width(30_f32)
+            .set_data(&data)
+            .expect("unable to set data")
+            .set_volume_visible(true)
+            .set_volume_panel_height(40);
+        assert_eq!(candlestick.bottom_panel_height(), 50);
+        assert_eq!(
+            candlestick.to_svg_with_size(80, 150).to_string(),
+            expected_svg_group
+        );
+
+        let data = vec![(20_f32, Ohlc::new(30_f32, 20_f32, 10_f32, 70_f32))];
+        assert!(matches!(
+            CandlestickView::new(
+                LinearScale::new(0_f32, 80_f32, 0, 80),
+                LinearScale::new(0_f32, 100_f32, 100, 0)
+            )
+            .set_data(&data),
+            Err(Error::OhlcValuesAreInvalid)
+        ));
+    }
+}
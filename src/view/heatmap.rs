@@ -0,0 +1,216 @@
+use crate::render::svg::*;
+use crate::scale::fit_scale;
+use crate::shape::cell::Cell;
+use crate::Theme;
+use crate::{BandScale, ColorScale, Error, Scale, View};
+use svg::node::Node;
+
+const DEFAULT_LABEL_VISIBLE: bool = false;
+const DEFAULT_COLOR_BAR_VISIBLE: bool = true;
+
+const DEFAULT_LIGHT_LABEL_COLOR: &str = "#ffffff";
+
+// Cells with relative luminance below this threshold get light labels.
+const DEFAULT_LABEL_LUMINANCE_THRESHOLD: f32 = 0.5_f32;
+
+/// HeatmapView represents a chart view with a matrix of cells colored by their values.
+/// Cells are placed at intersections of categories of x and y band scales.
+#[derive(Clone)]
+pub struct HeatmapView {
+    x_scale: BandScale,
+    y_scale: BandScale,
+    color_scale: ColorScale,
+    label_visible: bool,
+    color_bar_visible: bool,
+    rows: Vec<Vec<f32>>,
+}
+
+impl HeatmapView {
+    /// Create a new HeatmapView.
+    pub fn new(x_scale: BandScale, y_scale: BandScale, color_scale: ColorScale) -> Self {
+        Self {
+            x_scale,
+            y_scale,
+            color_scale,
+            label_visible: DEFAULT_LABEL_VISIBLE,
+            color_bar_visible: DEFAULT_COLOR_BAR_VISIBLE,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Configure visibility of cell value labels.
+    /// Label color is chosen automatically to contrast with the cell color.
+    /// Labels are formatted with the tick format of the color scale.
+    pub fn set_label_visible(mut self, label_visible: bool) -> Self {
+        self.label_visible = label_visible;
+        self
+    }
+
+    /// Configure visibility of the color bar in a chart legend.
+    pub fn set_color_bar_visible(mut self, color_bar_visible: bool) -> Self {
+        self.color_bar_visible = color_bar_visible;
+        self
+    }
+
+    // Get label color that contrasts with the provided cell color.
+    fn label_color(rgb: (u8, u8, u8)) -> &'static str {
+        let luminance =
+            (0.2126_f32 * rgb.0 as f32 + 0.7152_f32 * rgb.1 as f32 + 0.0722_f32 * rgb.2 as f32)
+                / 255_f32;
+        if luminance < DEFAULT_LABEL_LUMINANCE_THRESHOLD {
+            DEFAULT_LIGHT_LABEL_COLOR
+        } else {
+            DEFAULT_FONT_COLOR
+        }
+    }
+
+    // Get heatmap view SVG representation for the provided scales.
+    fn to_svg_with_scales(
+        &self,
+        x_scale: &BandScale,
+        y_scale: &BandScale,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        let x_categories = x_scale.ticks();
+        let y_categories = y_scale.ticks();
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_HEATMAP);
+
+        for (row, y_category) in self.rows.iter().zip(y_categories.iter()) {
+            for (value, x_category) in row.iter().zip(x_categories.iter()) {
+                if !value.is_finite() {
+                    continue;
+                }
+
+                let rgb = self.color_scale.rgb(*value);
+                let cell = Cell::new(
+                    x_scale.scale(x_category),
+                    y_scale.scale(y_category),
+                    x_scale.bandwidth(),
+                    y_scale.bandwidth(),
+                )
+                .set_fill_color(&self.color_scale.color(*value).to_string())
+                .set_label(&self.color_scale.tick_label(*value), Self::label_color(rgb))
+                .set_label_visible(self.label_visible);
+                res.append(cell.to_svg(theme));
+            }
+        }
+        res
+    }
+
+    /// Set values for cells as rows for y scale categories that contain values for x scale categories.
+    /// Non-finite values are treated as missing and their cells are not drawn.
+    pub fn set_data(mut self, rows: &[Vec<f32>]) -> Result<Self, Error> {
+        if rows.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+        let x_categories = self.x_scale.ticks();
+        let y_categories = self.y_scale.ticks();
+        if rows.len() > y_categories.len() || rows.iter().any(|row| row.len() > x_categories.len())
+        {
+            return Err(Error::CategoriesCountIsLess);
+        }
+
+        self.rows = rows.to_vec();
+
+        Ok(self)
+    }
+}
+
+impl View for HeatmapView {
+    /// Get heatmap view SVG representation.
+    fn to_svg(&self) -> svg::node::element::Group {
+        self.to_svg_with_scales(&self.x_scale, &self.y_scale, &Theme::default())
+    }
+
+    /// Get heatmap view SVG representation for the provided view size.
+    fn to_svg_with_size(&self, width: i32, height: i32) -> svg::node::element::Group {
+        self.to_svg_with_theme(width, height, &Theme::default())
+    }
+
+    /// Get heatmap view SVG representation for the provided view size and theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        self.to_svg_with_scales(
+            &fit_scale(&self.x_scale, width),
+            &fit_scale(&self.y_scale, height),
+            theme,
+        )
+    }
+
+    /// Get heatmap color scale for a chart legend.
+    fn color_scale(&self) -> Option<ColorScale> {
+        if !self.color_bar_visible {
+            return None;
+        }
+
+        Some(self.color_scale.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, TickFormat};
+
+    #[test]
+    fn heatmap_basic() {
+        let expected_svg_group = r##"<g class="heatmap">
+<g class="cell" transform="translate(0,0)">
+<rect fill="rgb(0,0,0)" height="50" shape-rendering="crispEdges" width="50" x="0" y="0"/>
+<text dy=".35em" fill="#ffffff" font-family="sans-serif" font-size="14px" text-anchor="middle" x="25" y="25">
+0
+</text>
+</g>
+<g class="cell" transform="translate(0,50)">
+<rect fill="rgb(255,255,255)" height="50" shape-rendering="crispEdges" width="50" x="0" y="0"/>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="25" y="25">
+1
+</text>
+</g>
+<g class="cell" transform="translate(50,50)">
+<rect fill="rgb(128,128,128)" height="50" shape-rendering="crispEdges" width="50" x="0" y="0"/>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="25" y="25">
+0.5
+</text>
+</g>
+</g>"##;
+
+        let categories = vec!["A".to_string(), "B".to_string()];
+        let x_scale = BandScale::new(categories.clone(), 0, 100)
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+        let y_scale = BandScale::new(categories, 0, 100)
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+        let color_scale = ColorScale::new(
+            0_f32,
+            1_f32,
+            &[
+                Color::new_from_hex("#000000"),
+                Color::new_from_hex("#ffffff"),
+            ],
+        )
+        .expect("unable to create color scale");
+        let data = vec![vec![0_f32, f32::NAN], vec![1_f32, 0.5_f32]];
+        let heatmap = HeatmapView::new(x_scale.clone(), y_scale.clone(), color_scale.clone())
+            .set_label_visible(true)
+            .set_data(&data)
+            .expect("unable to set data");
+        assert_eq!(heatmap.to_svg().to_string(), expected_svg_group);
+        assert!(heatmap.color_scale().is_some());
+
+        let heatmap = HeatmapView::new(
+            x_scale,
+            y_scale,
+            color_scale.set_tick_format(TickFormat::Percent(0)),
+        )
+        .set_label_visible(true)
+        .set_data(&[vec![0.1_f32 + 0.2_f32]])
+        .expect("unable to set data");
+        assert!(heatmap.to_svg().to_string().contains("\n30%\n"));
+    }
+}
@@ -0,0 +1,442 @@
+use crate::color::{COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_2};
+use crate::math::histogram::{bin_counts, freedman_diaconis_bin_width, sturges_bins_count};
+use crate::scale::fit_scale;
+use crate::shape::bar::Bar;
+use crate::Theme;
+use crate::{Color, Error, LegendEntry, LegendMarker, LinearScale, Orientation, Scale, View};
+use svg::node::Node;
+
+const DEFAULT_BINS: HistogramBins = HistogramBins::Sturges;
+const DEFAULT_DENSITY: bool = false;
+const DEFAULT_CUMULATIVE: bool = false;
+const DEFAULT_BAR_LABEL_VISIBLE: bool = false;
+
+// Width of the single bin that is used when all samples are equal.
+const DEFAULT_SINGLE_BIN_WIDTH: f32 = 1_f32;
+
+// Maximum count of bins, it keeps a tiny bin width from allocating an unbounded count of bars.
+const MAX_BINS_COUNT: usize = 10_000;
+
+/// HistogramBins contains available ways to split samples into bins.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HistogramBins {
+    /// Samples range is split into the provided count of bins of equal width.
+    /// Count must not exceed 10000 bins.
+    Count(usize),
+
+    /// Bins have the provided width and their edges are multiples of it.
+    /// Width must not split samples range into more than 10000 bins.
+    Width(f32),
+
+    /// Bins count is calculated with the Sturges' rule.
+    Sturges,
+
+    /// Bins width is calculated with the Freedman–Diaconis rule.
+    /// It falls back to the Sturges' rule if the width gives more than 10000 bins.
+    FreedmanDiaconis,
+}
+
+/// HistogramView represents a chart view with bars that show distribution of raw samples.
+/// Bars are placed on a continuous x scale and adjacent bars share their edges.
+#[derive(Clone)]
+pub struct HistogramView<Y = LinearScale> {
+    x_scale: LinearScale,
+    y_scale: Y,
+    bins: HistogramBins,
+    density: bool,
+    cumulative: bool,
+    name: String,
+    fill_color: String,
+    stroke_color: String,
+    default_colors: bool,
+    bar_label_visible: bool,
+    sorted_samples: Vec<f32>,
+    bin_edges: Vec<f32>,
+    bin_values: Vec<f32>,
+}
+
+impl<Y: Scale<f32>> HistogramView<Y> {
+    /// Create a new HistogramView.
+    pub fn new(x_scale: LinearScale, y_scale: Y) -> Self {
+        Self {
+            x_scale,
+            y_scale,
+            bins: DEFAULT_BINS,
+            density: DEFAULT_DENSITY,
+            cumulative: DEFAULT_CUMULATIVE,
+            name: String::new(),
+            fill_color: COLOR_HEX_BLUE_2.to_string(),
+            stroke_color: COLOR_HEX_BLUE_1.to_string(),
+            default_colors: true,
+            bar_label_visible: DEFAULT_BAR_LABEL_VISIBLE,
+            sorted_samples: Vec::new(),
+            bin_edges: Vec::new(),
+            bin_values: Vec::new(),
+        }
+    }
+
+    /// Configure how samples are split into bins, samples that are already set are split again.
+    /// Returns an error if the bins count or width is invalid for the rule or for the samples.
+    pub fn set_bins(mut self, bins: HistogramBins) -> Result<Self, Error> {
+        let bins_are_valid = match bins {
+            HistogramBins::Count(count) => count > 0 && count <= MAX_BINS_COUNT,
+            HistogramBins::Width(width) => width.is_finite() && width > 0_f32,
+            HistogramBins::Sturges | HistogramBins::FreedmanDiaconis => true,
+        };
+        if !bins_are_valid {
+            return Err(Error::HistogramBinsAreInvalid);
+        }
+
+        self.bins = bins;
+        if !self.sorted_samples.is_empty() {
+            self.bin_edges = self.calculate_bin_edges(&self.sorted_samples)?;
+            self.bin_values = self.calculate_bin_values();
+        }
+        Ok(self)
+    }
+
+    /// Configure density normalization so the total area of bars equals to 1.
+    /// Cumulative histograms are normalized by samples count so the last bar equals to 1.
+    pub fn set_density(mut self, density: bool) -> Self {
+        self.density = density;
+        self.bin_values = self.calculate_bin_values();
+        self
+    }
+
+    /// Configure cumulative mode where each bar contains samples of all previous bins.
+    pub fn set_cumulative(mut self, cumulative: bool) -> Self {
+        self.cumulative = cumulative;
+        self.bin_values = self.calculate_bin_values();
+        self
+    }
+
+    /// Set histogram name that is used in a chart legend.
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set bars fill color.
+    pub fn set_fill_color(mut self, fill_color: Color) -> Self {
+        self.fill_color = fill_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    /// Set bars stroke color.
+    pub fn set_stroke_color(mut self, stroke_color: Color) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    /// Configure label visibility for bars.
+    pub fn set_bar_label_visible(mut self, bar_label_visible: bool) -> Self {
+        self.bar_label_visible = bar_label_visible;
+        self
+    }
+
+    /// Get edges of calculated bins.
+    pub fn bin_edges(&self) -> &[f32] {
+        &self.bin_edges
+    }
+
+    /// Get values of calculated bins that are represented by bars heights.
+    pub fn bin_values(&self) -> &[f32] {
+        &self.bin_values
+    }
+
+    // Calculate edges of bins for the provided sorted samples.
+    fn calculate_bin_edges(&self, sorted_samples: &[f32]) -> Result<Vec<f32>, Error> {
+        let min = sorted_samples[0];
+        let max = sorted_samples[sorted_samples.len() - 1];
+        if (max - min).abs() < f32::EPSILON {
+            let half_width = DEFAULT_SINGLE_BIN_WIDTH / 2_f32;
+            return Ok(vec![min - half_width, max + half_width]);
+        }
+
+        let bins = match self.bins {
+            HistogramBins::FreedmanDiaconis => {
+                let width = freedman_diaconis_bin_width(sorted_samples);
+
+                // Samples with zero or tiny interquartile range fall back to the Sturges' rule.
+                if width > 0_f32 && (max - min) / width < MAX_BINS_COUNT as f32 {
+                    HistogramBins::Width(width)
+                } else {
+                    HistogramBins::Count(sturges_bins_count(sorted_samples.len()))
+                }
+            }
+            HistogramBins::Sturges => {
+                HistogramBins::Count(sturges_bins_count(sorted_samples.len()))
+            }
+            bins => bins,
+        };
+
+        match bins {
+            HistogramBins::Count(count) => {
+                if count == 0 || count > MAX_BINS_COUNT {
+                    return Err(Error::HistogramBinsAreInvalid);
+                }
+                let width = (max - min) / count as f32;
+                let mut edges: Vec<f32> = (0..count).map(|i| min + width * i as f32).collect();
+                edges.push(max);
+                Ok(edges)
+            }
+            HistogramBins::Width(width) => {
+                if !width.is_finite() || width <= 0_f32 {
+                    return Err(Error::HistogramBinsAreInvalid);
+                }
+                let start = (min / width).floor() * width;
+                let bins_count = ((max - start) / width).ceil();
+                if bins_count > MAX_BINS_COUNT as f32 {
+                    return Err(Error::HistogramBinsAreInvalid);
+                }
+                let mut count = (bins_count as usize).max(1);
+
+                // Rounding errors must not leave the largest sample outside of the last bin.
+                while start + width * (count as f32) < max {
+                    if count >= MAX_BINS_COUNT {
+                        return Err(Error::HistogramBinsAreInvalid);
+                    }
+                    count += 1;
+                }
+                Ok((0..=count).map(|i| start + width * i as f32).collect())
+            }
+            // Bins rules are resolved to count or width above.
+            HistogramBins::Sturges | HistogramBins::FreedmanDiaconis => {
+                Err(Error::HistogramBinsAreInvalid)
+            }
+        }
+    }
+
+    // Calculate values of bins for the samples and bin edges that are set.
+    fn calculate_bin_values(&self) -> Vec<f32> {
+        let edges = &self.bin_edges;
+        let counts = bin_counts(&self.sorted_samples, edges);
+        let samples_count = self.sorted_samples.len() as f32;
+
+        let mut values = Vec::with_capacity(counts.len());
+        let mut count_acc = 0;
+        for (i, count) in counts.iter().enumerate() {
+            count_acc += count;
+            let count = if self.cumulative { count_acc } else { *count };
+            let value = match (self.density, self.cumulative) {
+                (false, _) => count as f32,
+                (true, false) => count as f32 / (samples_count * (edges[i + 1] - edges[i])),
+                (true, true) => count as f32 / samples_count,
+            };
+            values.push(value);
+        }
+
+        values
+    }
+
+    // Get histogram view SVG representation for the provided scales.
+    fn to_svg_with_scales(
+        &self,
+        x_scale: &LinearScale,
+        y_scale: &Y,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new();
+
+        for (i, value) in self.bin_values.iter().enumerate() {
+            let (start, end) = if y_scale.is_range_reversed() {
+                (y_scale.scale(value), y_scale.scale(&0_f32))
+            } else {
+                (y_scale.scale(&0_f32), y_scale.scale(value))
+            };
+            let offset = x_scale.scale(&self.bin_edges[i]);
+            let width = x_scale.scale(&self.bin_edges[i + 1]) - offset;
+
+            let bar = Bar::new(start, end, *value, width, offset, Orientation::Vertical)
+                .set_fill_color(&self.fill_color)
+                .set_stroke_color(&self.stroke_color)
+                .set_label(&y_scale.tick_label(value))
+                .set_label_visible(self.bar_label_visible);
+            // Bars without explicitly set colors take them from the theme palette.
+            let bar = if self.default_colors {
+                bar.set_palette_series(0)
+            } else {
+                bar
+            };
+            res.append(bar.to_svg(theme));
+        }
+
+        res
+    }
+
+    /// Set raw samples for the histogram, non-finite samples are ignored.
+    pub fn set_data(mut self, samples: &[f32]) -> Result<Self, Error> {
+        let mut sorted_samples: Vec<f32> = samples
+            .iter()
+            .copied()
+            .filter(|sample| sample.is_finite())
+            .collect();
+        if sorted_samples.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+        sorted_samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        self.bin_edges = self.calculate_bin_edges(&sorted_samples)?;
+        self.sorted_samples = sorted_samples;
+        self.bin_values = self.calculate_bin_values();
+
+        Ok(self)
+    }
+}
+
+impl<Y: Scale<f32> + Clone> View for HistogramView<Y> {
+    /// Get histogram view SVG representation.
+    fn to_svg(&self) -> svg::node::element::Group {
+        self.to_svg_with_scales(&self.x_scale, &self.y_scale, &Theme::default())
+    }
+
+    /// Get histogram view SVG representation for the provided view size.
+    fn to_svg_with_size(&self, width: i32, height: i32) -> svg::node::element::Group {
+        self.to_svg_with_theme(width, height, &Theme::default())
+    }
+
+    /// Get histogram view SVG representation for the provided view size and theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        self.to_svg_with_scales(
+            &fit_scale(&self.x_scale, width),
+            &fit_scale(&self.y_scale, height),
+            theme,
+        )
+    }
+
+    /// Get count of histogram series that take their colors from the chart palette.
+    fn palette_series_count(&self) -> usize {
+        self.default_colors as usize
+    }
+
+    /// Get histogram view legend entries.
+    fn legend_entries(&self) -> Vec<LegendEntry> {
+        if self.name.is_empty() {
+            return Vec::new();
+        }
+
+        let entry = LegendEntry::new(
+            &self.name,
+            LegendMarker::Square,
+            &self.fill_color,
+            &self.stroke_color,
+        );
+        if self.default_colors {
+            return vec![entry.set_palette_series(0)];
+        }
+
+        vec![entry]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_basic() {
+        let expected_svg_group = r##"<g>
+<g class="bar" transform="translate(0,0)">
+<rect fill="#1960b2" height="50" shape-rendering="crispEdges" stroke="#0e3569" stroke-width="1" width="50" x="0" y="50"/>
+</g>
+<g class="bar" transform="translate(50,0)">
+<rect fill="#1960b2" height="100" shape-rendering="crispEdges" stroke="#0e3569" stroke-width="1" width="50" x="0" y="0"/>
+</g>
+</g>"##;
+
+        let x_scale = LinearScale::new(0_f32, 10_f32, 0, 100);
+        let y_scale = LinearScale::new(0_f32, 4_f32, 100, 0);
+        let data = vec![0_f32, 1_f32, 5_f32, 6_f32, 7_f32, 10_f32, f32::NAN];
+        let histogram = HistogramView::new(x_scale, y_scale)
+            .set_bins(HistogramBins::Width(5_f32))
+            .expect("unable to set bins")
+            .set_data(&data)
+            .expect("unable to set data");
+        assert_eq!(histogram.bin_edges(), &[0_f32, 5_f32, 10_f32]);
+        assert_eq!(histogram.bin_values(), &[2_f32, 4_f32]);
+        assert_eq!(histogram.to_svg().to_string(), expected_svg_group);
+    }
+
+    #[test]
+    fn histogram_density_cumulative() {
+        let x_scale = LinearScale::new(0_f32, 4_f32, 0, 100);
+        let y_scale = LinearScale::new(0_f32, 1_f32, 100, 0);
+        let data = vec![0_f32, 1_f32, 1_f32, 4_f32];
+
+        let histogram = HistogramView::new(x_scale.clone(), y_scale.clone())
+            .set_bins(HistogramBins::Count(2))
+            .expect("unable to set bins")
+            .set_density(true)
+            .set_data(&data)
+            .expect("unable to set data");
+        assert_eq!(histogram.bin_values(), &[0.375_f32, 0.125_f32]);
+
+        let histogram = HistogramView::new(x_scale, y_scale)
+            .set_bins(HistogramBins::Count(2))
+            .expect("unable to set bins")
+            .set_density(true)
+            .set_cumulative(true)
+            .set_data(&data)
+            .expect("unable to set data");
+        assert_eq!(histogram.bin_values(), &[0.75_f32, 1_f32]);
+    }
+
+    #[test]
+    fn histogram_bins_limit() {
+        let x_scale = LinearScale::new(0_f32, 1_000_f32, 0, 100);
+        let y_scale = LinearScale::new(0_f32, 10_f32, 100, 0);
+        let data = vec![0_f32, 1_000_f32];
+
+        let histogram = HistogramView::new(x_scale.clone(), y_scale.clone())
+            .set_bins(HistogramBins::Width(0.001_f32))
+            .expect("unable to set bins")
+            .set_data(&data);
+        assert!(matches!(histogram, Err(Error::HistogramBinsAreInvalid)));
+
+        let histogram = HistogramView::new(x_scale.clone(), y_scale.clone())
+            .set_bins(HistogramBins::Count(MAX_BINS_COUNT + 1));
+        assert!(matches!(histogram, Err(Error::HistogramBinsAreInvalid)));
+
+        // Freedman–Diaconis width of clustered samples with a far outlier falls back to Sturges' rule.
+        let mut data: Vec<f32> = (0..100).map(|i| i as f32 * 0.0001_f32).collect();
+        data.push(1_000_f32);
+        let histogram = HistogramView::new(x_scale, y_scale)
+            .set_bins(HistogramBins::FreedmanDiaconis)
+            .expect("unable to set bins")
+            .set_data(&data)
+            .expect("unable to set data");
+        assert_eq!(histogram.bin_values().len(), sturges_bins_count(data.len()));
+    }
+
+    #[test]
+    fn histogram_settings_after_data() {
+        let x_scale = LinearScale::new(0_f32, 4_f32, 0, 100);
+        let y_scale = LinearScale::new(0_f32, 4_f32, 100, 0);
+        let data = vec![0_f32, 1_f32, 1_f32, 4_f32];
+
+        // Samples that are already set are split again with the new settings.
+        let histogram = HistogramView::new(x_scale.clone(), y_scale.clone())
+            .set_data(&data)
+            .expect("unable to set data")
+            .set_bins(HistogramBins::Count(2))
+            .expect("unable to set bins");
+        assert_eq!(histogram.bin_edges(), &[0_f32, 2_f32, 4_f32]);
+        assert_eq!(histogram.bin_values(), &[3_f32, 1_f32]);
+
+        let histogram = histogram.set_cumulative(true).set_density(true);
+        assert_eq!(histogram.bin_values(), &[0.75_f32, 1_f32]);
+
+        let histogram = HistogramView::new(x_scale, y_scale)
+            .set_data(&[0_f32, 1_000_f32])
+            .expect("unable to set data")
+            .set_bins(HistogramBins::Width(0.001_f32));
+        assert!(matches!(histogram, Err(Error::HistogramBinsAreInvalid)));
+    }
+}
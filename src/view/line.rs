@@ -1,7 +1,14 @@
 use crate::color::{COLOR_HEX_BLUE_1, COLOR_HEX_BLUE_2};
 use crate::render::svg::*;
+use crate::scale::ScaleRange;
+use crate::shape::curve::curve_path;
 use crate::shape::point::Point;
-use crate::{BandScale, Color, Error, LinearScale, PointLabelPosition, PointType, Scale, View};
+use crate::view::{scale_position, ViewPoint};
+use crate::Theme;
+use crate::{
+    BandScale, Color, ContinuousScale, Curve, Error, LegendEntry, LegendMarker, LinearScale,
+    MissingValues, OptionalValue, PointLabelPosition, PointType, Scale, View,
+};
 use svg::Node;
 
 const DEFAULT_LABEL_VISIBLE: bool = true;
@@ -11,32 +18,46 @@ const DEFAULT_POINT_TYPE: PointType = PointType::Circle;
 const DEFAULT_POINT_VISIBLE: bool = true;
 
 const DEFAULT_LINE_STROKE_WIDTH: i32 = 2;
+const DEFAULT_CURVE: Curve = Curve::Linear;
+const DEFAULT_MISSING_VALUES: MissingValues = MissingValues::Break;
 
 /// LineView represents a single line.
 #[derive(Clone)]
-pub struct LineView {
-    x_scale: BandScale,
-    y_scale: LinearScale,
+pub struct LineView<X = BandScale, Y = LinearScale> {
+    x_scale: X,
+    y_scale: Y,
+    name: String,
     stroke_color: String,
     point_fill_color: String,
     point_stroke_color: String,
-    points: Vec<Point>,
+    default_colors: bool,
+    segments: Vec<Vec<ViewPoint>>,
+    x_range: ScaleRange,
+    y_range: ScaleRange,
+    curve: Curve,
+    missing_values: MissingValues,
     point_type: PointType,
     point_visible: bool,
     point_label_visible: bool,
     point_label_position: PointLabelPosition,
 }
 
-impl LineView {
+impl<X, Y> LineView<X, Y> {
     /// Create a new LineView.
-    pub fn new(x_scale: BandScale, y_scale: LinearScale) -> Self {
+    pub fn new(x_scale: X, y_scale: Y) -> Self {
         Self {
             x_scale,
             y_scale,
+            name: String::new(),
             stroke_color: COLOR_HEX_BLUE_1.to_string(),
             point_fill_color: COLOR_HEX_BLUE_2.to_string(),
             point_stroke_color: COLOR_HEX_BLUE_1.to_string(),
-            points: Vec::new(),
+            default_colors: true,
+            segments: Vec::new(),
+            x_range: ScaleRange::default(),
+            y_range: ScaleRange::default(),
+            curve: DEFAULT_CURVE,
+            missing_values: DEFAULT_MISSING_VALUES,
             point_type: DEFAULT_POINT_TYPE,
             point_visible: DEFAULT_POINT_VISIBLE,
             point_label_visible: DEFAULT_LABEL_VISIBLE,
@@ -44,21 +65,42 @@ impl LineView {
         }
     }
 
+    /// Set line name that is used in a chart legend.
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
     /// Set line stroke color.
     pub fn set_stroke_color(mut self, stroke_color: Color) -> Self {
         self.stroke_color = stroke_color.to_string();
+        self.default_colors = false;
+        self
+    }
+
+    /// Set curve that connects line points.
+    pub fn set_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Configure how missing values are drawn.
+    pub fn set_missing_values(mut self, missing_values: MissingValues) -> Self {
+        self.missing_values = missing_values;
         self
     }
 
     /// Set fill color for the point.
     pub fn set_point_fill_color(mut self, point_fill_color: Color) -> Self {
         self.point_fill_color = point_fill_color.to_string();
+        self.default_colors = false;
         self
     }
 
     /// Set stroke color for the point.
     pub fn set_point_stroke_color(mut self, point_stroke_color: Color) -> Self {
         self.point_stroke_color = point_stroke_color.to_string();
+        self.default_colors = false;
         self
     }
 
@@ -86,77 +128,154 @@ impl LineView {
         self
     }
 
+    // Create segments of points from the provided x and y domain values.
+    // Missing values start a new segment, get skipped or are replaced with hidden zero points.
+    fn segments<'a, XD: 'a, D: OptionalValue + 'a>(
+        &self,
+        values: impl Iterator<Item = (&'a XD, &'a D)>,
+    ) -> Result<Vec<Vec<ViewPoint>>, Error>
+    where
+        X: Scale<XD>,
+        Y: Scale<D::Value>,
+        D::Value: ToString,
+    {
+        let mut segments = vec![Vec::new()];
+        for (x_value, y_value) in values {
+            if !self.x_scale.accepts(x_value) {
+                return Err(Error::ValueIsNotInScaleDomain);
+            }
+            let (y_value, present) = match (y_value.value(), self.missing_values) {
+                (Some(y_value), _) => (y_value, true),
+                (None, MissingValues::Bridge) => continue,
+                (None, missing_values) => match D::zero() {
+                    Some(zero)
+                        if missing_values == MissingValues::Zero && self.y_scale.accepts(&zero) =>
+                    {
+                        (zero, false)
+                    }
+                    _ => {
+                        segments.push(Vec::new());
+                        continue;
+                    }
+                },
+            };
+            if !self.y_scale.accepts(&y_value) {
+                return Err(Error::ValueIsNotInScaleDomain);
+            }
+
+            let point = ViewPoint {
+                x: scale_position(&self.x_scale, x_value),
+                y: scale_position(&self.y_scale, &y_value),
+                x_label: String::new(),
+                y_label: self.y_scale.tick_label(&y_value),
+                present,
+            };
+            if let Some(segment) = segments.last_mut() {
+                segment.push(point);
+            }
+        }
+        segments.retain(|segment| !segment.is_empty());
+
+        Ok(segments)
+    }
+}
+
+impl<Y> LineView<BandScale, Y> {
     /// Set data for line points.
-    pub fn set_data(mut self, data: &[f32]) -> Result<Self, Error> {
+    /// Values are placed on categories of x scale in the same order, they can be missing.
+    pub fn set_data<D: OptionalValue>(mut self, data: &[D]) -> Result<Self, Error>
+    where
+        Y: Scale<D::Value>,
+        D::Value: ToString,
+    {
         if data.is_empty() {
             return Err(Error::DataIsEmpty);
         }
-        if data.len() != self.x_scale.ticks().len() {
+        let categories = self.x_scale.ticks();
+        if data.len() != categories.len() {
             return Err(Error::CategoriesCountDoesntEqual);
         }
 
-        // Compute offsets in case there is a non-zero bandwidth.
-        let x_bandwidth_offset = {
-            if self.x_scale.is_range_reversed() {
-                -self.x_scale.tick_offset()
-            } else {
-                self.x_scale.tick_offset()
-            }
-        };
-        let y_bandwidth_offset = {
-            if self.y_scale.is_range_reversed() {
-                -self.y_scale.tick_offset()
-            } else {
-                self.y_scale.tick_offset()
-            }
-        };
+        self.segments = self.segments(categories.iter().zip(data.iter()))?;
+        self.x_range = ScaleRange::new(&self.x_scale);
+        self.y_range = ScaleRange::new(&self.y_scale);
 
-        let categories = self.x_scale.ticks();
-        let mut points = Vec::new();
-        for (idx, value) in data.iter().enumerate() {
-            let category = &categories[idx];
-            let scaled_x = &self.x_scale.scale(category);
-            let scaled_y = self.y_scale.scale(value);
-
-            let point = Point::new(
-                scaled_x + x_bandwidth_offset,
-                scaled_y + y_bandwidth_offset,
-                self.point_type,
-                DEFAULT_POINT_SIZE,
-                &value.to_string(),
-                &self.point_fill_color.to_string(),
-                &self.point_stroke_color.to_string(),
-            )
-            .set_point_visible(self.point_visible)
-            .set_label_visible(self.point_label_visible)
-            .set_label_position(self.point_label_position);
-            points.push(point);
+        Ok(self)
+    }
+}
+
+impl<X: ContinuousScale, Y> LineView<X, Y> {
+    /// Set data for line points.
+    /// Each value is paired with its position on a continuous x scale, values can be missing.
+    pub fn set_data<D: OptionalValue>(mut self, data: &[(X::Domain, D)]) -> Result<Self, Error>
+    where
+        Y: Scale<D::Value>,
+        D::Value: ToString,
+    {
+        if data.is_empty() {
+            return Err(Error::DataIsEmpty);
         }
-        self.points = points;
+
+        self.segments = self.segments(data.iter().map(|(x_value, y_value)| (x_value, y_value)))?;
+        self.x_range = ScaleRange::new(&self.x_scale);
+        self.y_range = ScaleRange::new(&self.y_scale);
 
         Ok(self)
     }
 }
 
-impl View for LineView {
-    /// Get line SVG representation.
-    fn to_svg(&self) -> svg::node::element::Group {
+impl<X, Y> LineView<X, Y> {
+    // Get line SVG representation for the provided scale ranges.
+    fn to_svg_with_ranges(
+        &self,
+        x_range: ScaleRange,
+        y_range: ScaleRange,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
         let mut res = svg::node::element::Group::new();
-        let mut data = svg::node::element::path::Data::new();
 
-        for (point_idx, point) in self.points.iter().enumerate() {
-            if point_idx == 0 {
-                data = data.move_to((point.x(), point.y()));
-            } else {
-                data = data.line_to((point.x(), point.y()));
-            }
+        // Line without explicitly set colors takes them from the theme palette.
+        let palette_series = self.default_colors.then_some(0);
 
-            res.append(point.to_svg());
+        // Each segment starts a separate subpath of the same line.
+        let mut data = svg::node::element::path::Data::new();
+        for segment in self.segments.iter() {
+            let segment: Vec<Point> = segment
+                .iter()
+                .map(|view_point| {
+                    let point = Point::new(
+                        x_range.interpolate(view_point.x),
+                        y_range.interpolate(view_point.y),
+                        self.point_type,
+                        DEFAULT_POINT_SIZE,
+                        &view_point.y_label,
+                        &self.point_fill_color,
+                        &self.point_stroke_color,
+                    )
+                    .set_point_visible(self.point_visible && view_point.present)
+                    .set_label_visible(self.point_label_visible && view_point.present)
+                    .set_label_position(self.point_label_position);
+                    match palette_series {
+                        Some(series) => point.set_palette_series(series),
+                        None => point,
+                    }
+                })
+                .collect();
+            let coordinates: Vec<(f32, f32)> = segment.iter().map(|p| (p.x(), p.y())).collect();
+            data = curve_path(data, &coordinates, self.curve, true);
+            for point in segment.iter() {
+                res.append(point.to_svg(theme));
+            }
         }
         let line = svg::node::element::Path::new()
             .set(CLASS_ATTR, CLASS_LINE)
             .set(FILL_ATTR, FILL_NONE)
-            .set(STROKE_ATTR, self.stroke_color.clone())
+            .set(
+                STROKE_ATTR,
+                theme
+                    .palette_color(palette_series)
+                    .unwrap_or(&self.stroke_color),
+            )
             .set(STROKE_WIDTH_ATTR, DEFAULT_LINE_STROKE_WIDTH)
             .set(D_ATTR, data);
 
@@ -166,15 +285,67 @@ impl View for LineView {
     }
 }
 
+impl<X, Y> View for LineView<X, Y> {
+    /// Get line SVG representation.
+    fn to_svg(&self) -> svg::node::element::Group {
+        self.to_svg_with_ranges(self.x_range, self.y_range, &Theme::default())
+    }
+
+    /// Get line SVG representation for the provided view size.
+    fn to_svg_with_size(&self, width: i32, height: i32) -> svg::node::element::Group {
+        self.to_svg_with_theme(width, height, &Theme::default())
+    }
+
+    /// Get line SVG representation for the provided view size and theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        self.to_svg_with_ranges(self.x_range.fit(width), self.y_range.fit(height), theme)
+    }
+
+    /// Get count of line series that take their colors from the chart palette.
+    fn palette_series_count(&self) -> usize {
+        self.default_colors as usize
+    }
+
+    /// Get line legend entries.
+    fn legend_entries(&self) -> Vec<LegendEntry> {
+        if self.name.is_empty() {
+            return Vec::new();
+        }
+
+        let marker = if self.point_visible {
+            LegendMarker::LinePoint(self.point_type)
+        } else {
+            LegendMarker::Line
+        };
+        let entry = LegendEntry::new(
+            &self.name,
+            marker,
+            &self.point_fill_color,
+            &self.stroke_color,
+        );
+        if self.default_colors {
+            return vec![entry.set_palette_series(0)];
+        }
+
+        vec![entry]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Color;
+    use crate::{Color, TimeScale};
+    use chrono::{TimeZone, Utc};
 
     #[test]
     fn line_basic() {
         let expected_svg_group = r##"<g>
-<g class="point" transform="translate(13.414631,99.01)">
+<g class="point" transform="translate(13.41463,99.01)">
 <g>
 <line stroke="#ffffff" stroke-width="2px" x1="-5" x2="5" y1="-5" y2="5"/>
 <line stroke="#ffffff" stroke-width="2px" x1="5" x2="-5" y1="-5" y2="5"/>
@@ -210,7 +381,7 @@ mod tests {
 21
 </text>
 </g>
-<path class="line" d="M13.414631,99.01 L37.804874,99.865555 L62.195118,99.5 L86.585365,99.76667" fill="none" stroke="#ff006c" stroke-width="2"/>
+<path class="line" d="M13.41463,99.01 L37.804874,99.865555 L62.195118,99.5 L86.585365,99.76667" fill="none" stroke="#ff006c" stroke-width="2"/>
 </g>"##;
 
         let x_scale = BandScale::new(
@@ -236,4 +407,68 @@ mod tests {
         let line_svg = line.to_svg();
         assert_eq!(line_svg.to_string(), expected_svg_group);
     }
+
+    #[test]
+    fn line_time_scale() {
+        let expected_svg_group = r##"<g>
+<g class="point" transform="translate(0,50)"/>
+<g class="point" transform="translate(25,0)"/>
+<g class="point" transform="translate(100,100)"/>
+<path class="line" d="M0,50 L25,0 L100,100" fill="none" stroke="#0e3569" stroke-width="2"/>
+</g>"##;
+
+        let x_scale = TimeScale::new(
+            Utc.with_ymd_and_hms(2021, 5, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2021, 5, 5, 0, 0, 0).unwrap(),
+            0,
+            100,
+        );
+        let y_scale = LinearScale::new(0_f32, 100_f32, 100, 0);
+        let data = vec![
+            (Utc.with_ymd_and_hms(2021, 5, 1, 0, 0, 0).unwrap(), 50_f32),
+            (Utc.with_ymd_and_hms(2021, 5, 2, 0, 0, 0).unwrap(), 100_f32),
+            (Utc.with_ymd_and_hms(2021, 5, 5, 0, 0, 0).unwrap(), 0_f32),
+        ];
+        let line = LineView::new(x_scale, y_scale)
+            .set_point_visible(false)
+            .set_point_label_visible(false)
+            .set_data(&data)
+            .expect("unable to set data");
+        let line_svg = line.to_svg();
+        assert_eq!(line_svg.to_string(), expected_svg_group);
+    }
+
+    #[test]
+    fn line_missing_values() {
+        let x_scale = BandScale::new(
+            vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+            ],
+            0,
+            100,
+        )
+        .set_inner_padding(0_f32)
+        .set_outer_padding(0_f32);
+        let y_scale = LinearScale::new(0_f32, 100_f32, 100, 0);
+        let data = vec![Some(10_f32), None, Some(30_f32), Some(f32::NAN)];
+
+        let path = |missing_values| {
+            LineView::new(x_scale.clone(), y_scale.clone())
+                .set_missing_values(missing_values)
+                .set_data(&data)
+                .expect("unable to set data")
+                .to_svg()
+                .to_string()
+        };
+        assert!(path(MissingValues::Break).contains(r#"d="M12.5,90 M62.5,70""#));
+        assert!(path(MissingValues::Bridge).contains(r#"d="M12.5,90 L62.5,70""#));
+        assert!(path(MissingValues::Zero).contains(r#"d="M12.5,90 L37.5,100 L62.5,70 L87.5,100""#));
+
+        // Missing points have no marker and label.
+        assert!(path(MissingValues::Zero)
+            .contains(r#"<g class="point" transform="translate(37.5,100)"/>"#));
+    }
 }
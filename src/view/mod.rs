@@ -1,7 +1,16 @@
+use crate::scale::ScaleRange;
+use crate::{ColorScale, LegendEntry, Scale, Theme};
+
 pub mod area;
+pub mod box_plot;
+pub mod candlestick;
+pub mod heatmap;
+pub mod histogram;
 pub mod horizontal_bar;
 pub mod line;
+pub mod pie;
 pub mod scatter;
+pub mod stacked_area;
 pub mod vertical_bar;
 
 /// Orientation is used for views that use shapes that are configured by orientation.
@@ -11,7 +20,111 @@ pub enum Orientation {
     Vertical,
 }
 
+/// BarsLayout is used for bar views to configure how several bars of the same category are placed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BarsLayout {
+    /// Bars are stacked on top of each other.
+    Stacked,
+
+    /// Bars are placed next to each other inside a category.
+    Grouped,
+}
+
+/// MissingValues is used for views with paths to configure how missing values are drawn.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MissingValues {
+    /// Path is broken into separate segments at missing values.
+    Break,
+
+    /// Path connects present values around missing ones.
+    Bridge,
+
+    /// Missing values are treated as zero.
+    /// Paths are broken at missing values when zero is outside of the scale domain.
+    Zero,
+}
+
+// Get palette series of the values that take colors from the chart palette,
+// values with explicitly set colors get None.
+pub(crate) fn palette_series(
+    default_colors: impl Iterator<Item = bool>,
+    offset: usize,
+) -> Vec<Option<usize>> {
+    default_colors
+        .scan(offset, |series, default_colors| {
+            if !default_colors {
+                return Some(None);
+            }
+            *series += 1;
+            Some(Some(*series - 1))
+        })
+        .collect()
+}
+
+// ViewPoint is a point of a view with its position relative to the ranges of view scales,
+// views keep such points to lay them out for any view size.
+#[derive(Clone)]
+pub(crate) struct ViewPoint {
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+    pub(crate) x_label: String,
+    pub(crate) y_label: String,
+    pub(crate) present: bool,
+}
+
+// Get position of the value relative to the scale range, 0 is the range start and 1 is its end.
+// Position is moved to the middle of the band in case there is a non-zero bandwidth.
+pub(crate) fn scale_position<T, S: Scale<T>>(scale: &S, value: &T) -> f32 {
+    let bandwidth_offset = if scale.is_range_reversed() {
+        -scale.tick_offset()
+    } else {
+        scale.tick_offset()
+    };
+    ScaleRange::new(scale).normalize(scale.scale(value) + bandwidth_offset)
+}
+
 /// View contains data representation on a chart.
 pub trait View {
+    /// Get view SVG representation for the view size.
     fn to_svg(&self) -> svg::node::element::Group;
+
+    /// Get view SVG representation for the provided view size.
+    /// Views lay out their scales and shapes for the provided size,
+    /// views that don't support it ignore the size.
+    fn to_svg_with_size(&self, _width: i32, _height: i32) -> svg::node::element::Group {
+        self.to_svg()
+    }
+
+    /// Get view SVG representation for the provided view size and theme.
+    /// Views that don't support themes ignore the theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        _theme: &Theme,
+    ) -> svg::node::element::Group {
+        self.to_svg_with_size(width, height)
+    }
+
+    /// Get height of a panel that the view draws below its y scale range,
+    /// chart y axes leave this space at the bottom of the view area.
+    fn bottom_panel_height(&self) -> i32 {
+        0
+    }
+
+    /// Get count of view series that take their colors from the chart palette
+    /// because their colors aren't set explicitly.
+    fn palette_series_count(&self) -> usize {
+        0
+    }
+
+    /// Get entries that represent named series of the view in a chart legend.
+    fn legend_entries(&self) -> Vec<LegendEntry> {
+        Vec::new()
+    }
+
+    /// Get the color scale of the view that is shown as a color bar in a chart legend.
+    fn color_scale(&self) -> Option<ColorScale> {
+        None
+    }
 }
@@ -0,0 +1,360 @@
+use crate::render::svg::*;
+use crate::shape::sector::Sector;
+use crate::view::palette_series;
+use crate::Theme;
+use crate::{
+    Error, LegendEntry, LegendMarker, PieLabelFormat, PieLabelPosition, PieSlice, TickFormat, View,
+};
+use svg::Node;
+
+const DEFAULT_VIEW_WIDTH: i32 = 100;
+const DEFAULT_VIEW_HEIGHT: i32 = 100;
+const DEFAULT_OUTER_RADIUS_RATIO: f32 = 0.8_f32;
+const DEFAULT_INNER_RADIUS: f32 = 0_f32;
+const DEFAULT_START_ANGLE: f32 = 0_f32;
+const DEFAULT_PAD_ANGLE: f32 = 0_f32;
+const DEFAULT_SORT_ORDER: PieSortOrder = PieSortOrder::Original;
+
+const DEFAULT_LABEL_VISIBLE: bool = true;
+const DEFAULT_LABEL_POSITION: PieLabelPosition = PieLabelPosition::Inside;
+const DEFAULT_LABEL_FORMAT: PieLabelFormat = PieLabelFormat::Percentage;
+
+/// PieSortOrder contains available orders of pie slices.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PieSortOrder {
+    /// Slices are placed in the order of provided values.
+    Original,
+
+    /// Slices are placed from the smallest value to the largest.
+    Ascending,
+
+    /// Slices are placed from the largest value to the smallest.
+    Descending,
+}
+
+/// PieView represents a pie or a donut chart view.
+/// It doesn't use scales so it can be added to a chart without axes.
+#[derive(Clone)]
+pub struct PieView {
+    view_width: i32,
+    view_height: i32,
+    outer_radius: Option<f32>,
+    inner_radius: f32,
+    start_angle: f32,
+    pad_angle: f32,
+    sort_order: PieSortOrder,
+    label_visible: bool,
+    label_position: PieLabelPosition,
+    label_format: PieLabelFormat,
+    value_format: TickFormat,
+    slices: Vec<PieSlice>,
+    palette_series: Vec<Option<usize>>,
+    palette_series_count: usize,
+}
+
+impl PieView {
+    /// Create a new PieView.
+    pub fn new() -> Self {
+        Self {
+            view_width: DEFAULT_VIEW_WIDTH,
+            view_height: DEFAULT_VIEW_HEIGHT,
+            outer_radius: None,
+            inner_radius: DEFAULT_INNER_RADIUS,
+            start_angle: DEFAULT_START_ANGLE,
+            pad_angle: DEFAULT_PAD_ANGLE,
+            sort_order: DEFAULT_SORT_ORDER,
+            label_visible: DEFAULT_LABEL_VISIBLE,
+            label_position: DEFAULT_LABEL_POSITION,
+            label_format: DEFAULT_LABEL_FORMAT,
+            value_format: TickFormat::Default,
+            slices: Vec::new(),
+            palette_series: Vec::new(),
+            palette_series_count: 0,
+        }
+    }
+
+    /// Set size of the view that the pie is centered in when it's rendered with `to_svg`.
+    /// Charts render the pie for their own view size so it doesn't affect them.
+    pub fn set_view_size(mut self, view_width: i32, view_height: i32) -> Self {
+        self.view_width = view_width;
+        self.view_height = view_height;
+        self
+    }
+
+    /// Set outer radius of slices, by default it follows the smaller side of the view.
+    pub fn set_outer_radius(mut self, outer_radius: f32) -> Self {
+        self.outer_radius = Some(outer_radius);
+        self
+    }
+
+    /// Set inner radius of slices, non-zero value turns the pie into a donut.
+    pub fn set_inner_radius(mut self, inner_radius: f32) -> Self {
+        self.inner_radius = inner_radius;
+        self
+    }
+
+    /// Set angle of the first slice start in degrees measured clockwise from the top.
+    pub fn set_start_angle(mut self, start_angle: f32) -> Self {
+        self.start_angle = start_angle;
+        self
+    }
+
+    /// Set angle between adjacent slices in degrees.
+    pub fn set_pad_angle(mut self, pad_angle: f32) -> Self {
+        self.pad_angle = pad_angle;
+        self
+    }
+
+    /// Set order of slices.
+    pub fn set_sort_order(mut self, sort_order: PieSortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// Configure label visibility for slices.
+    pub fn set_label_visible(mut self, label_visible: bool) -> Self {
+        self.label_visible = label_visible;
+        self
+    }
+
+    /// Configure label position for slices.
+    pub fn set_label_position(mut self, label_position: PieLabelPosition) -> Self {
+        self.label_position = label_position;
+        self
+    }
+
+    /// Configure label format for slices.
+    pub fn set_label_format(mut self, label_format: PieLabelFormat) -> Self {
+        self.label_format = label_format;
+        self
+    }
+
+    /// Set format of slice values that are used in labels with `PieLabelFormat::Value`.
+    pub fn set_value_format(mut self, value_format: TickFormat) -> Self {
+        self.value_format = value_format;
+        self
+    }
+
+    fn label(&self, slice: &PieSlice, sum: f32) -> String {
+        let percentage = format!("{}%", (slice.value() / sum * 1000_f32).round() / 10_f32);
+        match self.label_format {
+            PieLabelFormat::Label => slice.label().to_string(),
+            PieLabelFormat::Value => self.value_format.format(slice.value()),
+            PieLabelFormat::Percentage => percentage,
+            PieLabelFormat::LabelAndPercentage => format!("{} ({})", slice.label(), percentage),
+        }
+    }
+
+    /// Set slices for the pie.
+    pub fn set_data(mut self, slices: &[PieSlice]) -> Result<Self, Error> {
+        if slices.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+        if slices
+            .iter()
+            .any(|slice| !slice.value().is_finite() || slice.value() < 0_f32)
+        {
+            return Err(Error::PieValuesAreInvalid);
+        }
+        let sum: f32 = slices.iter().map(|slice| slice.value()).sum();
+        if sum <= 0_f32 {
+            return Err(Error::PieValuesAreInvalid);
+        }
+
+        // Slices without explicitly set colors take the next palette colors in their original order.
+        self.palette_series = palette_series(slices.iter().map(|slice| slice.default_colors()), 0);
+        self.palette_series_count = self.palette_series.iter().flatten().count();
+        self.slices = slices.to_vec();
+
+        Ok(self)
+    }
+
+    // Get slices with their palette series in the configured order.
+    fn ordered_slices(&self) -> Vec<(&PieSlice, Option<usize>)> {
+        let mut ordered_slices: Vec<(&PieSlice, Option<usize>)> = self
+            .slices
+            .iter()
+            .zip(self.palette_series.iter().cloned())
+            .collect();
+        match self.sort_order {
+            PieSortOrder::Original => {}
+            PieSortOrder::Ascending => {
+                ordered_slices.sort_by(|a, b| a.0.value().partial_cmp(&b.0.value()).unwrap())
+            }
+            PieSortOrder::Descending => {
+                ordered_slices.sort_by(|a, b| b.0.value().partial_cmp(&a.0.value()).unwrap())
+            }
+        }
+        ordered_slices
+    }
+}
+
+impl Default for PieView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl View for PieView {
+    /// Get pie view SVG representation.
+    fn to_svg(&self) -> svg::node::element::Group {
+        self.to_svg_with_size(self.view_width, self.view_height)
+    }
+
+    /// Get pie view SVG representation for the provided view size.
+    fn to_svg_with_size(&self, width: i32, height: i32) -> svg::node::element::Group {
+        self.to_svg_with_theme(width, height, &Theme::default())
+    }
+
+    /// Get pie view SVG representation for the provided view size and theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        let outer_radius = self
+            .outer_radius
+            .unwrap_or(width.min(height) as f32 / 2_f32 * DEFAULT_OUTER_RADIUS_RATIO);
+        let sum: f32 = self.slices.iter().map(|slice| slice.value()).sum();
+
+        let mut res = svg::node::element::Group::new()
+            .set(CLASS_ATTR, CLASS_PIE)
+            .set(
+                TRANSFORM_ATTR,
+                translate_x_y(width as f32 / 2_f32, height as f32 / 2_f32),
+            );
+
+        let pad_angle = self.pad_angle.to_radians();
+        let mut angle = self.start_angle.to_radians();
+        for (slice, series) in self.ordered_slices() {
+            let slice_angle = slice.value() / sum * 2_f32 * std::f32::consts::PI;
+
+            // Pad angle is taken equally from both sides of a slice.
+            let pad = pad_angle.min(slice_angle) / 2_f32;
+            let sector = Sector::new(
+                angle + pad,
+                angle + slice_angle - pad,
+                self.inner_radius,
+                outer_radius,
+            )
+            .set_fill_color(slice.fill_color())
+            .set_stroke_color(slice.stroke_color())
+            .set_label(&self.label(slice, sum))
+            .set_label_visible(self.label_visible)
+            .set_label_position(self.label_position);
+            let sector = match series {
+                Some(series) => sector.set_palette_series(series),
+                None => sector,
+            };
+            res.append(sector.to_svg(theme));
+
+            angle += slice_angle;
+        }
+
+        res
+    }
+
+    /// Get count of pie slices that take their colors from the chart palette.
+    fn palette_series_count(&self) -> usize {
+        self.palette_series_count
+    }
+
+    /// Get pie view legend entries.
+    fn legend_entries(&self) -> Vec<LegendEntry> {
+        self.ordered_slices()
+            .iter()
+            .filter(|(slice, _)| !slice.label().is_empty())
+            .map(|(slice, series)| {
+                let entry = LegendEntry::new(
+                    slice.label(),
+                    LegendMarker::Square,
+                    slice.fill_color(),
+                    slice.stroke_color(),
+                );
+                match series {
+                    Some(series) => entry.set_palette_series(*series),
+                    None => entry,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{COLOR_HEX_BLUE_2, COLOR_HEX_BLUE_4, COLOR_HEX_GREEN_2, COLOR_HEX_GREEN_4};
+    use crate::Color;
+
+    #[test]
+    fn pie_basic() {
+        let expected_svg_group = r##"<g class="pie" transform="translate(50,50)">
+<g class="sector">
+<path d="M0,-40 A40,40,0,0,1,40,0 L20,0 A20,20,0,0,0,0,-20 z" fill="#117401" stroke="#00400e"/>
+<g>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="21.213203" y="-21.213203">
+B (25%)
+</text>
+</g>
+</g>
+<g class="sector">
+<path d="M40,0 A40,40,0,1,1,0,-40 L0,-20 A20,20,0,1,0,20,0 z" fill="#5095e5" stroke="#1960b2"/>
+<g>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="-21.213207" y="21.2132">
+A (75%)
+</text>
+</g>
+</g>
+</g>"##;
+
+        let data = vec![
+            PieSlice::new("A", 30_f32)
+                .set_fill_color(Color::new_from_hex(COLOR_HEX_BLUE_4))
+                .set_stroke_color(Color::new_from_hex(COLOR_HEX_BLUE_2)),
+            PieSlice::new("B", 10_f32)
+                .set_fill_color(Color::new_from_hex(COLOR_HEX_GREEN_4))
+                .set_stroke_color(Color::new_from_hex(COLOR_HEX_GREEN_2)),
+        ];
+        let pie = PieView::new()
+            .set_outer_radius(40_f32)
+            .set_inner_radius(20_f32)
+            .set_sort_order(PieSortOrder::Ascending)
+            .set_label_format(PieLabelFormat::LabelAndPercentage)
+            .set_data(&data)
+            .expect("unable to set data");
+        assert_eq!(pie.to_svg().to_string(), expected_svg_group);
+        assert_eq!(pie.legend_entries().len(), 2);
+
+        // Default radius follows the smaller side of the view so the pie is never distorted.
+        let pie_svg = PieView::new()
+            .set_data(&data)
+            .expect("unable to set data")
+            .to_svg_with_size(200, 100)
+            .to_string();
+        assert!(pie_svg.contains(r#"transform="translate(100,50)""#));
+        assert!(pie_svg.contains("A40,40,"));
+
+        // View size is only used when the pie is rendered by itself.
+        let pie_svg = PieView::new()
+            .set_view_size(200, 100)
+            .set_data(&data)
+            .expect("unable to set data")
+            .to_svg()
+            .to_string();
+        assert!(pie_svg.contains(r#"transform="translate(100,50)""#));
+
+        let pie = PieView::new()
+            .set_label_format(PieLabelFormat::Value)
+            .set_value_format(TickFormat::Fixed(1))
+            .set_data(&[PieSlice::new("A", 0.1_f32 + 0.2_f32)])
+            .expect("unable to set data");
+        assert!(pie.to_svg().to_string().contains("\n0.3\n"));
+
+        let data = vec![PieSlice::new("A", -1_f32)];
+        assert!(matches!(
+            PieView::new().set_data(&data),
+            Err(Error::PieValuesAreInvalid)
+        ));
+    }
+}
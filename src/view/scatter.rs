@@ -1,7 +1,13 @@
 use crate::color::{COLOR_HEX_BLUE_3, COLOR_HEX_BLUE_4};
 use crate::render::svg::*;
+use crate::scale::ScaleRange;
 use crate::shape::point::Point;
-use crate::{Color, Error, LinearScale, PointLabelPosition, PointType, Scale, View};
+use crate::view::{scale_position, ViewPoint};
+use crate::Theme;
+use crate::{
+    Color, Error, LegendEntry, LegendMarker, LinearScale, OptionalValue, PointLabelPosition,
+    PointType, Scale, View,
+};
 use svg::Node;
 
 const DEFAULT_LABEL_VISIBLE: bool = true;
@@ -12,27 +18,35 @@ const DEFAULT_POINT_VISIBLE: bool = true;
 
 /// ScatterView represents separated points view.
 #[derive(Clone)]
-pub struct ScatterView {
-    x_scale: LinearScale,
-    y_scale: LinearScale,
+pub struct ScatterView<X = LinearScale, Y = LinearScale> {
+    x_scale: X,
+    y_scale: Y,
+    name: String,
     point_fill_color: String,
     point_stroke_color: String,
-    points: Vec<Point>,
+    default_colors: bool,
+    points: Vec<ViewPoint>,
+    x_range: ScaleRange,
+    y_range: ScaleRange,
     point_type: PointType,
     point_visible: bool,
     point_label_visible: bool,
     point_label_position: PointLabelPosition,
 }
 
-impl ScatterView {
+impl<X, Y> ScatterView<X, Y> {
     /// Create a new ScatterView.
-    pub fn new(x_scale: LinearScale, y_scale: LinearScale) -> Self {
+    pub fn new(x_scale: X, y_scale: Y) -> Self {
         Self {
             x_scale,
             y_scale,
+            name: String::new(),
             point_fill_color: COLOR_HEX_BLUE_4.to_string(),
             point_stroke_color: COLOR_HEX_BLUE_3.to_string(),
+            default_colors: true,
             points: Vec::new(),
+            x_range: ScaleRange::default(),
+            y_range: ScaleRange::default(),
             point_type: DEFAULT_POINT_TYPE,
             point_visible: DEFAULT_POINT_VISIBLE,
             point_label_visible: DEFAULT_LABEL_VISIBLE,
@@ -40,15 +54,23 @@ impl ScatterView {
         }
     }
 
+    /// Set scatter name that is used in a chart legend.
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
     /// Set scatter points fill color.
     pub fn set_point_fill_color(mut self, point_fill_color: Color) -> Self {
         self.point_fill_color = point_fill_color.to_string();
+        self.default_colors = false;
         self
     }
 
     /// Set scatter points stroke color.
     pub fn set_point_stroke_color(mut self, point_stroke_color: Color) -> Self {
         self.point_stroke_color = point_stroke_color.to_string();
+        self.default_colors = false;
         self
     }
 
@@ -77,68 +99,131 @@ impl ScatterView {
     }
 
     /// Set values for scatter view.
-    pub fn set_data(mut self, data: &[(f32, f32)]) -> Result<Self, Error> {
+    /// Values can be numbers, timestamps or categories depending on the view scales.
+    /// Points with missing y values are skipped.
+    pub fn set_data<XD: ToString, D: OptionalValue>(
+        mut self,
+        data: &[(XD, D)],
+    ) -> Result<Self, Error>
+    where
+        X: Scale<XD>,
+        Y: Scale<D::Value>,
+        D::Value: ToString,
+    {
         if data.is_empty() {
             return Err(Error::DataIsEmpty);
         }
 
-        // Compute offsets in case there is a non-zero bandwidth.
-        let x_bandwidth_offset = {
-            if self.x_scale.is_range_reversed() {
-                -self.x_scale.tick_offset()
-            } else {
-                self.x_scale.tick_offset()
-            }
-        };
-        let y_bandwidth_offset = {
-            if self.y_scale.is_range_reversed() {
-                -self.y_scale.tick_offset()
-            } else {
-                self.y_scale.tick_offset()
+        let mut points = Vec::new();
+        for (x_value, y_value) in data.iter() {
+            let y_value = match y_value.value() {
+                Some(y_value) => y_value,
+                None => continue,
+            };
+            if !self.x_scale.accepts(x_value) || !self.y_scale.accepts(&y_value) {
+                return Err(Error::ValueIsNotInScaleDomain);
             }
-        };
 
-        let mut points = Vec::new();
-        for values in data.iter() {
-            let scaled_x = &self.x_scale.scale(&values.0);
-            let scaled_y = self.y_scale.scale(&values.1);
+            points.push(ViewPoint {
+                x: scale_position(&self.x_scale, x_value),
+                y: scale_position(&self.y_scale, &y_value),
+                x_label: self.x_scale.tick_label(x_value),
+                y_label: self.y_scale.tick_label(&y_value),
+                present: true,
+            });
+        }
+        self.points = points;
+        self.x_range = ScaleRange::new(&self.x_scale);
+        self.y_range = ScaleRange::new(&self.y_scale);
+
+        Ok(self)
+    }
 
+    // Get scatter view SVG representation for the provided scale ranges.
+    fn to_svg_with_ranges(
+        &self,
+        x_range: ScaleRange,
+        y_range: ScaleRange,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new();
+        for view_point in self.points.iter() {
             let point = Point::new(
-                scaled_x + x_bandwidth_offset,
-                scaled_y + y_bandwidth_offset,
+                x_range.interpolate(view_point.x),
+                y_range.interpolate(view_point.y),
                 self.point_type,
                 DEFAULT_POINT_SIZE,
-                &values.1.to_string(),
-                &self.point_fill_color.to_string(),
-                &self.point_stroke_color.to_string(),
+                &view_point.y_label,
+                &self.point_fill_color,
+                &self.point_stroke_color,
             )
             .set_point_visible(self.point_visible)
-            .set_x_label(&values.0.to_string())
+            .set_x_label(&view_point.x_label)
             .set_label_visible(self.point_label_visible)
             .set_label_position(self.point_label_position);
-            points.push(point);
+            // Points without explicitly set colors take them from the theme palette.
+            let point = if self.default_colors {
+                point.set_palette_series(0)
+            } else {
+                point
+            };
+            res.append(point.to_svg(theme));
         }
-        self.points = points;
 
-        Ok(self)
+        res
     }
 }
 
-impl View for ScatterView {
+impl<X, Y> View for ScatterView<X, Y> {
     /// Get scatter view SVG representation.
     fn to_svg(&self) -> svg::node::element::Group {
-        let mut res = svg::node::element::Group::new();
-        for point in self.points.iter() {
-            res.append(point.to_svg());
+        self.to_svg_with_ranges(self.x_range, self.y_range, &Theme::default())
+    }
+
+    /// Get scatter view SVG representation for the provided view size.
+    fn to_svg_with_size(&self, width: i32, height: i32) -> svg::node::element::Group {
+        self.to_svg_with_theme(width, height, &Theme::default())
+    }
+
+    /// Get scatter view SVG representation for the provided view size and theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        self.to_svg_with_ranges(self.x_range.fit(width), self.y_range.fit(height), theme)
+    }
+
+    /// Get count of scatter series that take their colors from the chart palette.
+    fn palette_series_count(&self) -> usize {
+        self.default_colors as usize
+    }
+
+    /// Get scatter legend entries.
+    fn legend_entries(&self) -> Vec<LegendEntry> {
+        if self.name.is_empty() {
+            return Vec::new();
         }
 
-        res
+        let entry = LegendEntry::new(
+            &self.name,
+            LegendMarker::Point(self.point_type),
+            &self.point_fill_color,
+            &self.point_stroke_color,
+        );
+        if self.default_colors {
+            return vec![entry.set_palette_series(0)];
+        }
+
+        vec![entry]
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::BandScale;
 
     #[test]
     fn scatter_basic() {
@@ -166,4 +251,56 @@ mod tests {
         let scatter_svg = scatter.to_svg();
         assert_eq!(scatter_svg.to_string(), expected_svg_group);
     }
+
+    #[test]
+    fn scatter_band_scale() {
+        let expected_svg_group = r##"<g>
+<g class="point" transform="translate(25,80)"/>
+<g class="point" transform="translate(75,30)"/>
+</g>"##;
+
+        let x_scale = BandScale::new(vec!["A".to_string(), "B".to_string()], 0, 100)
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+        let y_scale = LinearScale::new(0.0, 100.0, 100, 0);
+        let scatter = ScatterView::new(x_scale.clone(), y_scale.clone())
+            .set_point_visible(false)
+            .set_point_label_visible(false);
+
+        let data = vec![("A".to_string(), 20_f32), ("B".to_string(), 70_f32)];
+        let scatter_svg = scatter
+            .clone()
+            .set_data(&data)
+            .expect("unable to set data")
+            .to_svg();
+        assert_eq!(scatter_svg.to_string(), expected_svg_group);
+
+        let data = vec![("C".to_string(), 20_f32)];
+        assert!(matches!(
+            scatter.set_data(&data),
+            Err(Error::ValueIsNotInScaleDomain)
+        ));
+    }
+
+    #[test]
+    fn scatter_to_svg_with_size() {
+        let expected_svg_group = r##"<g>
+<g class="point" transform="translate(50,160)"/>
+<g class="point" transform="translate(150,60)"/>
+</g>"##;
+
+        let x_scale = BandScale::new(vec!["A".to_string(), "B".to_string()], 0, 100)
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+        let y_scale = LinearScale::new(0.0, 100.0, 100, 0);
+        let data = vec![("A".to_string(), 20_f32), ("B".to_string(), 70_f32)];
+        let scatter = ScatterView::new(x_scale, y_scale)
+            .set_point_visible(false)
+            .set_point_label_visible(false)
+            .set_data(&data)
+            .expect("unable to set data");
+
+        let scatter_svg = scatter.to_svg_with_size(200, 200);
+        assert_eq!(scatter_svg.to_string(), expected_svg_group);
+    }
 }
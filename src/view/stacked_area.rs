@@ -0,0 +1,426 @@
+use crate::render::svg::*;
+use crate::scale::fit_scale;
+use crate::shape::area::Area;
+use crate::shape::point::Point;
+use crate::view::palette_series;
+use crate::Theme;
+use crate::{
+    AreaValues, BandScale, Curve, Error, LegendEntry, LegendMarker, LinearScale,
+    PointLabelPosition, PointType, Scale, View,
+};
+use svg::Node;
+
+const DEFAULT_OFFSET: StackedAreaOffset = StackedAreaOffset::Zero;
+const DEFAULT_CURVE: Curve = Curve::Linear;
+
+const DEFAULT_POINT_TYPE: PointType = PointType::Circle;
+const DEFAULT_POINT_VISIBLE: bool = false;
+const DEFAULT_POINT_LABEL_VISIBLE: bool = false;
+const DEFAULT_POINT_LABEL_POSITION: PointLabelPosition = PointLabelPosition::Top;
+
+// Sum of layers of every category in normalized stacked areas.
+const NORMALIZED_TOTAL: f32 = 100_f32;
+
+/// StackedAreaOffset contains available baselines of the lowest layer in stacked area views.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StackedAreaOffset {
+    /// Layers are stacked from zero.
+    Zero,
+
+    /// Layers are stacked from zero and scaled so every category sums to 100.
+    Normalized,
+
+    /// Streamgraph baseline that minimizes the weighted wiggle of layers,
+    /// the whole stream is centered around zero.
+    Wiggle,
+}
+
+/// StackedAreaView represents a chart view with several area layers stacked on top of each other.
+#[derive(Clone)]
+pub struct StackedAreaView<Y = LinearScale> {
+    x_scale: BandScale,
+    y_scale: Y,
+    offset: StackedAreaOffset,
+    curve: Curve,
+    areas_values: Vec<AreaValues>,
+    palette_series: Vec<Option<usize>>,
+    legend_entries: Vec<LegendEntry>,
+    palette_series_count: usize,
+    point_type: PointType,
+    point_visible: bool,
+    point_label_visible: bool,
+    point_label_position: PointLabelPosition,
+}
+
+impl<Y: Scale<f32>> StackedAreaView<Y> {
+    /// Create a new StackedAreaView.
+    pub fn new(x_scale: BandScale, y_scale: Y) -> Self {
+        Self {
+            x_scale,
+            y_scale,
+            offset: DEFAULT_OFFSET,
+            curve: DEFAULT_CURVE,
+            areas_values: Vec::new(),
+            palette_series: Vec::new(),
+            legend_entries: Vec::new(),
+            palette_series_count: 0,
+            point_type: DEFAULT_POINT_TYPE,
+            point_visible: DEFAULT_POINT_VISIBLE,
+            point_label_visible: DEFAULT_POINT_LABEL_VISIBLE,
+            point_label_position: DEFAULT_POINT_LABEL_POSITION,
+        }
+    }
+
+    /// Configure baseline of the lowest layer.
+    pub fn set_offset(mut self, offset: StackedAreaOffset) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set curve that connects points of layers.
+    pub fn set_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Set type of points at layer tops.
+    pub fn set_point_type(mut self, point_type: PointType) -> Self {
+        self.point_type = point_type;
+        self
+    }
+
+    /// Set visibility of points at layer tops.
+    pub fn set_point_visible(mut self, point_visible: bool) -> Self {
+        self.point_visible = point_visible;
+        self
+    }
+
+    /// Set visibility of point labels that contain values of layers.
+    pub fn set_point_label_visible(mut self, point_label_visible: bool) -> Self {
+        self.point_label_visible = point_label_visible;
+        self
+    }
+
+    /// Set position of point labels.
+    pub fn set_point_label_position(mut self, point_label_position: PointLabelPosition) -> Self {
+        self.point_label_position = point_label_position;
+        self
+    }
+
+    // Get stacked area view SVG representation for the provided scales.
+    fn to_svg_with_scales(
+        &self,
+        x_scale: &BandScale,
+        y_scale: &Y,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        let layers: Vec<&Vec<f32>> = self.areas_values.iter().map(|av| av.values()).collect();
+        let bounds = stack(&layers, self.offset);
+
+        // Compute offset in case there is a non-zero bandwidth.
+        let x_bandwidth_offset = {
+            if x_scale.is_range_reversed() {
+                -x_scale.tick_offset()
+            } else {
+                x_scale.tick_offset()
+            }
+        };
+        let xs: Vec<f32> = x_scale
+            .ticks()
+            .iter()
+            .map(|category| x_scale.scale(category) + x_bandwidth_offset)
+            .collect();
+
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_STACKED_AREA);
+        for ((av, layer_bounds), series) in self
+            .areas_values
+            .iter()
+            .zip(bounds.iter())
+            .zip(self.palette_series.iter())
+        {
+            // Polygon goes along the layer top and returns along its baseline.
+            let mut points = Vec::with_capacity(xs.len());
+            for ((x, (_, top)), value) in xs.iter().zip(layer_bounds.iter()).zip(av.values()) {
+                let point = Point::new(
+                    *x,
+                    y_scale.scale(top),
+                    self.point_type,
+                    DEFAULT_POINT_SIZE,
+                    &y_scale.tick_label(value),
+                    av.fill_color(),
+                    av.stroke_color(),
+                )
+                .set_point_visible(self.point_visible)
+                .set_label_visible(self.point_label_visible)
+                .set_label_position(self.point_label_position);
+                points.push(point);
+            }
+            let mut baseline_points = Vec::with_capacity(xs.len());
+            for (x, (baseline, _)) in xs.iter().zip(layer_bounds.iter()).rev() {
+                let point = Point::new(
+                    *x,
+                    y_scale.scale(baseline),
+                    self.point_type,
+                    DEFAULT_POINT_SIZE,
+                    "",
+                    av.fill_color(),
+                    av.stroke_color(),
+                )
+                .set_point_visible(false)
+                .set_label_visible(false);
+                baseline_points.push(point);
+            }
+            let area = Area::new(points, av.fill_color(), av.stroke_color())
+                .set_baseline_points(baseline_points)
+                .set_curve(self.curve);
+            let area = match series {
+                Some(series) => area.set_palette_series(*series),
+                None => area,
+            };
+            res.append(area.to_svg(theme));
+        }
+
+        res
+    }
+
+    /// Set values for layers, the first layer is placed at the bottom.
+    /// Each layer contains values for every category of x scale in the same order.
+    pub fn set_data(mut self, areas_values: &[AreaValues]) -> Result<Self, Error> {
+        if areas_values.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+        let categories = self.x_scale.ticks();
+        if areas_values
+            .iter()
+            .any(|av| av.values().len() != categories.len())
+        {
+            return Err(Error::CategoriesCountDoesntEqual);
+        }
+        if areas_values
+            .iter()
+            .flat_map(|av| av.values().iter())
+            .any(|value| !value.is_finite() || *value < 0_f32)
+        {
+            return Err(Error::StackedAreaValuesAreInvalid);
+        }
+
+        let layers: Vec<&Vec<f32>> = areas_values.iter().map(|av| av.values()).collect();
+        if stack(&layers, self.offset)
+            .iter()
+            .flatten()
+            .any(|(baseline, top)| !self.y_scale.accepts(baseline) || !self.y_scale.accepts(top))
+        {
+            return Err(Error::ValueIsNotInScaleDomain);
+        }
+
+        // AreaValues without explicitly set colors take the next palette colors.
+        let palette_series = palette_series(areas_values.iter().map(|av| av.default_colors()), 0);
+        self.areas_values = areas_values.to_vec();
+        self.palette_series_count = palette_series.iter().flatten().count();
+
+        // Add legend entries for named AreaValues.
+        self.legend_entries = areas_values
+            .iter()
+            .zip(palette_series.iter())
+            .filter(|(av, _)| !av.name().is_empty())
+            .map(|(av, series)| {
+                let entry = LegendEntry::new(
+                    av.name(),
+                    LegendMarker::Square,
+                    av.fill_color(),
+                    av.stroke_color(),
+                );
+                match series {
+                    Some(series) => entry.set_palette_series(*series),
+                    None => entry,
+                }
+            })
+            .collect();
+        self.palette_series = palette_series;
+
+        Ok(self)
+    }
+}
+
+// Compute (baseline, top) bounds of every layer for every category.
+fn stack(layers: &[&Vec<f32>], offset: StackedAreaOffset) -> Vec<Vec<(f32, f32)>> {
+    let categories_count = layers[0].len();
+    let totals: Vec<f32> = (0..categories_count)
+        .map(|j| layers.iter().map(|layer| layer[j]).sum())
+        .collect();
+
+    let (baselines, scales): (Vec<f32>, Vec<f32>) = match offset {
+        StackedAreaOffset::Zero => (vec![0_f32; categories_count], vec![1_f32; categories_count]),
+        StackedAreaOffset::Normalized => (
+            vec![0_f32; categories_count],
+            totals
+                .iter()
+                .map(|total| {
+                    if *total > 0_f32 {
+                        NORMALIZED_TOTAL / total
+                    } else {
+                        0_f32
+                    }
+                })
+                .collect(),
+        ),
+        StackedAreaOffset::Wiggle => (
+            wiggle_baselines(layers, &totals),
+            vec![1_f32; categories_count],
+        ),
+    };
+
+    let mut acc = baselines;
+    layers
+        .iter()
+        .map(|layer| {
+            layer
+                .iter()
+                .enumerate()
+                .map(|(j, value)| {
+                    let baseline = acc[j];
+                    acc[j] += value * scales[j];
+                    (baseline, acc[j])
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Compute streamgraph baselines as described by Byron and Wattenberg.
+fn wiggle_baselines(layers: &[&Vec<f32>], totals: &[f32]) -> Vec<f32> {
+    let mut baselines = vec![0_f32; totals.len()];
+    let mut baseline = 0_f32;
+    for j in 1..totals.len() {
+        let mut weighted_slope = 0_f32;
+        let mut below_slope = 0_f32;
+        for layer in layers.iter() {
+            let slope = layer[j] - layer[j - 1];
+            weighted_slope += (below_slope + slope / 2_f32) * layer[j];
+            below_slope += slope;
+        }
+        if totals[j] > 0_f32 {
+            baseline -= weighted_slope / totals[j];
+        }
+        baselines[j] = baseline;
+    }
+
+    // Center the stream around zero so it fits symmetric scale domains.
+    let low = baselines.iter().cloned().fold(f32::INFINITY, f32::min);
+    let high = baselines
+        .iter()
+        .zip(totals.iter())
+        .map(|(baseline, total)| baseline + total)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let center = (low + high) / 2_f32;
+
+    baselines.iter().map(|baseline| baseline - center).collect()
+}
+
+impl<Y: Scale<f32> + Clone> View for StackedAreaView<Y> {
+    /// Get stacked area view SVG representation.
+    fn to_svg(&self) -> svg::node::element::Group {
+        self.to_svg_with_scales(&self.x_scale, &self.y_scale, &Theme::default())
+    }
+
+    /// Get stacked area view SVG representation for the provided view size.
+    fn to_svg_with_size(&self, width: i32, height: i32) -> svg::node::element::Group {
+        self.to_svg_with_theme(width, height, &Theme::default())
+    }
+
+    /// Get stacked area view SVG representation for the provided view size and theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        self.to_svg_with_scales(
+            &fit_scale(&self.x_scale, width),
+            &fit_scale(&self.y_scale, height),
+            theme,
+        )
+    }
+
+    /// Get count of stacked area layers that take their colors from the chart palette.
+    fn palette_series_count(&self) -> usize {
+        self.palette_series_count
+    }
+
+    /// Get stacked area view legend entries.
+    fn legend_entries(&self) -> Vec<LegendEntry> {
+        self.legend_entries.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stacked_area_offsets() {
+        let bottom = vec![1_f32, 3_f32, 0_f32];
+        let top = vec![3_f32, 1_f32, 0_f32];
+        let layers = vec![&bottom, &top];
+
+        assert_eq!(
+            stack(&layers, StackedAreaOffset::Zero),
+            vec![
+                vec![(0_f32, 1_f32), (0_f32, 3_f32), (0_f32, 0_f32)],
+                vec![(1_f32, 4_f32), (3_f32, 4_f32), (0_f32, 0_f32)],
+            ]
+        );
+        assert_eq!(
+            stack(&layers, StackedAreaOffset::Normalized),
+            vec![
+                vec![(0_f32, 25_f32), (0_f32, 75_f32), (0_f32, 0_f32)],
+                vec![(25_f32, 100_f32), (75_f32, 100_f32), (0_f32, 0_f32)],
+            ]
+        );
+
+        // Equal layers keep the stream flat and centered.
+        let layer = vec![2_f32, 2_f32];
+        assert_eq!(
+            stack(&[&layer, &layer], StackedAreaOffset::Wiggle),
+            vec![
+                vec![(-2_f32, 0_f32), (-2_f32, 0_f32)],
+                vec![(0_f32, 2_f32), (0_f32, 2_f32)],
+            ]
+        );
+    }
+
+    #[test]
+    fn stacked_area_basic() {
+        let expected_svg_group = r##"<g class="stacked-area">
+<g class="area">
+<path d="M25,75 L75,50 L75,100 L25,100 z" fill="#038d05" stroke="#0c3300"/>
+<g class="point" transform="translate(25,75)"/>
+<g class="point" transform="translate(75,50)"/>
+<g class="point" transform="translate(75,100)"/>
+<g class="point" transform="translate(25,100)"/>
+</g>
+<g class="area">
+<path d="M25,0 L75,0 L75,50 L25,75 z" fill="#038d05" stroke="#0c3300"/>
+<g class="point" transform="translate(25,0)"/>
+<g class="point" transform="translate(75,0)"/>
+<g class="point" transform="translate(75,50)"/>
+<g class="point" transform="translate(25,75)"/>
+</g>
+</g>"##;
+
+        let x_scale = BandScale::new(vec!["a".to_string(), "b".to_string()], 0, 100)
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+        let y_scale = LinearScale::new(0_f32, 100_f32, 100, 0);
+        let data = vec![
+            AreaValues::new(vec![1_f32, 1_f32]).set_name("Bottom"),
+            AreaValues::new(vec![3_f32, 1_f32]),
+        ];
+        let stacked_area = StackedAreaView::new(x_scale, y_scale)
+            .set_offset(StackedAreaOffset::Normalized)
+            .set_data(&data)
+            .expect("unable to set data");
+        assert_eq!(stacked_area.to_svg().to_string(), expected_svg_group);
+        assert_eq!(stacked_area.legend_entries().len(), 1);
+    }
+}
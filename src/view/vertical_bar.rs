@@ -1,32 +1,50 @@
+use crate::scale::fit_scale;
 use crate::shape::bar::Bar;
+use crate::view::palette_series;
+use crate::Theme;
 use crate::{
-    BandScale, BarLabelPosition, BarsValues, Error, LinearScale, Orientation, Scale, View,
+    BandScale, BarLabelPosition, BarsLayout, BarsValues, Error, LegendEntry, LegendMarker,
+    LinearScale, Orientation, Scale, View,
 };
-use std::collections::HashMap;
 use svg::node::Node;
 
 const DEFAULT_BAR_LABEL_VISIBLE: bool = true;
 const DEFAULT_BAR_LABEL_POSITION: BarLabelPosition = BarLabelPosition::Center;
+const DEFAULT_BARS_LAYOUT: BarsLayout = BarsLayout::Stacked;
+const DEFAULT_GROUP_INNER_PADDING: f32 = 0.1_f32;
+
+// Group inner padding is kept below 1 so grouped bars never collapse to zero width.
+const MAX_GROUP_INNER_PADDING: f32 = 0.9_f32;
 
 /// VerticalBarView represents a chart view with vertical bars.
 #[derive(Clone)]
-pub struct VerticalBarView {
+pub struct VerticalBarView<Y = LinearScale> {
     x_scale: BandScale,
-    y_scale: LinearScale,
-    bars: Vec<Bar>,
+    y_scale: Y,
+    bars_values: Vec<BarsValues>,
+    palette_series: Vec<Option<usize>>,
+    legend_entries: Vec<LegendEntry>,
+    palette_series_count: usize,
     bar_label_visible: bool,
     bar_label_position: BarLabelPosition,
+    bars_layout: BarsLayout,
+    group_inner_padding: f32,
 }
 
-impl VerticalBarView {
+impl<Y: Scale<f32>> VerticalBarView<Y> {
     /// Create a new VerticalBarView.
-    pub fn new(x_scale: BandScale, y_scale: LinearScale) -> Self {
+    pub fn new(x_scale: BandScale, y_scale: Y) -> Self {
         Self {
             x_scale,
             y_scale,
-            bars: Vec::new(),
+            bars_values: Vec::new(),
+            palette_series: Vec::new(),
+            legend_entries: Vec::new(),
+            palette_series_count: 0,
             bar_label_visible: DEFAULT_BAR_LABEL_VISIBLE,
             bar_label_position: DEFAULT_BAR_LABEL_POSITION,
+            bars_layout: DEFAULT_BARS_LAYOUT,
+            group_inner_padding: DEFAULT_GROUP_INNER_PADDING,
         }
     }
 
@@ -42,87 +60,213 @@ impl VerticalBarView {
         self
     }
 
-    /// Set values for bars.
-    pub fn set_data(mut self, bars_values: &[BarsValues]) -> Result<Self, Error> {
-        if bars_values.is_empty() {
-            return Err(Error::DataIsEmpty);
-        }
+    /// Configure layout for bars of the same category.
+    pub fn set_bars_layout(mut self, bars_layout: BarsLayout) -> Self {
+        self.bars_layout = bars_layout;
+        self
+    }
 
-        // Populate a map of category to tuples of (value, fill_color, stroke_color).
-        let x_scale_domain = self.x_scale.ticks();
-        let mut bars_categories = HashMap::new();
-        for bv_opts in bars_values.iter() {
-            if bv_opts.values().len() > self.x_scale.ticks().len() {
-                return Err(Error::CategoriesCountIsLess);
-            }
-            for (i, value) in bv_opts.values().iter().enumerate() {
-                let category = &x_scale_domain[i];
-                bars_categories.entry(category).or_insert_with(Vec::new);
-                if let Some(category_entries) = bars_categories.get_mut(&category) {
-                    category_entries.push((value, bv_opts.fill_color(), bv_opts.stroke_color()));
-                };
+    /// Configure padding of grouped bars as a fraction of each bar sub-band.
+    /// Half of the padding is kept on each side of a bar so it applies to a single series too.
+    /// Values are clamped to the [0, 0.9] range and NaN resets the padding to its default.
+    pub fn set_group_inner_padding(mut self, group_inner_padding: f32) -> Self {
+        self.group_inner_padding = if group_inner_padding.is_nan() {
+            DEFAULT_GROUP_INNER_PADDING
+        } else {
+            group_inner_padding.clamp(0_f32, MAX_GROUP_INNER_PADDING)
+        };
+        self
+    }
+
+    // Create bars for the provided scales, bars are laid out when the view is rendered
+    // so layout settings apply after the data is set.
+    fn bars(&self, x_scale: &BandScale, y_scale: &Y) -> Vec<Bar> {
+        // Collect tuples of (bars_values_idx, value, fill_color, stroke_color) for every category
+        // in the order of the scale, so the view is rendered the same way every time.
+        let x_scale_domain = x_scale.ticks();
+        let mut bars_categories: Vec<(&String, Vec<_>)> = x_scale_domain
+            .iter()
+            .map(|category| (category, Vec::new()))
+            .collect();
+        for (bv_idx, bv_opts) in self.bars_values.iter().enumerate() {
+            for ((_, category_entries), value) in
+                bars_categories.iter_mut().zip(bv_opts.values().iter())
+            {
+                category_entries.push((
+                    bv_idx,
+                    value,
+                    bv_opts.fill_color(),
+                    bv_opts.stroke_color(),
+                ));
             }
         }
 
-        // Create vector of bars from the bars_categories map.
+        // Split category bandwidth into sub-bands for each BarsValues in case bars are grouped.
+        let (bar_width, bar_step, bar_offset) = match self.bars_layout {
+            BarsLayout::Stacked => (x_scale.bandwidth(), 0_f32, 0_f32),
+            BarsLayout::Grouped => {
+                let step = x_scale.bandwidth() / self.bars_values.len() as f32;
+                (
+                    step * (1_f32 - self.group_inner_padding),
+                    step,
+                    step * self.group_inner_padding / 2_f32,
+                )
+            }
+        };
+
+        // Create vector of bars from the bars_categories entries.
         let mut bars = Vec::new();
         for (category, category_entries) in bars_categories.iter() {
             let mut value_acc = 0_f32;
-            let mut start = self.y_scale.scale(&value_acc);
+            let mut start = y_scale.scale(&value_acc);
             let mut end = start;
 
             for category_entry in category_entries.iter() {
-                let value = category_entry.0;
-                let fill_color = category_entry.1;
-                let stroke_color = category_entry.2;
+                let bv_idx = category_entry.0;
+                let value = category_entry.1;
+                let fill_color = category_entry.2;
+                let stroke_color = category_entry.3;
+
+                // Grouped bars always start from zero instead of the end of the previous bar.
+                if self.bars_layout == BarsLayout::Grouped {
+                    value_acc = 0_f32;
+                    start = y_scale.scale(&value_acc);
+                    end = start;
+                }
 
                 value_acc += value;
-                if self.y_scale.is_range_reversed() {
+                if y_scale.is_range_reversed() {
                     end = start;
-                    start = self.y_scale.scale(&value_acc);
+                    start = y_scale.scale(&value_acc);
                 } else {
                     start = end;
-                    end = self.y_scale.scale(&value_acc);
+                    end = y_scale.scale(&value_acc);
                 }
 
                 let bar = Bar::new(
                     start,
                     end,
                     *value,
-                    self.x_scale.bandwidth(),
-                    self.x_scale.scale(&category.to_string()),
+                    bar_width,
+                    x_scale.scale(&category.to_string()) + bar_offset + bar_step * bv_idx as f32,
                     Orientation::Vertical,
                 )
                 .set_fill_color(fill_color)
                 .set_stroke_color(stroke_color)
+                .set_label(&y_scale.tick_label(value))
                 .set_label_visible(self.bar_label_visible)
                 .set_label_position(self.bar_label_position);
+                let bar = match self.palette_series[bv_idx] {
+                    Some(series) => bar.set_palette_series(series),
+                    None => bar,
+                };
                 bars.push(bar);
             }
         }
-        self.bars = bars;
+
+        bars
+    }
+
+    // Get bar view SVG representation for the provided scales.
+    fn to_svg_with_scales(
+        &self,
+        x_scale: &BandScale,
+        y_scale: &Y,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new();
+        for bar in self.bars(x_scale, y_scale) {
+            res.append(bar.to_svg(theme));
+        }
+
+        res
+    }
+
+    /// Set values for bars.
+    pub fn set_data(mut self, bars_values: &[BarsValues]) -> Result<Self, Error> {
+        if bars_values.is_empty() {
+            return Err(Error::DataIsEmpty);
+        }
+
+        // BarsValues without explicitly set colors take the next palette colors.
+        let palette_series = palette_series(
+            bars_values.iter().map(|bv_opts| bv_opts.default_colors()),
+            0,
+        );
+
+        for bv_opts in bars_values.iter() {
+            if bv_opts.values().len() > self.x_scale.ticks().len() {
+                return Err(Error::CategoriesCountIsLess);
+            }
+        }
+        self.bars_values = bars_values.to_vec();
+        self.palette_series = palette_series.clone();
+
+        self.palette_series_count = palette_series.iter().flatten().count();
+
+        // Add legend entries for named BarsValues.
+        self.legend_entries = bars_values
+            .iter()
+            .zip(palette_series.iter())
+            .filter(|(bv_opts, _)| !bv_opts.name().is_empty())
+            .map(|(bv_opts, series)| {
+                let entry = LegendEntry::new(
+                    bv_opts.name(),
+                    LegendMarker::Square,
+                    bv_opts.fill_color(),
+                    bv_opts.stroke_color(),
+                );
+                match series {
+                    Some(series) => entry.set_palette_series(*series),
+                    None => entry,
+                }
+            })
+            .collect();
 
         Ok(self)
     }
 }
 
-impl View for VerticalBarView {
+impl<Y: Scale<f32> + Clone> View for VerticalBarView<Y> {
     /// Get bar view SVG representation.
     fn to_svg(&self) -> svg::node::element::Group {
-        let mut res = svg::node::element::Group::new();
+        self.to_svg_with_scales(&self.x_scale, &self.y_scale, &Theme::default())
+    }
 
-        for bar in self.bars.iter() {
-            res.append(bar.to_svg());
-        }
+    /// Get bar view SVG representation for the provided view size.
+    fn to_svg_with_size(&self, width: i32, height: i32) -> svg::node::element::Group {
+        self.to_svg_with_theme(width, height, &Theme::default())
+    }
 
-        res
+    /// Get bar view SVG representation for the provided view size and theme.
+    fn to_svg_with_theme(
+        &self,
+        width: i32,
+        height: i32,
+        theme: &Theme,
+    ) -> svg::node::element::Group {
+        self.to_svg_with_scales(
+            &fit_scale(&self.x_scale, width),
+            &fit_scale(&self.y_scale, height),
+            theme,
+        )
+    }
+
+    /// Get count of bar series that take their colors from the chart palette.
+    fn palette_series_count(&self) -> usize {
+        self.palette_series_count
+    }
+
+    /// Get bar view legend entries.
+    fn legend_entries(&self) -> Vec<LegendEntry> {
+        self.legend_entries.clone()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::color::{COLOR_HEX_BLUE_2, COLOR_HEX_BLUE_4};
+    use crate::color::{COLOR_HEX_BLUE_2, COLOR_HEX_BLUE_4, COLOR_HEX_GREEN_2, COLOR_HEX_GREEN_4};
     use crate::Color;
 
     #[test]
@@ -151,4 +295,93 @@ mod tests {
         let vertical_bar_svg = vertical_bar.to_svg();
         assert_eq!(vertical_bar_svg.to_string(), expected_svg_group);
     }
+
+    #[test]
+    fn vertical_bar_grouped() {
+        let expected_svg_group = r##"<g>
+<g class="bar" transform="translate(20,0)">
+<rect fill="#5095e5" height="40" shape-rendering="crispEdges" stroke="#1960b2" stroke-width="1" width="40" x="0" y="60"/>
+</g>
+<g class="bar" transform="translate(100,0)">
+<rect fill="#117401" height="80" shape-rendering="crispEdges" stroke="#00400e" stroke-width="1" width="40" x="0" y="20"/>
+</g>
+</g>"##;
+
+        let x_scale = BandScale::new(vec!["A".to_string()], 0, 160)
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+        let y_scale = LinearScale::new(0_f32, 100_f32, 100, 0);
+        let data = vec![
+            BarsValues::new(vec![40_f32])
+                .set_fill_color(Color::new_from_hex(COLOR_HEX_BLUE_4))
+                .set_stroke_color(Color::new_from_hex(COLOR_HEX_BLUE_2)),
+            BarsValues::new(vec![80_f32])
+                .set_fill_color(Color::new_from_hex(COLOR_HEX_GREEN_4))
+                .set_stroke_color(Color::new_from_hex(COLOR_HEX_GREEN_2)),
+        ];
+        let vertical_bar = VerticalBarView::new(x_scale.clone(), y_scale.clone())
+            .set_bars_layout(BarsLayout::Grouped)
+            .set_group_inner_padding(0.5_f32)
+            .set_bar_label_visible(false)
+            .set_data(&data)
+            .expect("unable to set data");
+        let vertical_bar_svg = vertical_bar.to_svg();
+        assert_eq!(vertical_bar_svg.to_string(), expected_svg_group);
+
+        // Layout that is set after data is used when the view is rendered.
+        let vertical_bar = VerticalBarView::new(x_scale.clone(), y_scale.clone())
+            .set_bar_label_visible(false)
+            .set_data(&data)
+            .expect("unable to set data")
+            .set_bars_layout(BarsLayout::Grouped)
+            .set_group_inner_padding(0.5_f32);
+        assert_eq!(vertical_bar.to_svg().to_string(), expected_svg_group);
+
+        // Padding applies to a single series too and is clamped below the bar sub-band width.
+        let vertical_bar = VerticalBarView::new(x_scale, y_scale)
+            .set_bars_layout(BarsLayout::Grouped)
+            .set_group_inner_padding(2_f32)
+            .set_data(&data[..1])
+            .expect("unable to set data");
+        assert!(vertical_bar
+            .to_svg()
+            .to_string()
+            .contains(r#"transform="translate(72,0)""#));
+    }
+
+    #[test]
+    fn vertical_bar_category_order() {
+        let expected_svg_group = r##"<g>
+<g class="bar" transform="translate(0,0)">
+<rect fill="#5095e5" height="10" shape-rendering="crispEdges" stroke="#1960b2" stroke-width="1" width="25" x="0" y="90"/>
+</g>
+<g class="bar" transform="translate(25,0)">
+<rect fill="#5095e5" height="20" shape-rendering="crispEdges" stroke="#1960b2" stroke-width="1" width="25" x="0" y="80"/>
+</g>
+<g class="bar" transform="translate(50,0)">
+<rect fill="#5095e5" height="30" shape-rendering="crispEdges" stroke="#1960b2" stroke-width="1" width="25" x="0" y="70"/>
+</g>
+<g class="bar" transform="translate(75,0)">
+<rect fill="#5095e5" height="40" shape-rendering="crispEdges" stroke="#1960b2" stroke-width="1" width="25" x="0" y="60"/>
+</g>
+</g>"##;
+
+        let categories = ["A", "B", "C", "D"];
+        let x_scale = BandScale::new(categories.iter().map(|c| c.to_string()).collect(), 0, 100)
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+        let y_scale = LinearScale::new(0_f32, 100_f32, 100, 0);
+        let data = vec![BarsValues::new(vec![10_f32, 20_f32, 30_f32, 40_f32])
+            .set_fill_color(Color::new_from_hex(COLOR_HEX_BLUE_4))
+            .set_stroke_color(Color::new_from_hex(COLOR_HEX_BLUE_2))];
+        let vertical_bar = VerticalBarView::new(x_scale, y_scale)
+            .set_bar_label_visible(false)
+            .set_data(&data)
+            .expect("unable to set data");
+
+        // Bars follow the order of categories so repeated renders are identical.
+        let vertical_bar_svg = vertical_bar.to_svg().to_string();
+        assert_eq!(vertical_bar_svg, expected_svg_group);
+        assert_eq!(vertical_bar.to_svg().to_string(), vertical_bar_svg);
+    }
 }